@@ -0,0 +1,105 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use unimock::*;
+
+#[unimock(api=HotMock)]
+trait Hot {
+    fn hot(&self, input: u32) -> u32;
+    fn other1(&self) -> u32;
+    fn other2(&self) -> u32;
+    fn other3(&self) -> u32;
+}
+
+#[unimock(api=BlobMock)]
+trait Blob {
+    fn owned(&self) -> Vec<u8>;
+    fn shared(&self) -> Arc<Vec<u8>>;
+    fn lent(&self) -> &[u8];
+}
+
+fn single_pattern(c: &mut Criterion) {
+    c.bench_function("single_pattern", |b| {
+        let u = Unimock::new(HotMock::hot.each_call(matching!(_)).returns(42_u32));
+        b.iter(|| u.hot(black_box(1)))
+    });
+}
+
+fn many_patterns(c: &mut Criterion) {
+    c.bench_function("many_patterns", |b| {
+        let u = Unimock::new((
+            HotMock::hot.stub(|each| {
+                each.call(matching!(0)).returns(0_u32);
+                each.call(matching!(1)).returns(1_u32);
+                each.call(matching!(2)).returns(2_u32);
+                each.call(matching!(3)).returns(3_u32);
+                each.call(matching!(4)).returns(4_u32);
+                each.call(matching!(5)).returns(5_u32);
+                each.call(matching!(6)).returns(6_u32);
+                each.call(matching!(7)).returns(7_u32);
+                each.call(matching!(_)).returns(42_u32);
+            }),
+            HotMock::other1.each_call(matching!()).returns(1_u32),
+            HotMock::other2.each_call(matching!()).returns(2_u32),
+            HotMock::other3.each_call(matching!()).returns(3_u32),
+        ));
+        b.iter(|| u.hot(black_box(100)));
+
+        u.other1();
+        u.other2();
+        u.other3();
+    });
+}
+
+fn ordered(c: &mut Criterion) {
+    c.bench_function("ordered_1000", |b| {
+        b.iter(|| {
+            let u = Unimock::new(
+                HotMock::hot
+                    .next_call(matching!(_))
+                    .returns(42_u32)
+                    .n_times(1000),
+            );
+            for i in 0..1000 {
+                black_box(u.hot(black_box(i)));
+            }
+        })
+    });
+}
+
+fn large_output(c: &mut Criterion) {
+    const SIZE: usize = 1024 * 1024;
+
+    c.bench_function("large_output_owned", |b| {
+        let u = Unimock::new(
+            BlobMock::owned
+                .each_call(matching!())
+                .returns(vec![0_u8; SIZE]),
+        );
+        b.iter(|| u.owned())
+    });
+    c.bench_function("large_output_arc", |b| {
+        let u = Unimock::new(
+            BlobMock::shared
+                .each_call(matching!())
+                .returns(vec![0_u8; SIZE]),
+        );
+        b.iter(|| u.shared())
+    });
+    c.bench_function("large_output_ref", |b| {
+        let u = Unimock::new(
+            BlobMock::lent
+                .each_call(matching!())
+                .returns(vec![0_u8; SIZE]),
+        );
+        b.iter(|| u.lent().len())
+    });
+}
+
+criterion_group!(
+    benches,
+    single_pattern,
+    many_patterns,
+    ordered,
+    large_output
+);
+criterion_main!(benches);
@@ -1,92 +1,169 @@
-use crate::alloc::{format, vec, BTreeMap, Entry, String, ToString};
+use crate::alloc::{vec, BTreeMap, Entry, ToString};
 use crate::build::dyn_builder::DynCallPatternBuilder;
 use crate::call_pattern::CallPattern;
-use crate::fn_mocker::{FnMocker, PatternMatchMode};
+use crate::error::AssembleError;
+use crate::fn_mocker::{Fallback, FnMocker, PatternMatchMode};
 use crate::output::OutputError;
-use crate::Clause;
-use crate::{clause, MockFnInfo};
+use crate::{clause, AnyBox, MockFnInfo};
 
 use core::any::TypeId;
 
 pub(crate) struct MockAssembler {
     fn_mockers: BTreeMap<TypeId, FnMocker>,
+    fallback_modes: BTreeMap<TypeId, Fallback>,
+    max_debug_lens: BTreeMap<TypeId, usize>,
+    input_formats: BTreeMap<TypeId, AnyBox>,
     current_call_index: usize,
+    optional: bool,
 }
 
-impl MockAssembler {
-    #[inline]
-    #[track_caller]
-    pub fn try_from_clause(clause: impl Clause) -> Result<Self, String> {
-        let mut assembler = Self::new();
-        clause.deconstruct(&mut assembler).map(|_| assembler)
-    }
+pub(crate) struct Assembly {
+    pub fn_mockers: BTreeMap<TypeId, FnMocker>,
+    pub fallback_modes: BTreeMap<TypeId, Fallback>,
+    pub max_debug_lens: BTreeMap<TypeId, usize>,
+    pub input_formats: BTreeMap<TypeId, AnyBox>,
+}
 
-    fn new() -> Self {
+impl MockAssembler {
+    pub fn new() -> Self {
         Self {
             fn_mockers: BTreeMap::new(),
+            fallback_modes: BTreeMap::new(),
+            max_debug_lens: BTreeMap::new(),
+            input_formats: BTreeMap::new(),
             current_call_index: 0,
+            optional: false,
         }
     }
 
-    pub fn finish(self) -> BTreeMap<TypeId, FnMocker> {
-        self.fn_mockers
+    /// Start ordered call patterns at the given call index instead of at the first call.
+    pub fn starting_at_call_index(mut self, call_index: usize) -> Self {
+        self.current_call_index = call_index;
+        self
+    }
+
+    /// Whether MockFns set up from now on may go uncalled without failing verification.
+    pub fn set_optional(&mut self, optional: bool) {
+        self.optional = optional;
+    }
+
+    pub fn finish(mut self) -> Assembly {
+        for fn_mocker in self.fn_mockers.values_mut() {
+            fn_mocker.update_priority_order();
+        }
+
+        Assembly {
+            fn_mockers: self.fn_mockers,
+            fallback_modes: self.fallback_modes,
+            max_debug_lens: self.max_debug_lens,
+            input_formats: self.input_formats,
+        }
     }
 }
 
 impl clause::term::Sink for MockAssembler {
-    fn push(&mut self, info: MockFnInfo, mut builder: DynCallPatternBuilder) -> Result<(), String> {
+    fn push(
+        &mut self,
+        info: MockFnInfo,
+        mut builder: DynCallPatternBuilder,
+    ) -> Result<(), AssembleError> {
         if let Some(responder_error) = builder.responder_error.take() {
+            let path = info.path.to_string();
+            let label = builder.label.map(ToString::to_string);
             return Err(match responder_error {
-                OutputError::OwnershipRequired => "Ownership required".to_string(),
-                OutputError::NoMutexApi => {
-                    "No Mutex API available. Enable the `spin-lock` feature in `no_std` mode, or use the `.answers` API instead of `.returns`."
-                        .to_string()
-                }
+                OutputError::OwnershipRequired => AssembleError::OwnershipRequired { path, label },
+                OutputError::NoMutexApi => AssembleError::NoMutexApi { path, label },
             });
         }
 
         let pattern_match_mode = builder.pattern_match_mode;
         let mock_type_id = info.type_id;
+        let optional = self.optional || builder.default_stub;
 
-        let call_pattern = self.new_call_pattern(builder);
+        let call_pattern = self.new_call_pattern(info, builder)?;
 
         match self.fn_mockers.entry(mock_type_id) {
             Entry::Occupied(mut entry) => {
                 if entry.get().pattern_match_mode != pattern_match_mode {
-                    return Err(
-                        format!(
-                            "A clause for {path} has already been registered as {old_mode:?}, but got re-registered as {new_mode:?}. They cannot be mixed for the same MockFn.",
-                            path = &entry.get().info.path,
-                            old_mode = entry.get().pattern_match_mode,
-                            new_mode = pattern_match_mode,
-                        ),
-                    );
+                    return Err(AssembleError::IncompatiblePatternMatchMode {
+                        path: info.path.to_string(),
+                        old_mode: entry.get().pattern_match_mode,
+                        new_mode: pattern_match_mode,
+                    });
                 }
 
-                entry.get_mut().call_patterns.push(call_pattern);
+                let fn_mocker = entry.get_mut();
+                fn_mocker.optional &= optional;
+                fn_mocker.call_patterns.push(call_pattern);
             }
             Entry::Vacant(entry) => {
                 entry.insert(FnMocker {
                     info,
                     pattern_match_mode,
                     call_patterns: vec![call_pattern],
+                    priority_order: vec![],
+                    optional,
                 });
             }
         }
 
         Ok(())
     }
+
+    fn push_fallback_mode(
+        &mut self,
+        info: MockFnInfo,
+        fallback: Fallback,
+    ) -> Result<(), AssembleError> {
+        match self.fallback_modes.entry(info.type_id) {
+            Entry::Occupied(entry) => Err(AssembleError::IncompatibleFallbackMode {
+                path: info.path.to_string(),
+                old_mode: entry.get().mode(),
+                new_mode: fallback.mode(),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(fallback);
+                Ok(())
+            }
+        }
+    }
+
+    fn push_max_debug_len(
+        &mut self,
+        info: MockFnInfo,
+        max_debug_len: usize,
+    ) -> Result<(), AssembleError> {
+        // the last override wins
+        self.max_debug_lens.insert(info.type_id, max_debug_len);
+        Ok(())
+    }
+
+    fn push_input_format(
+        &mut self,
+        info: MockFnInfo,
+        input_format: AnyBox,
+    ) -> Result<(), AssembleError> {
+        // the last override wins
+        self.input_formats.insert(info.type_id, input_format);
+        Ok(())
+    }
 }
 
 impl MockAssembler {
-    fn new_call_pattern(&mut self, builder: DynCallPatternBuilder) -> CallPattern {
+    fn new_call_pattern(
+        &mut self,
+        info: MockFnInfo,
+        builder: DynCallPatternBuilder,
+    ) -> Result<CallPattern, AssembleError> {
         let mut ordered_call_index_range: core::ops::Range<usize> = Default::default();
 
         if builder.pattern_match_mode == PatternMatchMode::InOrder {
-            let exact_calls = builder
-                .count_expectation
-                .exact_calls()
-                .expect("BUG: Inexact quantification of ordered call pattern.");
+            let exact_calls = builder.count_expectation.exact_calls().ok_or_else(|| {
+                AssembleError::MockHasNoExactExpectation {
+                    path: info.path.to_string(),
+                    label: builder.label.map(ToString::to_string),
+                }
+            })?;
 
             ordered_call_index_range.start = self.current_call_index;
             ordered_call_index_range.end = self.current_call_index + exact_calls.0;
@@ -94,11 +171,15 @@ impl MockAssembler {
             self.current_call_index = ordered_call_index_range.end;
         }
 
-        CallPattern {
+        Ok(CallPattern {
             input_matcher: builder.input_matcher,
             responders: builder.responders,
             ordered_call_index_range,
             call_counter: builder.count_expectation.into_counter(),
-        }
+            label: builder.label,
+            priority: builder.priority,
+            default_stub: builder.default_stub,
+            each_order: builder.each_order,
+        })
     }
 }
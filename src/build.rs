@@ -1,24 +1,28 @@
+use core::any::TypeId;
 use core::marker::PhantomData;
 
 use crate::alloc::vec;
-use crate::alloc::{String, ToString, Vec};
+use crate::alloc::{Arc, Box, String, ToString, Vec};
 use crate::call_pattern::*;
-use crate::fn_mocker::PatternMatchMode;
+use crate::fn_mocker::{Fallback, FallbackMode, PatternMatchMode};
 use crate::output::{IntoReturn, IntoReturnOnce, Return, ReturnDefault};
-use crate::private::{AnswerClosure, AnswerClosureInner};
+use crate::private::{AnswerClosure, AnswerClosureInner, DelegateTo, OutputMapFn};
 use crate::property::*;
-use crate::responder::{Answerer, DynResponder, IntoReturner};
+use crate::responder::{Answerer, DynResponder, IntoReturner, ResponseCondition};
 use crate::*;
 use dyn_builder::*;
 
 pub(crate) mod dyn_builder {
-    use crate::alloc::{vec, Vec};
+    use core::any::Any;
+    use core::sync::atomic::AtomicUsize;
+
+    use crate::alloc::{vec, Arc, Box, Vec};
     use crate::output::OutputError;
-    use crate::responder::Returner;
+    use crate::responder::{IntermittentFailure, ResponseCondition, Returner};
     use crate::MockFn;
 
     use crate::{
-        call_pattern::{DynCallOrderResponder, DynInputMatcher},
+        call_pattern::{DynCallOrderResponder, DynInputMatcher, EachOrder, PendingPolls},
         counter,
         fn_mocker::PatternMatchMode,
         responder::DynResponder,
@@ -32,6 +36,18 @@ pub(crate) mod dyn_builder {
         pub(crate) count_expectation: counter::CallCountExpectation,
         pub(crate) current_response_index: usize,
         pub(crate) responder_error: Option<OutputError>,
+        /// The next pushed responder becomes a fallback of the previous one
+        pub(crate) push_as_fallback: bool,
+        pub(crate) label: Option<&'static str>,
+        pub(crate) priority: u32,
+        /// Condition applied to the next pushed responder
+        pub(crate) pending_condition: Option<ResponseCondition>,
+        /// Pending polls applied to the next pushed responder
+        pub(crate) pending_polls: Option<PendingPolls>,
+        /// Whether the pattern is part of a default stub, see [crate::call_pattern::CallPattern::default_stub]
+        pub(crate) default_stub: bool,
+        /// Position in an [Each](crate::build::Each) with [strict_order](crate::build::Each::strict_order)
+        pub(crate) each_order: Option<EachOrder>,
     }
 
     impl DynCallPatternBuilder {
@@ -46,6 +62,13 @@ pub(crate) mod dyn_builder {
                 count_expectation: Default::default(),
                 current_response_index: 0,
                 responder_error: None,
+                push_as_fallback: false,
+                label: None,
+                priority: 0,
+                pending_condition: None,
+                pending_polls: None,
+                default_stub: false,
+                each_order: None,
             }
         }
     }
@@ -87,6 +110,7 @@ pub(crate) mod dyn_builder {
                 Ok(responder) => self.push_responder(responder.into_dyn_responder()),
                 Err(error) => {
                     let dyn_builder = self.inner_mut();
+                    dyn_builder.push_as_fallback = false;
                     if dyn_builder.responder_error.is_none() {
                         dyn_builder.responder_error = Some(error);
                     }
@@ -96,12 +120,83 @@ pub(crate) mod dyn_builder {
 
         pub fn push_responder(&mut self, responder: DynResponder) {
             let dyn_builder = self.inner_mut();
+            let responder = match dyn_builder.pending_condition.take() {
+                Some(condition) => DynResponder::Conditional(condition, Box::new(responder)),
+                None => responder,
+            };
+            if core::mem::take(&mut dyn_builder.push_as_fallback) {
+                if let Some(last) = dyn_builder.responders.last_mut() {
+                    last.responder.push_fallback(responder);
+                    return;
+                }
+            }
             dyn_builder.responders.push(DynCallOrderResponder {
                 response_index: dyn_builder.current_response_index,
                 responder,
+                delay: None,
+                pending_polls: dyn_builder.pending_polls.take(),
             })
         }
 
+        /// Note: must be called after `push_responder`
+        #[cfg(feature = "mock-tokio-1")]
+        pub fn set_delay(&mut self, delay: core::time::Duration) {
+            if let Some(responder) = self.inner_mut().responders.last_mut() {
+                responder.delay = Some(delay);
+            }
+        }
+
+        /// Note: must be called after `push_responder`
+        pub fn set_condition(&mut self, condition: ResponseCondition) {
+            if let Some(last) = self.inner_mut().responders.last_mut() {
+                let inner = core::mem::replace(&mut last.responder, DynResponder::Unmock);
+                last.responder = DynResponder::Conditional(condition, Box::new(inner));
+            }
+        }
+
+        /// Note: must be called after `push_responder`
+        pub fn set_intermittent_failure<F: MockFn>(
+            &mut self,
+            every: usize,
+            failure: Result<Returner<F>, OutputError>,
+        ) {
+            let failure = match failure {
+                Ok(failure) => failure.into_dyn_responder(),
+                Err(error) => {
+                    let dyn_builder = self.inner_mut();
+                    if dyn_builder.responder_error.is_none() {
+                        dyn_builder.responder_error = Some(error);
+                    }
+                    return;
+                }
+            };
+            if let Some(last) = self.inner_mut().responders.last_mut() {
+                let success = core::mem::replace(&mut last.responder, DynResponder::Unmock);
+                last.responder = DynResponder::Intermittent(IntermittentFailure {
+                    every,
+                    calls: AtomicUsize::new(0),
+                    failure: Box::new(failure),
+                    success: Box::new(success),
+                });
+            }
+        }
+
+        /// Note: must be called after `push_responder`
+        #[track_caller]
+        pub fn map_unmocked(&mut self, map_fn: Arc<dyn Any + Send + Sync>) {
+            match self.inner_mut().responders.last_mut() {
+                Some(last) if matches!(last.responder, DynResponder::Unmock) => {
+                    last.responder = DynResponder::UnmockMap(map_fn);
+                }
+                _ => panic!("map_output must directly follow applies_unmocked"),
+            }
+        }
+
+        /// Note: must be called after `push_responder`
+        pub fn prepare_fallback(&mut self) {
+            self.inner_mut().push_as_fallback = true;
+        }
+
         /// Note: must be called after `push_responder`
         pub fn quantify(&mut self, times: usize, exactness: counter::Exactness) {
             let builder = self.inner_mut();
@@ -120,8 +215,11 @@ pub(crate) mod dyn_builder {
 }
 
 /// Builder for defining a series of cascading call patterns on a specific [MockFn].
+#[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
 pub struct Each<F: MockFn> {
     patterns: Vec<dyn_builder::DynCallPatternBuilder>,
+    input_recorder: Option<InputRecorder<F>>,
+    strict_order: bool,
     mock_fn: PhantomData<F>,
 }
 
@@ -134,6 +232,7 @@ where
     /// The new call pattern will be matched after any previously defined call patterns on the same [Each] instance.
     ///
     /// The method returns a [DefineMultipleResponses], which is used to define how unimock responds to the matched call.
+    #[track_caller]
     pub fn call<'e>(
         &'e mut self,
         matching_fn: &dyn Fn(&mut Matching<F>),
@@ -150,24 +249,304 @@ where
         }
     }
 
+    /// Create a clause for `F` by defining call patterns in the passed setup function.
+    ///
+    /// This is equivalent to [MockFn::stub], but names the [MockFn] as a type parameter instead of a receiver.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn func(&self, arg: i32) -> i32;
+    /// }
+    ///
+    /// let clause = build::Each::<TraitMock::func>::from_fn(|each| {
+    ///     each.call(matching!(1)).returns(2);
+    ///     each.call(matching!(_)).returns(0);
+    /// });
+    ///
+    /// let u = Unimock::new(clause);
+    /// assert_eq!(2, u.func(1));
+    /// assert_eq!(0, u.func(42));
+    /// ```
+    pub fn from_fn(setup: impl FnOnce(&mut Self)) -> Self {
+        let mut each = Self::new();
+        setup(&mut each);
+        each
+    }
+
+    /// Require the call patterns of this [Each] to be matched in the order they were defined, including ones defined later.
+    ///
+    /// Only one call pattern is tried at a time, and the next one takes over once it has been matched its exact number of calls,
+    /// so each call pattern must be exactly quantified.
+    /// The order only applies within this [Each]: calls to other [MockFn]s, and to call patterns of other clauses, may come in between.
+    /// A call that comes when no call pattern of this [Each] is left fails like any call that matches no call pattern.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn func(&self, arg: i32) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(TraitMock::func.stub(|each| {
+    ///     each.strict_order();
+    ///     each.call(matching!(1)).returns(10).n_times(2);
+    ///     each.call(matching!(2)).returns(20).once();
+    /// }));
+    ///
+    /// assert_eq!(10, u.func(1));
+    /// assert_eq!(10, u.func(1));
+    /// assert_eq!(20, u.func(2));
+    /// ```
+    pub fn strict_order(&mut self) -> &mut Self {
+        self.strict_order = true;
+        self
+    }
+
+    /// Record the inputs of every call answered by any of the call patterns of this [Each], including ones defined later.
+    ///
+    /// The returned [InputTracker] gives access to the recorded inputs, also after the clause has been passed to [Unimock].
+    /// Tracking requires a [MockFn] whose inputs don't borrow anything.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn func(&self, arg: i32) -> i32;
+    /// }
+    ///
+    /// let mut each = TraitMock::func.stub(|each| {
+    ///     each.call(matching!(_)).returns(0);
+    /// });
+    /// let tracker = each.track_inputs();
+    ///
+    /// let u = Unimock::new(each);
+    /// u.func(1);
+    /// u.func(2);
+    ///
+    /// tracker.assert_called_with(&2);
+    /// assert_eq!(vec![1, 2], tracker.all_calls());
+    /// ```
+    #[cfg(any(feature = "std", feature = "spin-lock"))]
+    pub fn track_inputs<I>(&mut self) -> InputTracker<F>
+    where
+        F: for<'i> MockFn<Inputs<'i> = I>,
+        I: Clone + Send + 'static,
+    {
+        let calls = alloc::Arc::new(private::MutexIsh::new(vec![]));
+        let recorded_calls = calls.clone();
+        self.input_recorder = Some(InputRecorder(alloc::Arc::new(move |inputs: &I| {
+            recorded_calls.locked(|calls| calls.push(inputs.clone()));
+        })));
+
+        InputTracker { calls }
+    }
+
     pub(crate) fn new() -> Self {
         Self {
             patterns: vec![],
+            input_recorder: None,
+            strict_order: false,
             mock_fn: PhantomData,
         }
     }
 }
 
+/// Shorthands for the most common call patterns defined on an [Each].
+///
+/// Every method defines the next call pattern, exactly like [Each::call] followed by a response and a quantifier.
+///
+/// # Example
+/// ```
+/// # use unimock::*;
+/// #[unimock(api=TraitMock)]
+/// trait Trait {
+///     fn func(&self, arg: i32) -> i32;
+/// }
+///
+/// let u = Unimock::new(TraitMock::func.stub(|each| {
+///     each.once_returns(matching!(1), 10);
+///     each.n_times_returns(2, matching!(2), 20);
+///     each.always_returns(matching!(_), 0);
+/// }));
+///
+/// assert_eq!(10, u.func(1));
+/// assert_eq!(20, u.func(2));
+/// assert_eq!(20, u.func(2));
+/// assert_eq!(0, u.func(3));
+/// ```
+pub trait EachExt<F: MockFn> {
+    /// Shorthand for `.call(matching_fn).returns(value).once()`.
+    fn once_returns<T>(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>;
+
+    /// Shorthand for `.call(matching_fn).answers(answer_fn).once()`.
+    fn once_answers(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        answer_fn: &'static F::AnswerFn,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact>;
+
+    /// Shorthand for `.call(matching_fn).returns(value).n_times(times)`.
+    fn n_times_returns<T>(
+        &mut self,
+        times: usize,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>;
+
+    /// Shorthand for `.call(matching_fn).returns(value)`.
+    fn always_returns<T>(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> Quantify<'_, F, InAnyOrder>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>;
+}
+
+impl<F> EachExt<F> for Each<F>
+where
+    F: MockFn + 'static,
+{
+    #[track_caller]
+    fn once_returns<T>(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.call(matching_fn).returns(value).once()
+    }
+
+    #[track_caller]
+    fn once_answers(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        answer_fn: &'static F::AnswerFn,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact> {
+        self.call(matching_fn).answers(answer_fn).once()
+    }
+
+    #[track_caller]
+    fn n_times_returns<T>(
+        &mut self,
+        times: usize,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> QuantifiedResponse<'_, F, InAnyOrder, Exact>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.call(matching_fn).returns(value).n_times(times)
+    }
+
+    #[track_caller]
+    fn always_returns<T>(
+        &mut self,
+        matching_fn: &dyn Fn(&mut Matching<F>),
+        value: T,
+    ) -> Quantify<'_, F, InAnyOrder>
+    where
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.call(matching_fn).returns(value)
+    }
+}
+
+/// Access to the inputs recorded through [Each::track_inputs].
+///
+/// Inputs are recorded in call order, from all clones of the [Unimock] instance.
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+pub struct InputTracker<F: MockFn> {
+    calls: alloc::Arc<private::MutexIsh<Vec<F::Inputs<'static>>>>,
+}
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+impl<F: MockFn> InputTracker<F>
+where
+    F::Inputs<'static>: Clone,
+{
+    /// The inputs of all calls recorded so far.
+    pub fn all_calls(&self) -> Vec<F::Inputs<'static>> {
+        self.calls.locked(|calls| calls.clone())
+    }
+
+    /// Assert that at least one recorded call had the given inputs.
+    #[track_caller]
+    pub fn assert_called_with(&self, expected_inputs: &F::Inputs<'static>)
+    where
+        F::Inputs<'static>: PartialEq + core::fmt::Debug,
+    {
+        let calls = self.all_calls();
+        if !calls.iter().any(|inputs| inputs == expected_inputs) {
+            panic!(
+                "{}: Expected a call with inputs {:?}, but the recorded calls were {:?}.",
+                F::info().path,
+                expected_inputs,
+                calls
+            );
+        }
+    }
+}
+
 impl<F> Clause for Each<F>
 where
     F: MockFn,
 {
-    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), String> {
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
         if self.patterns.is_empty() {
-            return Err("Stub contained no call patterns".to_string());
+            return Err(AssembleError::EmptyStub {
+                path: F::info().path.to_string(),
+            });
         }
 
-        for builder in self.patterns.into_iter() {
+        let order_cursor = self
+            .strict_order
+            .then(|| Arc::new(core::sync::atomic::AtomicUsize::new(0)));
+        let mut next_position = 0;
+
+        for mut builder in self.patterns.into_iter() {
+            if let Some(input_recorder) = &self.input_recorder {
+                builder.input_matcher.input_recorder =
+                    Some(DynInputRecorder(Box::new(input_recorder.clone())));
+            }
+            if let Some(cursor) = &order_cursor {
+                let exact_calls = builder.count_expectation.exact_calls().ok_or_else(|| {
+                    AssembleError::MockHasNoExactExpectation {
+                        path: F::info().path.to_string(),
+                        label: builder.label.map(ToString::to_string),
+                    }
+                })?;
+
+                let position = (exact_calls.0 > 0).then(|| {
+                    next_position += 1;
+                    next_position - 1
+                });
+                builder.each_order = Some(EachOrder {
+                    cursor: cursor.clone(),
+                    position,
+                });
+            }
             sink.push(F::info(), builder)?;
         }
 
@@ -175,7 +554,480 @@ where
     }
 }
 
+/// A clause that overrides how a specific [MockFn] behaves when a call is not matched by any call pattern.
+///
+/// Created by [MockFn::spy_fallback], [MockFn::strict] or [MockFn::must_be_mocked].
+pub struct FallbackOverride<F: MockFn> {
+    fallback: Fallback,
+    mock_fn: PhantomData<F>,
+}
+
+impl<F: MockFn> FallbackOverride<F> {
+    pub(crate) fn new(fallback: Fallback) -> Self {
+        Self {
+            fallback,
+            mock_fn: PhantomData,
+        }
+    }
+}
+
+impl<F: MockFn> Clone for FallbackOverride<F> {
+    fn clone(&self) -> Self {
+        Self::new(self.fallback)
+    }
+}
+
+impl<F> Clause for FallbackOverride<F>
+where
+    F: MockFn,
+{
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
+        sink.push_fallback_mode(F::info(), self.fallback)
+    }
+}
+
+/// A clause that overrides the maximum length of the debug representation of a specific [MockFn]'s inputs.
+///
+/// Created by [MockFn::max_debug_len].
+pub struct MaxDebugLenOverride<F: MockFn> {
+    max_debug_len: usize,
+    mock_fn: PhantomData<F>,
+}
+
+impl<F: MockFn> MaxDebugLenOverride<F> {
+    pub(crate) fn new(max_debug_len: usize) -> Self {
+        Self {
+            max_debug_len,
+            mock_fn: PhantomData,
+        }
+    }
+}
+
+impl<F> Clause for MaxDebugLenOverride<F>
+where
+    F: MockFn,
+{
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
+        sink.push_max_debug_len(F::info(), self.max_debug_len)
+    }
+}
+
+/// A clause that replaces how a specific [MockFn]'s inputs are rendered in error messages and call records.
+///
+/// Created by [MockFn::with_input_format].
+pub struct InputFormatOverride<F: MockFn> {
+    input_format: debug::InputFormat<F>,
+}
+
+impl<F: MockFn> InputFormatOverride<F> {
+    pub(crate) fn new(input_format: debug::InputFormat<F>) -> Self {
+        Self { input_format }
+    }
+}
+
+impl<F> Clause for InputFormatOverride<F>
+where
+    F: MockFn,
+{
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
+        sink.push_input_format(F::info(), Box::new(self.input_format))
+    }
+}
+
+/// A clause made up of any number of other clauses, created by combining clauses with `|`.
+///
+/// Unlike a tuple, the type does not change when more clauses are added,
+/// so a test helper can return a set of default clauses that each test extends with `|=`.
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// # use unimock::build::Clauses;
+/// #[unimock(api=FooMock)]
+/// trait Foo {
+///     fn foo(&self) -> i32;
+///     fn bar(&self) -> i32;
+/// }
+///
+/// fn default_clauses() -> Clauses {
+///     FooMock::foo.each_call(matching!()).returns(1) | FooMock::bar.each_call(matching!()).returns(2)
+/// }
+///
+/// let mut clauses = default_clauses();
+/// clauses |= FooMock::foo.each_call(matching!()).returns(3);
+///
+/// let u = Unimock::new(clauses);
+/// assert_eq!(3, u.foo() + u.bar());
+/// ```
+#[derive(Default)]
+pub struct Clauses {
+    #[allow(clippy::type_complexity)]
+    clauses: Vec<Box<dyn FnOnce(&mut dyn clause::term::Sink) -> Result<(), AssembleError>>>,
+}
+
+impl Clauses {
+    /// Create an empty set of clauses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a clause.
+    pub fn push(&mut self, clause: impl Clause + 'static) {
+        self.clauses
+            .push(Box::new(move |sink| clause.deconstruct(sink)));
+    }
+
+    /// Move all clauses into `target`, after the clauses it already has.
+    ///
+    /// Useful for test utilities that build sets of clauses independently, and compose them before creating a [Unimock].
+    /// The call patterns of the two sets are merged per [MockFn], just like when combining the sets with `|`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// # use unimock::build::Clauses;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self, a: i32) -> i32;
+    /// }
+    ///
+    /// let mut target = Clauses::new();
+    /// target.push(FooMock::foo.each_call(matching!(1)).returns(10));
+    ///
+    /// let mut other = Clauses::new();
+    /// other.push(FooMock::foo.each_call(matching!(2)).returns(20));
+    /// other.merge_into(&mut target);
+    ///
+    /// let u = Unimock::new(target);
+    /// assert_eq!(30, u.foo(1) + u.foo(2));
+    /// ```
+    pub fn merge_into(self, target: &mut Clauses) {
+        target.clauses.extend(self.clauses);
+    }
+}
+
+impl Clause for Clauses {
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
+        for clause in self.clauses {
+            clause(sink)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Clause + 'static> core::ops::BitOr<C> for Clauses {
+    type Output = Clauses;
+
+    fn bitor(mut self, rhs: C) -> Clauses {
+        self.push(rhs);
+        self
+    }
+}
+
+impl<C: Clause + 'static> core::ops::BitOrAssign<C> for Clauses {
+    fn bitor_assign(&mut self, rhs: C) {
+        self.push(rhs);
+    }
+}
+
+/// A base clause combined with an overriding clause, created by [Clause::with_override].
+///
+/// The unordered call patterns of the override are tried before the ones of the base.
+pub struct WithOverride<B, O> {
+    pub(crate) base: B,
+    pub(crate) override_: O,
+}
+
+impl<B: Clause, O: Clause> Clause for WithOverride<B, O> {
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
+        let mut base_sink = clause::PrioritySink::new(sink, 0);
+        self.base.deconstruct(&mut base_sink)?;
+        let override_priority = base_sink.max_priority() + 1;
+
+        self.override_
+            .deconstruct(&mut clause::PrioritySink::new(sink, override_priority))
+    }
+}
+
+/// Implement `|` for a terminal clause type, producing [Clauses].
+macro_rules! clause_bitor_impl {
+    ($ty:ty, [$($params:tt)*], [$($bounds:tt)*]) => {
+        impl<$($params)*, C> core::ops::BitOr<C> for $ty
+        where
+            Self: Clause + 'static,
+            C: Clause + 'static,
+            $($bounds)*
+        {
+            type Output = Clauses;
+
+            fn bitor(self, rhs: C) -> Clauses {
+                Clauses::new() | self | rhs
+            }
+        }
+    };
+}
+
+clause_bitor_impl!(Each<F>, [F: MockFn], []);
+clause_bitor_impl!(FallbackOverride<F>, [F: MockFn], []);
+clause_bitor_impl!(MaxDebugLenOverride<F>, [F: MockFn], []);
+clause_bitor_impl!(InputFormatOverride<F>, [F: MockFn], []);
+clause_bitor_impl!(WithOverride<B, O>, [B, O], []);
+clause_bitor_impl!(Quantify<'p, F, O>, ['p, F: MockFn, O], []);
+clause_bitor_impl!(QuantifiedResponse<'p, F, O, R>, ['p, F: MockFn, O, R], []);
+clause_bitor_impl!(
+    QuantifyReturnValue<'p, F, T, O>,
+    ['p, F: MockFn, T, O],
+    [T: IntoReturnOnce<F::OutputKind>, <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,]
+);
+
+/// Builder for a [Unimock] instance, created by [Unimock::builder].
+///
+/// Every option has a default, so only the options that differ from [Unimock::new] need to be set.
+pub struct UnimockBuilder {
+    pub(crate) assembler: Result<assemble::MockAssembler, AssembleError>,
+    pub(crate) fallback_mode: FallbackMode,
+    pub(crate) counted_fns: Vec<TypeId>,
+    pub(crate) unmock_recursion_limit: usize,
+    pub(crate) max_debug_len: usize,
+}
+
+impl UnimockBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            assembler: Ok(assemble::MockAssembler::new()),
+            fallback_mode: FallbackMode::Error,
+            counted_fns: vec![],
+            unmock_recursion_limit: 256,
+            max_debug_len: crate::debug::DEFAULT_MAX_DEBUG_LEN,
+        }
+    }
+
+    /// Set what to do when a call is not matched by any call pattern.
+    ///
+    /// The default is [FallbackMode::Error].
+    pub fn fallback(mut self, fallback_mode: FallbackMode) -> Self {
+        self.fallback_mode = fallback_mode;
+        self
+    }
+
+    /// Add a [Clause] to the mock setup.
+    ///
+    /// This may be called several times, which has the same effect as passing all the clauses as one tuple.
+    pub fn clause(mut self, clause: impl Clause) -> Self {
+        if let Ok(assembler) = &mut self.assembler {
+            if let Err(error) = clause.deconstruct(assembler) {
+                self.assembler = Err(error);
+            }
+        }
+        self
+    }
+
+    pub(crate) fn set_optional(&mut self, optional: bool) {
+        if let Ok(assembler) = &mut self.assembler {
+            assembler.set_optional(optional);
+        }
+    }
+
+    /// Count the calls made to the [MockFn]s with the given type ids.
+    ///
+    /// The counts are available through [Unimock::call_count].
+    pub fn count_calls(mut self, fn_list: impl IntoIterator<Item = TypeId>) -> Self {
+        self.counted_fns.extend(fn_list);
+        self
+    }
+
+    /// Set how deeply the unmocked implementation of a single [MockFn] may recurse into itself.
+    ///
+    /// Exceeding the limit panics with an error naming the [MockFn], instead of overflowing the stack.
+    /// This typically happens when a clause for the base case of a recursive function is missing.
+    ///
+    /// With `std`, the depth is counted per thread, and an `async` unmocked implementation only counts
+    /// while its future is created, not while it is awaited.
+    ///
+    /// The default is 256.
+    pub fn unmock_recursion_limit(mut self, limit: usize) -> Self {
+        self.unmock_recursion_limit = limit;
+        self
+    }
+
+    /// Set the maximum length, in bytes, of the debug representation of a single input in error messages.
+    ///
+    /// Longer representations are cut off and end with a note about how many bytes were elided.
+    /// [MockFn::max_debug_len] overrides the limit for a single [MockFn].
+    ///
+    /// The default is 4096.
+    pub fn max_debug_len(mut self, max_len: usize) -> Self {
+        self.max_debug_len = max_len;
+        self
+    }
+
+    /// Build the configured [Unimock] instance.
+    ///
+    /// # Panics
+    /// Panics if any of the passed clauses were invalid.
+    #[track_caller]
+    pub fn build(self) -> Unimock {
+        match self.try_build() {
+            Ok(unimock) => unimock,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Build the configured [Unimock] instance, or return the first error found in the passed clauses.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self) -> i32;
+    /// }
+    ///
+    /// let error = Unimock::builder()
+    ///     .clause(FooMock::foo.stub(|_| {}))
+    ///     .try_build()
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(error, AssembleError::EmptyStub { path } if path.ends_with("Foo::foo")));
+    /// ```
+    pub fn try_build(self) -> Result<Unimock, AssembleError> {
+        Ok(Unimock::from_assembly(
+            self.assembler?.finish(),
+            self.fallback_mode,
+            self.counted_fns,
+            self.unmock_recursion_limit,
+            self.max_debug_len,
+        ))
+    }
+}
+
+/// A reusable template for [Unimock] instances sharing a common setup.
+///
+/// A fixture stores clause _factories_ instead of clauses, so each instance created by [Fixture::unimock] or [Fixture::builder]
+/// gets freshly built call patterns, with its own call counters and its own verification.
+/// A fixture is [Send] and [Sync], so it can be stored in a `static`.
+///
+/// A [MockFn] set up only by the fixture does not need to be called.
+/// Other expectations, like exact call counts, still apply.
+/// When a test adds its own clause for the same [MockFn], that MockFn must be called as usual,
+/// and the fixture's call patterns are tried before the test's.
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// use std::sync::OnceLock;
+/// use unimock::build::Fixture;
+///
+/// #[unimock(api=ClockMock)]
+/// trait Clock {
+///     fn now(&self) -> u64;
+/// }
+///
+/// #[unimock(api=AuthMock)]
+/// trait Auth {
+///     fn authorize(&self, user: &str) -> bool;
+/// }
+///
+/// #[unimock(api=StoreMock)]
+/// trait Store {
+///     fn load(&self, key: &str) -> Option<String>;
+/// }
+///
+/// fn fixture() -> &'static Fixture {
+///     static FIXTURE: OnceLock<Fixture> = OnceLock::new();
+///     FIXTURE.get_or_init(|| {
+///         Fixture::new()
+///             .with_fn(|| ClockMock::now.each_call(matching!()).returns(0_u64))
+///             .with_fn(|| AuthMock::authorize.each_call(matching!(_)).returns(true))
+///     })
+/// }
+///
+/// // Only uses the clock:
+/// let u = fixture().unimock();
+/// assert_eq!(0, u.now());
+///
+/// // Adds a test-specific clause on top of the fixture:
+/// let u = fixture()
+///     .builder()
+///     .clause(StoreMock::load.next_call(matching!("key")).returns(None))
+///     .build();
+/// assert!(u.authorize("alice"));
+/// assert_eq!(None, u.load("key"));
+/// ```
+pub struct Fixture {
+    #[allow(clippy::type_complexity)]
+    clause_fns: Vec<Box<dyn Fn(UnimockBuilder) -> UnimockBuilder + Send + Sync>>,
+}
+
+impl Fixture {
+    /// Create an empty fixture.
+    pub fn new() -> Self {
+        Self { clause_fns: vec![] }
+    }
+
+    /// Add a clause that can be cloned into every instance.
+    ///
+    /// Most call pattern clauses can't be cloned. Use [Fixture::with_fn] for those.
+    pub fn with<C>(self, clause: C) -> Self
+    where
+        C: Clause + Clone + Send + Sync + 'static,
+    {
+        self.with_fn(move || clause.clone())
+    }
+
+    /// Add a function that creates clauses anew for every instance.
+    pub fn with_fn<C, CF>(mut self, clause_fn: CF) -> Self
+    where
+        C: Clause,
+        CF: Fn() -> C + Send + Sync + 'static,
+    {
+        self.clause_fns
+            .push(Box::new(move |builder| builder.clause(clause_fn())));
+        self
+    }
+
+    /// Create a [UnimockBuilder] with the fixture's clauses, for adding test-specific setup.
+    pub fn builder(&self) -> UnimockBuilder {
+        let mut builder = UnimockBuilder::new();
+        builder.set_optional(true);
+        for clause_fn in &self.clause_fns {
+            builder = clause_fn(builder);
+        }
+        builder.set_optional(false);
+        builder
+    }
+
+    /// Create a [Unimock] instance with only the fixture's clauses.
+    ///
+    /// # Panics
+    /// Panics if any of the fixture's clauses were invalid.
+    #[track_caller]
+    pub fn unimock(&self) -> Unimock {
+        self.builder().build()
+    }
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A matched call pattern, ready for defining a single response.
+///
+/// A call pattern without a response is not set up, so an unused [DefineResponse] is warned about:
+///
+/// ```compile_fail
+/// # #![deny(unused_must_use)]
+/// # use unimock::*;
+/// #[unimock(api=TraitMock)]
+/// trait Trait {
+///     fn func(&self) -> i32;
+/// }
+///
+/// TraitMock::func.next_call(matching!()); // the response is missing
+/// ```
+#[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
 pub struct DefineResponse<'p, F: MockFn, O: Ordering> {
     wrapper: DynBuilderWrapper<'p>,
     mock_fn: PhantomData<F>,
@@ -222,9 +1074,78 @@ impl<'p, F: MockFn, O: Ordering> DefineResponse<'p, F, O> {
             ordering: self.ordering,
         }
     }
+
+    /// Specify the output of the call pattern as a box of the given value, which is returned exactly once.
+    ///
+    /// Unlike [Self::returns_boxed], this does not require the value to implement [Clone].
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// pub trait Handler {
+    ///     fn handle(&mut self) -> i32;
+    /// }
+    ///
+    /// impl_box_from!(Handler);
+    ///
+    /// struct Counter(i32);
+    ///
+    /// impl Handler for Counter {
+    ///     fn handle(&mut self) -> i32 {
+    ///         self.0 += 1;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// #[unimock(api=FactoryMock)]
+    /// trait Factory {
+    ///     fn make(&self) -> Box<dyn Handler>;
+    /// }
+    ///
+    /// let u = Unimock::new(FactoryMock::make.next_call(matching!()).returns_boxed_once(Counter(0)));
+    ///
+    /// let mut handler = u.make();
+    /// assert_eq!(1, handler.handle());
+    /// assert_eq!(2, handler.handle());
+    /// ```
+    pub fn returns_boxed_once<B, T>(mut self, value: T) -> QuantifiedResponse<'p, F, O, Exact>
+    where
+        F: MockFn<OutputKind = crate::output::Owning<B>>,
+        B: crate::output::BoxFrom<T> + 'static,
+        T: Send + Sync + 'static,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.wrapper.push_returner_result::<F>(
+            crate::output::owning::Owned::boxed_once(value).map(|r| r.into_returner()),
+        );
+        self.wrapper.quantify(1, counter::Exactness::Exact);
+        QuantifiedResponse {
+            wrapper: self.wrapper,
+            mock_fn: PhantomData,
+            ordering: self.ordering,
+            _repetition: Exact,
+        }
+    }
 }
 
 /// A matched call pattern, ready for defining multiple response, requiring return values to implement [Clone].
+///
+/// A call pattern without a response is not set up, so an unused [DefineMultipleResponses] is warned about:
+///
+/// ```compile_fail
+/// # #![deny(unused_must_use)]
+/// # use unimock::*;
+/// #[unimock(api=TraitMock)]
+/// trait Trait {
+///     fn func(&self, arg: i32) -> i32;
+/// }
+///
+/// let u = Unimock::new(TraitMock::func.stub(|each| {
+///     each.call(matching!(1)); // the response is missing
+///     each.call(matching!(_)).returns(0);
+/// }));
+/// ```
+#[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
 pub struct DefineMultipleResponses<'p, F: MockFn, O: Ordering> {
     wrapper: DynBuilderWrapper<'p>,
     mock_fn: PhantomData<F>,
@@ -242,6 +1163,10 @@ where
     ///
     /// The value can be anything that can be converted [Into] the mock function output.
     ///
+    /// An owned output is cloned for every call. For large values, consider letting the method output an `Arc<T>`:
+    /// A plain `T` passed to this method is converted into an `Arc` once, and only the `Arc` gets cloned per call.
+    /// Outputs that are references are lent out from the stored value, without any cloning.
+    ///
     /// # Example
     /// ```
     /// # use unimock::*;
@@ -268,6 +1193,81 @@ where
             .push_returner_result(value.into_return().map(|r| r.into_returner()));
         self.quantify()
     }
+
+    /// Make every `n`-th call matching this call pattern return `Err(error)`.
+    ///
+    /// Useful for testing retry and resilience logic against a dependency that fails intermittently.
+    /// The other calls are answered by the response defined using [FailsEvery::then_returns].
+    /// Failing and succeeding calls are both counted towards the quantification of the call pattern.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=FetchMock)]
+    /// trait Fetch {
+    ///     fn fetch(&self) -> Result<i32, String>;
+    /// }
+    ///
+    /// let u = Unimock::new(
+    ///     FetchMock::fetch
+    ///         .each_call(matching!())
+    ///         .fails_every(3, "unavailable".to_string())
+    ///         .then_returns(42)
+    ///         .n_times(4),
+    /// );
+    ///
+    /// assert_eq!(Ok(42), u.fetch());
+    /// assert_eq!(Ok(42), u.fetch());
+    /// assert_eq!(Err("unavailable".to_string()), u.fetch());
+    /// assert_eq!(Ok(42), u.fetch());
+    /// ```
+    #[track_caller]
+    pub fn fails_every<T, E>(self, n: usize, error: E) -> FailsEvery<'p, F, O, E>
+    where
+        F: MockFn<OutputKind = output::Owning<Result<T, E>>>,
+    {
+        assert!(n > 0, "fails_every requires a non-zero period");
+        FailsEvery {
+            define: self,
+            every: n,
+            error,
+        }
+    }
+}
+
+/// A call pattern failing intermittently, created by [DefineMultipleResponses::fails_every].
+#[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
+pub struct FailsEvery<'p, F: MockFn, O: Ordering, E> {
+    define: DefineMultipleResponses<'p, F, O>,
+    every: usize,
+    error: E,
+}
+
+impl<'p, F, O, E> FailsEvery<'p, F, O, E>
+where
+    F: MockFn + 'static,
+    O: Ordering,
+{
+    /// Return `Ok(value)` from the calls that don't fail.
+    pub fn then_returns<T, V>(self, value: V) -> Quantify<'p, F, O>
+    where
+        F: MockFn<OutputKind = output::Owning<Result<T, E>>>,
+        T: Clone + Send + Sync + 'static,
+        E: Clone + Send + Sync + 'static,
+        V: Into<T>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        let mut quantify = self.define.returns(Ok::<T, E>(value.into()));
+        quantify.wrapper.set_intermittent_failure::<F>(
+            self.every,
+            IntoReturn::<F::OutputKind>::into_return(Err::<T, E>(self.error))
+                .map(|r| r.into_returner()),
+        );
+        quantify
+    }
 }
 
 macro_rules! define_response_common_impl {
@@ -293,6 +1293,12 @@ macro_rules! define_response_common_impl {
                 }
             }
 
+            /// Name the call pattern, see [Quantify::named].
+            pub fn named(mut self, label: &'static str) -> Self {
+                self.wrapper.inner_mut().label = Some(label);
+                self
+            }
+
             /// Specify the response of the call pattern by calling `Default::default()`.
             ///
             /// # Example
@@ -430,46 +1436,236 @@ assert_eq!(55, u.fib(10));
                 self.quantify()
             }
 
-            /// Specify the response of the call pattern by invoking the given closure that can then compute it based on input parameters.
+            /// Specify the response of the call pattern by invoking the given closure that can then compute it based on input parameters.
+            #[doc = concat!("\
+```
+# use unimock::*;
+use std::sync::{Arc, Mutex};
+
+#[unimock(api=TraitMock)]
+trait Trait {
+    fn get(&self) -> i32;
+}
+
+let mutex: Arc<Mutex<i32>> = Arc::new(Mutex::new(0));
+
+let u = Unimock::new(
+    TraitMock::get
+        .each_call(matching!())
+        .answers_arc({
+            let mutex = mutex.clone();
+            Arc::new(move |_| {
+                *mutex.lock().unwrap()
+            })
+        })
+);
+
+assert_eq!(0, u.get());
+*mutex.lock().unwrap() = 42;
+assert_eq!(42, u.get());
+```
+",
+            )]
+            pub fn answers_arc(
+                mut self,
+                answer_fn: crate::alloc::Arc<F::AnswerFn>,
+            ) -> Quantify<'p, F, O> {
+                self.wrapper
+                    .push_responder(Answerer::<F> { answer_closure: AnswerClosure(AnswerClosureInner::Arc(answer_fn)) }.into_dyn_responder());
+                self.quantify()
+            }
+
+            /// Specify the response of the call pattern by forwarding the call to `instance`, another implementation of the mocked trait.
+            ///
+            /// This is useful for reusing a hand-written fake, while keeping the call counting and verification of unimock.
+            /// Only available for `&self` methods that have no generic parameters, that are not `async`,
+            /// and whose output is an owned value without borrows.
+            /// The signature may not mention `Self`.
+            #[doc = concat!("\
+```
+# use unimock::*;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+#[unimock(api=RepoMock)]
+trait Repo {
+    fn get(&self, id: u32) -> Option<String>;
+    fn insert(&self, id: u32, name: String);
+}
+
+#[derive(Default)]
+struct InMemoryRepo(Mutex<BTreeMap<u32, String>>);
+
+impl Repo for InMemoryRepo {
+    fn get(&self, id: u32) -> Option<String> {
+        self.0.lock().unwrap().get(&id).cloned()
+    }
+
+    fn insert(&self, id: u32, name: String) {
+        self.0.lock().unwrap().insert(id, name);
+    }
+}
+
+let fake = std::sync::Arc::new(InMemoryRepo::default());
+let u = Unimock::new((
+    RepoMock::insert.next_call(matching!(1, _)).delegates_to_arc(fake.clone()).once(),
+    RepoMock::get.each_call(matching!(_)).delegates_to_arc(fake),
+));
+
+u.insert(1, \"one\".to_string());
+assert_eq!(Some(\"one\".to_string()), u.get(1));
+assert_eq!(None, u.get(2));
+```
+",
+            )]
+            pub fn delegates_to<T>(self, instance: T) -> Quantify<'p, F, O>
+            where
+                F: DelegateTo<T>,
+            {
+                self.answers_arc(F::delegate_answer(crate::alloc::Arc::new(instance)))
+            }
+
+            /// Specify the response of the call pattern by forwarding the call to a shared `instance`, see [delegates_to](Self::delegates_to).
+            ///
+            /// Sharing the instance lets several call patterns forward to the same fake.
+            pub fn delegates_to_arc<T>(self, instance: crate::alloc::Arc<T>) -> Quantify<'p, F, O>
+            where
+                F: DelegateTo<T>,
+            {
+                self.answers_arc(F::delegate_answer(instance))
+            }
+
+            /// Specify the response of the call pattern by forwarding the call to `other`, another [Unimock](crate::Unimock) instance.
+            ///
+            /// The call is evaluated against the clauses of `other` for the same [MockFn], and its output returned.
+            /// Calls forwarded this way count towards the verification of `other`,
+            /// which happens when the original instance of `other` is dropped, so forward to a clone of it.
+            /// This layers overrides on top of a shared base instance without rebuilding its clauses.
+            ///
+            /// Has the same restrictions as [delegates_to](Self::delegates_to).
+            /// A call that keeps being forwarded between instances panics once it exceeds the
+            /// [unmock_recursion_limit](crate::build::UnimockBuilder::unmock_recursion_limit) of the receiving instance.
             #[doc = concat!("\
 ```
 # use unimock::*;
-use std::sync::{Arc, Mutex};
-
-#[unimock(api=TraitMock)]
-trait Trait {
-    fn get(&self) -> i32;
+#[unimock(api=GreeterMock)]
+trait Greeter {
+    fn greet(&self, name: String) -> String;
 }
 
-let mutex: Arc<Mutex<i32>> = Arc::new(Mutex::new(0));
-
-let u = Unimock::new(
-    TraitMock::get
-        .each_call(matching!())
-        .answers_arc({
-            let mutex = mutex.clone();
-            Arc::new(move |_| {
-                *mutex.lock().unwrap()
-            })
-        })
+let base = Unimock::new(
+    GreeterMock::greet
+        .each_call(matching!(_))
+        .answers(&|_, name| format!(\"Hello, {name}!\")),
 );
+let u = Unimock::new((
+    GreeterMock::greet
+        .each_call(matching!(\"Bob\"))
+        .returns(\"Hi Bob\"),
+    GreeterMock::greet
+        .each_call(matching!(_))
+        .forwards_to(base.clone()),
+));
 
-assert_eq!(0, u.get());
-*mutex.lock().unwrap() = 42;
-assert_eq!(42, u.get());
+assert_eq!(\"Hi Bob\", u.greet(\"Bob\".to_string()));
+assert_eq!(\"Hello, Alice!\", u.greet(\"Alice\".to_string()));
 ```
 ",
             )]
-            pub fn answers_arc(
-                mut self,
-                answer_fn: crate::alloc::Arc<F::AnswerFn>,
-            ) -> Quantify<'p, F, O> {
+            pub fn forwards_to(self, other: crate::Unimock) -> Quantify<'p, F, O>
+            where
+                F: DelegateTo<crate::Unimock>,
+            {
+                self.delegates_to(other)
+            }
+
+            /// Specify the output of the call pattern as a box of the given value, for methods that return e.g. `Box<dyn Trait>`.
+            ///
+            /// The value is cloned and boxed afresh for every call, so the box itself does not have to implement [Clone].
+            /// The box type must implement [BoxFrom](crate::output::BoxFrom) for the value,
+            /// which is the case for `Box<dyn std::error::Error + Send + Sync>` and similar error boxes,
+            /// and can be implemented for other trait objects with [impl_box_from!](crate::impl_box_from).
+            ///
+            /// # Example
+            /// ```
+            /// # use unimock::*;
+            /// use std::error::Error;
+            ///
+            /// #[derive(Clone, Debug)]
+            /// struct Timeout;
+            ///
+            /// impl std::fmt::Display for Timeout {
+            ///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            ///         write!(f, "timeout")
+            ///     }
+            /// }
+            ///
+            /// impl Error for Timeout {}
+            ///
+            /// #[unimock(api=ConnMock)]
+            /// trait Conn {
+            ///     fn last_error(&self) -> Box<dyn Error + Send + Sync>;
+            /// }
+            ///
+            /// let u = Unimock::new(
+            ///     ConnMock::last_error
+            ///         .each_call(matching!())
+            ///         .returns_boxed(Timeout)
+            ///         .n_times(2)
+            /// );
+            ///
+            /// assert_eq!("timeout", u.last_error().to_string());
+            /// assert_eq!("timeout", u.last_error().to_string());
+            /// ```
+            pub fn returns_boxed<B, T>(mut self, value: T) -> Quantify<'p, F, O>
+            where
+                F: MockFn<OutputKind = crate::output::Owning<B>>,
+                B: crate::output::BoxFrom<T> + 'static,
+                T: Clone + Send + Sync + 'static,
+                <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+            {
                 self.wrapper
-                    .push_responder(Answerer::<F> { answer_closure: AnswerClosure(AnswerClosureInner::Arc(answer_fn)) }.into_dyn_responder());
+                    .push_returner_result::<F>(Ok(crate::output::owning::Owned::boxed(value).into_returner()));
+                self.quantify()
+            }
+
+            /// Specify the output of a call pattern returning `Cow<'_, T>` as [Cow::Owned](crate::alloc::Cow::Owned).
+            ///
+            /// `returns` stores the value and lends it out as [Cow::Borrowed](crate::alloc::Cow::Borrowed),
+            /// while this returns a new owned copy of it for every call.
+            ///
+            /// # Example
+            /// ```
+            /// # use unimock::*;
+            /// use std::borrow::Cow;
+            ///
+            /// #[unimock(api=UserMock)]
+            /// trait User {
+            ///     fn name(&self) -> Cow<'_, str>;
+            /// }
+            ///
+            /// let u = Unimock::new(UserMock::name.each_call(matching!()).returns_owned("alice"));
+            ///
+            /// assert!(matches!(u.name(), Cow::Owned(name) if name == "alice"));
+            /// ```
+            pub fn returns_owned<T, V>(mut self, value: V) -> Quantify<'p, F, O>
+            where
+                F: MockFn<OutputKind = crate::output::CowLending<T>>,
+                T: ?Sized + crate::alloc::ToOwned + 'static,
+                T::Owned: Send + Sync + 'static,
+                V: Into<T::Owned>,
+                <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+            {
+                self.wrapper.push_returner_result::<F>(Ok(
+                    crate::output::cow_lending::CowLent::<T>::owned(value.into()).into_returner(),
+                ));
                 self.quantify()
             }
 
             /// Prevent this call pattern from succeeding by explicitly panicking with a custom message.
+            ///
+            /// The panic is not recorded as a failure of the [Unimock](crate::Unimock) instance, so catching it keeps the instance verifiable.
+            /// A panic that nobody observes, e.g. on a detached thread, goes unreported.
             pub fn panics(mut self, message: impl Into<String>) -> Quantify<'p, F, O> {
                 let message = message.into();
                 self.wrapper
@@ -584,6 +1780,22 @@ where
     O: Copy,
     <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
 {
+    /// Name the call pattern, see [Quantify::named].
+    pub fn named(mut self, label: &'static str) -> Self {
+        self.wrapper.inner_mut().label = Some(label);
+        self
+    }
+
+    /// Only let this response take part in pattern matching while `predicate` returns `true`, see [Quantify::only_if].
+    pub fn only_if(mut self, predicate: impl Fn() -> bool + Send + Sync + 'static) -> Self
+    where
+        O: Ordering<Kind = InAnyOrder>,
+    {
+        // The return value is pushed by the quantifier, or when this builder is dropped
+        self.wrapper.inner_mut().pending_condition = Some(ResponseCondition(Box::new(predicate)));
+        self
+    }
+
     /// Expect this call pattern to be matched exactly once.
     ///
     /// This is the only quantifier that works together with return values that don't implement [Clone].
@@ -654,6 +1866,28 @@ where
             _repetition: AtLeast,
         }
     }
+
+    /// Define a response to fall back to when this return value has already been returned.
+    ///
+    /// See [Quantify::with_fallback].
+    pub fn with_fallback(mut self) -> DefineMultipleResponses<'p, F, O>
+    where
+        O: Ordering,
+    {
+        self.wrapper.push_returner_result(
+            self.return_value
+                .take()
+                .unwrap()
+                .into_return_once()
+                .map(|r| r.into_returner()),
+        );
+        self.wrapper.prepare_fallback();
+        DefineMultipleResponses {
+            wrapper: self.wrapper.steal(),
+            mock_fn: PhantomData,
+            ordering: self.ordering,
+        }
+    }
 }
 
 impl<'p, F, T, O> Clause for QuantifyReturnValue<'p, F, T, O>
@@ -663,7 +1897,7 @@ where
     O: Copy + Ordering,
     <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
 {
-    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), String> {
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
         self.once().deconstruct(sink)
     }
 }
@@ -725,6 +1959,163 @@ where
         }
     }
 
+    /// Delay the current response by the given duration.
+    ///
+    /// Useful for testing timeout and retry logic.
+    /// The delay is awaited with [tokio::time::sleep](tokio_1::time::sleep), so it must run on a tokio runtime,
+    /// and follows [tokio::time::pause](tokio_1::time::pause).
+    ///
+    /// Only `async` methods without a `&mut self` or `Pin` receiver can await a delay.
+    /// A delayed response in any other method fails the call with [MockErrorKind::DelayNotAwaited](crate::MockErrorKind::DelayNotAwaited),
+    /// because blocking the thread would also block the executor.
+    #[cfg(feature = "mock-tokio-1")]
+    pub fn with_delay(mut self, delay: core::time::Duration) -> Self {
+        self.wrapper.set_delay(delay);
+        self
+    }
+
+    /// Only let the current response take part in pattern matching while `predicate` returns `true`.
+    ///
+    /// The predicate is evaluated at call time.
+    /// When it returns `false`, the call pattern is treated as not matching, and the next call pattern is tried instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// #[unimock(api=DoorMock)]
+    /// trait Door {
+    ///     fn open(&self) -> bool;
+    /// }
+    ///
+    /// let unlocked = Arc::new(AtomicBool::new(false));
+    /// let u = Unimock::new((
+    ///     DoorMock::open.each_call(matching!()).returns(true).only_if({
+    ///         let unlocked = unlocked.clone();
+    ///         move || unlocked.load(Ordering::SeqCst)
+    ///     }),
+    ///     DoorMock::open.each_call(matching!()).returns(false),
+    /// ));
+    ///
+    /// assert!(!u.open());
+    /// unlocked.store(true, Ordering::SeqCst);
+    /// assert!(u.open());
+    /// ```
+    pub fn only_if(mut self, predicate: impl Fn() -> bool + Send + Sync + 'static) -> Self
+    where
+        O: Ordering<Kind = InAnyOrder>,
+    {
+        self.wrapper
+            .set_condition(ResponseCondition(Box::new(predicate)));
+        self
+    }
+
+    /// Transform the output of the unmocked implementation before it is returned to the caller.
+    ///
+    /// Must directly follow [applies_unmocked](DefineResponse::applies_unmocked),
+    /// and is only available for methods that return an owned value.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=CounterMock, unmock_with=[count])]
+    /// trait Counter {
+    ///     fn count(&self) -> u32;
+    /// }
+    ///
+    /// fn count(_: &impl core::any::Any) -> u32 {
+    ///     42
+    /// }
+    ///
+    /// let u = Unimock::new(
+    ///     CounterMock::count
+    ///         .each_call(matching!())
+    ///         .applies_unmocked()
+    ///         .map_output(|real| real + 1),
+    /// );
+    ///
+    /// assert_eq!(43, u.count());
+    /// ```
+    #[track_caller]
+    pub fn map_output<T>(mut self, map_fn: impl Fn(T) -> T + Send + Sync + 'static) -> Self
+    where
+        F::OutputKind: Return<Type = T>,
+        T: 'static,
+    {
+        let map_fn: OutputMapFn<T> = Box::new(map_fn);
+        self.wrapper.map_unmocked(Arc::new(map_fn));
+        self
+    }
+
+    /// Define a response to fall back to when the current one is unable to respond.
+    ///
+    /// A response is unable to respond when it [panics](DefineMultipleResponses::panics),
+    /// or when it is a value that can only be returned once and has already been returned.
+    /// The responses of a fallback chain are tried in order, and take up a single place in an output sequence.
+    /// Note that panics raised inside answer functions are not caught, so an answer always counts as a response.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=CacheMock)]
+    /// trait Cache {
+    ///     fn get(&self, key: &str) -> String;
+    /// }
+    ///
+    /// let u = Unimock::new(
+    ///     CacheMock::get
+    ///         .some_call(matching!(_))
+    ///         .returns("cached")
+    ///         .with_fallback()
+    ///         .answers(&|_, key| format!("fresh {key}"))
+    /// );
+    ///
+    /// assert_eq!("cached", u.get("a"));
+    /// assert_eq!("fresh b", u.get("b"));
+    /// ```
+    pub fn with_fallback(mut self) -> DefineMultipleResponses<'p, F, O> {
+        self.wrapper.prepare_fallback();
+        DefineMultipleResponses {
+            wrapper: self.wrapper,
+            mock_fn: PhantomData,
+            ordering: self.ordering,
+        }
+    }
+
+    /// Name the call pattern.
+    ///
+    /// Error messages and the [verification report](crate::Unimock::verification_report)
+    /// refer to a named call pattern by its label instead of its index.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=AuthMock)]
+    /// trait Auth {
+    ///     fn login(&self, user: &str) -> bool;
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     AuthMock::login
+    ///         .each_call(matching!("alice"))
+    ///         .returns(true)
+    ///         .named("auth happy path"),
+    ///     AuthMock::login
+    ///         .each_call(matching!(_))
+    ///         .returns(false)
+    ///         .named("auth rejected"),
+    /// ));
+    ///
+    /// assert!(u.login("alice"));
+    /// assert!(!u.login("mallory"));
+    /// ```
+    pub fn named(mut self, label: &'static str) -> Self {
+        self.wrapper.inner_mut().label = Some(label);
+        self
+    }
+
     fn into_exact(self) -> QuantifiedResponse<'p, F, O, Exact> {
         QuantifiedResponse {
             wrapper: self.wrapper,
@@ -740,7 +2131,7 @@ where
     F: MockFn,
     O: Ordering,
 {
-    fn deconstruct(mut self, sink: &mut dyn clause::term::Sink) -> Result<(), String> {
+    fn deconstruct(mut self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
         if self.wrapper.inner().pattern_match_mode == PatternMatchMode::InOrder {
             self.wrapper.quantify(1, counter::Exactness::Exact);
         }
@@ -784,6 +2175,111 @@ where
             ordering: self.ordering,
         }
     }
+
+    /// Delay the current response by the given duration.
+    ///
+    /// Useful for testing timeout and retry logic.
+    /// The delay is awaited with [tokio::time::sleep](tokio_1::time::sleep), so it must run on a tokio runtime,
+    /// and follows [tokio::time::pause](tokio_1::time::pause).
+    ///
+    /// Only `async` methods without a `&mut self` or `Pin` receiver can await a delay.
+    /// A delayed response in any other method fails the call with [MockErrorKind::DelayNotAwaited](crate::MockErrorKind::DelayNotAwaited),
+    /// because blocking the thread would also block the executor.
+    #[cfg(feature = "mock-tokio-1")]
+    pub fn with_delay(mut self, delay: core::time::Duration) -> Self {
+        self.wrapper.set_delay(delay);
+        self
+    }
+
+    /// Name the call pattern, see [Quantify::named].
+    pub fn named(mut self, label: &'static str) -> Self {
+        self.wrapper.inner_mut().label = Some(label);
+        self
+    }
+
+    /// Only let this response take part in pattern matching while `predicate` returns `true`, see [Quantify::only_if].
+    pub fn only_if(mut self, predicate: impl Fn() -> bool + Send + Sync + 'static) -> Self
+    where
+        O: Ordering<Kind = InAnyOrder>,
+    {
+        self.wrapper
+            .set_condition(ResponseCondition(Box::new(predicate)));
+        self
+    }
+
+    /// Shorthand for `.then().returns(value).once()`.
+    pub fn then_returns<T>(self, value: T) -> QuantifiedResponse<'p, F, O, Exact>
+    where
+        R: Repetition<Kind = Exact>,
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.then().returns(value).once()
+    }
+
+    /// Shorthand for `.then().returns(value).n_times(times)`.
+    pub fn then_returns_n_times<T>(
+        self,
+        value: T,
+        times: usize,
+    ) -> QuantifiedResponse<'p, F, O, Exact>
+    where
+        R: Repetition<Kind = Exact>,
+        T: IntoReturn<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        self.then().returns(value).n_times(times)
+    }
+
+    /// Respond with the default value of the output type after the preceding responses have been used up.
+    ///
+    /// The call pattern is expected to be matched at least as many times as the preceding responses are quantified,
+    /// and any further calls return the default value instead of failing.
+    /// This only works for call patterns matched in any order.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn get(&self) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(
+    ///     TraitMock::get
+    ///         .each_call(matching!())
+    ///         .returns(1)
+    ///         .once()
+    ///         .then_returns(2)
+    ///         .and_then_default(),
+    /// );
+    ///
+    /// assert_eq!(1, u.get());
+    /// assert_eq!(2, u.get());
+    /// assert_eq!(0, u.get());
+    /// assert_eq!(0, u.get());
+    /// ```
+    pub fn and_then_default(mut self) -> QuantifiedResponse<'p, F, O, AtLeast>
+    where
+        R: Repetition<Kind = Exact>,
+        O: Ordering<Kind = InAnyOrder>,
+        F::OutputKind: Return,
+        <F::OutputKind as Return>::Type: ReturnDefault<F::OutputKind>,
+        <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        let default =
+            <<F::OutputKind as Return>::Type as ReturnDefault<F::OutputKind>>::return_default();
+        self.wrapper
+            .push_returner_result(Ok(default.into_returner()));
+        self.wrapper.quantify(0, counter::Exactness::AtLeast);
+
+        QuantifiedResponse {
+            wrapper: self.wrapper,
+            mock_fn: PhantomData,
+            ordering: self.ordering,
+            _repetition: AtLeast,
+        }
+    }
 }
 
 impl<'p, F, O, R> Clause for QuantifiedResponse<'p, F, O, R>
@@ -792,7 +2288,210 @@ where
     O: Ordering,
     R: Repetition,
 {
-    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), String> {
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError> {
         sink.push(F::info(), self.wrapper.into_owned())
     }
 }
+
+/// Response builders intended for `async` methods.
+///
+/// The delays in this module are awaited with tokio's timer, and require the `mock-tokio-1` feature.
+#[cfg(feature = "mock-tokio-1")]
+pub mod async_ext {
+    use core::time::Duration;
+
+    use super::*;
+
+    impl<'p, F: MockFn, O: Ordering + Copy> DefineResponse<'p, F, O> {
+        /// Respond with the given value after sleeping for the given duration.
+        ///
+        /// Useful for testing timeout and retry logic, where some calls are slow and others are not.
+        /// This is a shorthand for `.returns(value).once().with_delay(duration)`,
+        /// see [QuantifiedResponse::with_delay] for how the delay is applied.
+        ///
+        /// # Example
+        /// ```
+        /// # use unimock::*;
+        /// use std::time::Duration;
+        ///
+        /// #[unimock(api=FetchMock)]
+        /// trait Fetch {
+        ///     async fn fetch(&self) -> i32;
+        /// }
+        ///
+        /// let u = Unimock::new((
+        ///     FetchMock::fetch
+        ///         .next_call(matching!())
+        ///         .answers_with_sleep_then_value(Duration::from_secs(10), 1),
+        ///     FetchMock::fetch
+        ///         .next_call(matching!())
+        ///         .returns(2),
+        /// ));
+        ///
+        /// # tokio_1::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+        /// let first = tokio_1::time::timeout(Duration::from_millis(10), u.fetch()).await;
+        /// assert!(first.is_err());
+        /// assert_eq!(2, u.fetch().await);
+        /// # });
+        /// ```
+        pub fn answers_with_sleep_then_value<T>(
+            self,
+            duration: Duration,
+            value: T,
+        ) -> QuantifiedResponse<'p, F, O, Exact>
+        where
+            T: IntoReturnOnce<F::OutputKind>,
+            <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+        {
+            self.returns(value).once().with_delay(duration)
+        }
+    }
+
+    impl<'p, F, O> DefineMultipleResponses<'p, F, O>
+    where
+        F: MockFn + 'static,
+        O: Ordering,
+    {
+        /// Respond with the given value after sleeping for the given duration.
+        ///
+        /// This is a shorthand for `.returns(value).with_delay(duration)`,
+        /// see [Quantify::with_delay] for how the delay is applied.
+        pub fn answers_with_sleep_then_value<T>(
+            self,
+            duration: Duration,
+            value: T,
+        ) -> Quantify<'p, F, O>
+        where
+            T: IntoReturn<F::OutputKind>,
+            <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+        {
+            self.returns(value).with_delay(duration)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use pending::PendingResponse;
+
+#[cfg(feature = "std")]
+mod pending {
+    use super::*;
+
+    impl<'p, F, O> DefineMultipleResponses<'p, F, O>
+    where
+        F: MockFn + 'static,
+        O: Ordering,
+    {
+        /// Make the future of the call return `Poll::Pending` the given number of times before resolving.
+        ///
+        /// Every pending poll wakes the task again, so any executor keeps polling the future until it resolves.
+        /// Useful for testing executors and `select!` loops.
+        /// The response itself is defined using [PendingResponse::then_resolves].
+        pub fn pending_times(self, times: usize) -> PendingResponse<Self> {
+            PendingResponse {
+                define: self,
+                times,
+                must_resolve: false,
+            }
+        }
+    }
+
+    impl<'p, F: MockFn, O: Ordering> DefineResponse<'p, F, O> {
+        /// Make the future of the call return `Poll::Pending` the given number of times before resolving.
+        ///
+        /// Every pending poll wakes the task again, so any executor keeps polling the future until it resolves.
+        /// Useful for testing executors and `select!` loops.
+        /// The response itself is defined using [PendingResponse::then_resolves].
+        ///
+        /// # Example
+        /// ```
+        /// # use unimock::*;
+        /// use std::future::Future;
+        /// use std::task::{Context, Poll};
+        ///
+        /// #[unimock(api=FetchMock)]
+        /// trait Fetch {
+        ///     async fn fetch(&self) -> i32;
+        /// }
+        ///
+        /// let u = Unimock::new(
+        ///     FetchMock::fetch
+        ///         .next_call(matching!())
+        ///         .pending_times(2)
+        ///         .then_resolves(42)
+        ///         .once(),
+        /// );
+        ///
+        /// let mut future = std::pin::pin!(u.fetch());
+        /// let mut cx = Context::from_waker(futures_util_0_3::task::noop_waker_ref());
+        /// assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        /// assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        /// assert_eq!(Poll::Ready(42), future.as_mut().poll(&mut cx));
+        /// ```
+        pub fn pending_times(self, times: usize) -> PendingResponse<Self> {
+            PendingResponse {
+                define: self,
+                times,
+                must_resolve: false,
+            }
+        }
+    }
+
+    /// A response that stays pending for a number of polls, created by `pending_times`.
+    ///
+    /// This only applies to `async` methods. Methods returning a future in another way resolve immediately.
+    pub struct PendingResponse<D> {
+        define: D,
+        times: usize,
+        must_resolve: bool,
+    }
+
+    impl<D> PendingResponse<D> {
+        /// Report dropping the future before it resolved when the [Unimock] instance is verified.
+        ///
+        /// A future that is cancelled while pending, e.g. by losing a `select!`, is not a failure by default.
+        pub fn must_resolve(mut self) -> Self {
+            self.must_resolve = true;
+            self
+        }
+
+        fn pending_polls(&self) -> PendingPolls {
+            PendingPolls {
+                times: self.times,
+                must_resolve: self.must_resolve,
+            }
+        }
+    }
+
+    impl<'p, F: MockFn, O: Ordering> PendingResponse<DefineResponse<'p, F, O>> {
+        /// Resolve the future with the given value after it has been pending, see [DefineResponse::returns].
+        pub fn then_resolves<T>(self, value: T) -> QuantifyReturnValue<'p, F, T, O>
+        where
+            T: IntoReturnOnce<F::OutputKind>,
+            <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+        {
+            let pending_polls = self.pending_polls();
+            let mut define = self.define;
+            define.wrapper.inner_mut().pending_polls = Some(pending_polls);
+            define.returns(value)
+        }
+    }
+
+    impl<'p, F, O> PendingResponse<DefineMultipleResponses<'p, F, O>>
+    where
+        F: MockFn + 'static,
+        O: Ordering,
+    {
+        /// Resolve the future with the given value after it has been pending, see [DefineMultipleResponses::returns].
+        pub fn then_resolves<T>(self, value: T) -> Quantify<'p, F, O>
+        where
+            T: IntoReturn<F::OutputKind>,
+            <<F as MockFn>::OutputKind as Kind>::Return: IntoReturner<F>,
+        {
+            let pending_polls = self.pending_polls();
+            let mut define = self.define;
+            define.wrapper.inner_mut().pending_polls = Some(pending_polls);
+            define.returns(value)
+        }
+    }
+}
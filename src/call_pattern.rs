@@ -1,3 +1,5 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::alloc::Vec;
 use crate::responder::DynResponder;
 
@@ -32,6 +34,14 @@ pub(crate) struct CallPattern {
     pub responders: Vec<DynCallOrderResponder>,
     pub ordered_call_index_range: core::ops::Range<usize>,
     pub call_counter: counter::CallCounter,
+    /// User-defined name of the call pattern, shown in error messages instead of its index
+    pub label: Option<&'static str>,
+    /// Unordered call patterns with higher priority are tried first
+    pub priority: u32,
+    /// Catch-all patterns of a default stub are tried after all others, and may go uncalled
+    pub default_stub: bool,
+    /// Position in an [Each](crate::build::Each) with [strict_order](crate::build::Each::strict_order)
+    pub each_order: Option<EachOrder>,
 }
 
 impl CallPattern {
@@ -40,37 +50,129 @@ impl CallPattern {
         inputs: &F::Inputs<'_>,
         mismatch_reporter: Option<&mut MismatchReporter>,
     ) -> PatternResult<bool> {
-        match (&self.input_matcher.dyn_matching_fn, mismatch_reporter) {
+        let matched = match (&self.input_matcher.dyn_matching_fn, mismatch_reporter) {
             (Some(DynMatchingFn(f)), Some(reporter)) => {
-                Ok((downcast_box::<MatchingFn<F>>(f)?.0)(inputs, reporter))
+                (downcast_box::<MatchingFn<F>>(f)?.0)(inputs, reporter)
+            }
+            (Some(DynMatchingFn(f)), None) => {
+                (downcast_box::<MatchingFn<F>>(f)?.0)(inputs, &mut MismatchReporter::new_disabled())
             }
-            (Some(DynMatchingFn(f)), None) => Ok((downcast_box::<MatchingFn<F>>(f)?.0)(
-                inputs,
-                &mut MismatchReporter::new_disabled(),
-            )),
-            (None, _) => Err(PatternError::NoMatcherFunction),
+            (None, _) => return Err(PatternError::NoMatcherFunction),
+        };
+
+        Ok(matched)
+    }
+
+    /// Record the inputs of a call answered by this call pattern, see [crate::build::Each::track_inputs].
+    pub fn record_inputs<F: MockFn>(&self, inputs: &F::Inputs<'_>) -> PatternResult<()> {
+        if let Some(DynInputRecorder(recorder)) = &self.input_matcher.input_recorder {
+            (downcast_box::<InputRecorder<F>>(recorder)?.0)(inputs);
         }
+
+        Ok(())
     }
 
     pub fn debug_location(&self, pat_index: PatIndex) -> debug::CallPatternLocation {
         if let Some(debug) = self.input_matcher.matcher_debug {
             debug::CallPatternLocation::Debug(debug)
         } else {
-            debug::CallPatternLocation::PatIndex(pat_index)
+            debug::CallPatternLocation::PatIndex(pat_index, self.input_matcher.defined_at)
         }
     }
 
-    pub fn next_responder(&self) -> Option<&DynResponder> {
-        find_responder_by_call_index(&self.responders, self.call_counter.fetch_add())
+    /// Count a call matching this call pattern, unless the call pattern or the responder for the call is inactive, or the call pattern is closed.
+    ///
+    /// The call counter is read once, so that a parallel call can't change which responder gets checked.
+    pub fn count_call(&self) -> CountedCall<'_> {
+        let mut counted_call = CountedCall::Inactive;
+        let mut counted_calls = 0;
+        self.call_counter.fetch_add_if(|call_index| {
+            if self
+                .each_order
+                .as_ref()
+                .is_some_and(|each_order| !each_order.is_current())
+            {
+                counted_call = CountedCall::Inactive;
+                return false;
+            }
+
+            let responder = find_responder_by_call_index(&self.responders, call_index);
+            if responder
+                .is_some_and(|call_order_responder| !call_order_responder.responder.is_active())
+            {
+                counted_call = CountedCall::Inactive;
+                return false;
+            }
+
+            if self.call_counter.is_closed_at(call_index) {
+                counted_call = CountedCall::Closed;
+                return false;
+            }
+
+            counted_call = CountedCall::Responder(responder);
+            counted_calls = call_index + 1;
+            true
+        });
+
+        if let Some(each_order) = &self.each_order {
+            if Some(counted_calls) == self.call_counter.expectation().exact_calls().map(|n| n.0) {
+                each_order.advance();
+            }
+        }
+
+        counted_call
     }
 }
 
+/// The position of a call pattern within an [Each](crate::build::Each) that is matched in [strict_order](crate::build::Each::strict_order).
+pub(crate) struct EachOrder {
+    /// The position that may currently be matched, shared by all call patterns of the [Each](crate::build::Each)
+    pub cursor: alloc::Arc<AtomicUsize>,
+    /// `None` for a call pattern that expects no calls, which is never matched
+    pub position: Option<usize>,
+}
+
+impl EachOrder {
+    fn is_current(&self) -> bool {
+        self.position == Some(self.cursor.load(Ordering::SeqCst))
+    }
+
+    /// Let the next call pattern of the [Each](crate::build::Each) be matched.
+    fn advance(&self) {
+        if let Some(position) = self.position {
+            let _ = self.cursor.compare_exchange(
+                position,
+                position + 1,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+        }
+    }
+}
+
+/// Outcome of [CallPattern::count_call].
+pub(crate) enum CountedCall<'p> {
+    /// The call pattern or the responder for the call is inactive, and the call was not counted
+    Inactive,
+    /// No more calls are expected, and the call was not counted
+    Closed,
+    /// The call was counted, and gets answered by this responder
+    Responder(Option<&'p DynCallOrderResponder>),
+}
+
 pub(crate) struct DynInputMatcher {
     dyn_matching_fn: Option<DynMatchingFn>,
     pub(crate) matcher_debug: Option<debug::InputMatcherDebug>,
+    /// Description listed when no call pattern matches a call, see [Matching::debug_hint]
+    pub(crate) debug_hint: Option<&'static str>,
+    /// Where the call pattern was defined
+    pub(crate) defined_at: &'static core::panic::Location<'static>,
+    /// Records the inputs of every answered call
+    pub(crate) input_recorder: Option<DynInputRecorder>,
 }
 
 impl DynInputMatcher {
+    #[track_caller]
     pub fn from_matching_fn<F: MockFn>(matching_fn: &dyn Fn(&mut Matching<F>)) -> Self {
         let mut builder = Matching::new();
         matching_fn(&mut builder);
@@ -78,12 +180,27 @@ impl DynInputMatcher {
         Self {
             dyn_matching_fn: builder.matching_fn.map(|f| DynMatchingFn(Box::new(f))),
             matcher_debug: builder.matcher_debug,
+            debug_hint: builder.debug_hint,
+            defined_at: core::panic::Location::caller(),
+            input_recorder: None,
         }
     }
 }
 
 struct DynMatchingFn(AnyBox);
 
+pub(crate) struct DynInputRecorder(pub AnyBox);
+
+pub(crate) struct InputRecorder<F: MockFn>(
+    #[allow(clippy::type_complexity)] pub alloc::Arc<dyn for<'i> Fn(&F::Inputs<'i>) + Send + Sync>,
+);
+
+impl<F: MockFn> Clone for InputRecorder<F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 pub(crate) struct MatchingFn<F: MockFn>(
     #[allow(clippy::type_complexity)]
     pub  Box<dyn (for<'i> Fn(&F::Inputs<'i>, &mut MismatchReporter) -> bool) + Send + Sync>,
@@ -92,12 +209,24 @@ pub(crate) struct MatchingFn<F: MockFn>(
 pub(crate) struct DynCallOrderResponder {
     pub response_index: usize,
     pub responder: DynResponder,
+    /// Time to wait before responding
+    pub delay: Option<core::time::Duration>,
+    /// Polls answered with `Poll::Pending` before responding, in `async` methods
+    pub pending_polls: Option<PendingPolls>,
+}
+
+/// How an `async` response stays pending before resolving.
+#[derive(Clone, Copy)]
+pub(crate) struct PendingPolls {
+    pub times: usize,
+    /// Whether dropping the future before it resolves is a mock failure
+    pub must_resolve: bool,
 }
 
 fn find_responder_by_call_index(
     responders: &[DynCallOrderResponder],
     call_index: usize,
-) -> Option<&DynResponder> {
+) -> Option<&DynCallOrderResponder> {
     if responders.is_empty() {
         return None;
     }
@@ -106,8 +235,8 @@ fn find_responder_by_call_index(
         responders.binary_search_by(|responder| responder.response_index.cmp(&call_index));
 
     Some(match index_result {
-        Ok(index) => &responders[index].responder,
-        Err(insert_index) => &responders[insert_index - 1].responder,
+        Ok(index) => &responders[index],
+        Err(insert_index) => &responders[insert_index - 1],
     })
 }
 
@@ -123,17 +252,23 @@ mod tests {
             DynCallOrderResponder {
                 response_index: 0,
                 responder: DynResponder::Panic("0".into()),
+                delay: None,
+                pending_polls: None,
             },
             DynCallOrderResponder {
                 response_index: 5,
                 responder: DynResponder::Panic("5".into()),
+                delay: None,
+                pending_polls: None,
             },
         ];
 
         fn find_msg(responders: &[DynCallOrderResponder], call_index: usize) -> Option<&str> {
-            find_responder_by_call_index(responders, call_index).map(|responder| match responder {
-                DynResponder::Panic(msg) => msg.as_ref(),
-                _ => panic!(),
+            find_responder_by_call_index(responders, call_index).map(|responder| {
+                match &responder.responder {
+                    DynResponder::Panic(msg) => msg.as_ref(),
+                    _ => panic!(),
+                }
             })
         }
 
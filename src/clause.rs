@@ -1,18 +1,156 @@
-use crate::alloc::String;
 use crate::*;
 
 pub(crate) mod term {
-    use crate::alloc::String;
-
-    use crate::{build::dyn_builder::DynCallPatternBuilder, MockFnInfo};
+    use crate::{
+        build::dyn_builder::DynCallPatternBuilder, error::AssembleError, fn_mocker::Fallback,
+        AnyBox, MockFnInfo,
+    };
 
     pub trait Sink {
-        fn push(&mut self, info: MockFnInfo, builder: DynCallPatternBuilder) -> Result<(), String>;
+        fn push(
+            &mut self,
+            info: MockFnInfo,
+            builder: DynCallPatternBuilder,
+        ) -> Result<(), AssembleError>;
+
+        fn push_fallback_mode(
+            &mut self,
+            info: MockFnInfo,
+            fallback: Fallback,
+        ) -> Result<(), AssembleError>;
+
+        fn push_max_debug_len(
+            &mut self,
+            info: MockFnInfo,
+            max_debug_len: usize,
+        ) -> Result<(), AssembleError>;
+
+        fn push_input_format(
+            &mut self,
+            info: MockFnInfo,
+            input_format: AnyBox,
+        ) -> Result<(), AssembleError>;
+    }
+}
+
+/// A clause whose call patterns are reported as defined at `defined_at`, instead of where they were built.
+///
+/// Used by clauses that get built lazily in [Clause::deconstruct], far away from the user's code.
+#[cfg_attr(not(any(feature = "std", feature = "spin-lock")), allow(dead_code))]
+pub(crate) struct DefinedAt<C> {
+    pub clause: C,
+    pub defined_at: &'static core::panic::Location<'static>,
+}
+
+impl<C: Clause> Clause for DefinedAt<C> {
+    fn deconstruct(self, sink: &mut dyn term::Sink) -> Result<(), error::AssembleError> {
+        self.clause.deconstruct(&mut DefinedAtSink {
+            sink,
+            defined_at: self.defined_at,
+        })
+    }
+}
+
+#[cfg_attr(not(any(feature = "std", feature = "spin-lock")), allow(dead_code))]
+struct DefinedAtSink<'s> {
+    sink: &'s mut dyn term::Sink,
+    defined_at: &'static core::panic::Location<'static>,
+}
+
+impl term::Sink for DefinedAtSink<'_> {
+    fn push(
+        &mut self,
+        info: MockFnInfo,
+        mut builder: build::dyn_builder::DynCallPatternBuilder,
+    ) -> Result<(), error::AssembleError> {
+        builder.input_matcher.defined_at = self.defined_at;
+        self.sink.push(info, builder)
+    }
+
+    fn push_fallback_mode(
+        &mut self,
+        info: MockFnInfo,
+        fallback: fn_mocker::Fallback,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_fallback_mode(info, fallback)
+    }
+
+    fn push_max_debug_len(
+        &mut self,
+        info: MockFnInfo,
+        max_debug_len: usize,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_max_debug_len(info, max_debug_len)
+    }
+
+    fn push_input_format(
+        &mut self,
+        info: MockFnInfo,
+        input_format: AnyBox,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_input_format(info, input_format)
+    }
+}
+
+/// A sink adding `priority` to the call patterns passing through it, keeping track of the highest resulting priority.
+pub(crate) struct PrioritySink<'s> {
+    sink: &'s mut dyn term::Sink,
+    priority: u32,
+    max_priority: u32,
+}
+
+impl<'s> PrioritySink<'s> {
+    pub fn new(sink: &'s mut dyn term::Sink, priority: u32) -> Self {
+        Self {
+            sink,
+            priority,
+            max_priority: priority,
+        }
+    }
+
+    pub fn max_priority(&self) -> u32 {
+        self.max_priority
+    }
+}
+
+impl term::Sink for PrioritySink<'_> {
+    fn push(
+        &mut self,
+        info: MockFnInfo,
+        mut builder: build::dyn_builder::DynCallPatternBuilder,
+    ) -> Result<(), error::AssembleError> {
+        builder.priority += self.priority;
+        self.max_priority = self.max_priority.max(builder.priority);
+        self.sink.push(info, builder)
+    }
+
+    fn push_fallback_mode(
+        &mut self,
+        info: MockFnInfo,
+        fallback: fn_mocker::Fallback,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_fallback_mode(info, fallback)
+    }
+
+    fn push_max_debug_len(
+        &mut self,
+        info: MockFnInfo,
+        max_debug_len: usize,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_max_debug_len(info, max_debug_len)
+    }
+
+    fn push_input_format(
+        &mut self,
+        info: MockFnInfo,
+        input_format: AnyBox,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_input_format(info, input_format)
     }
 }
 
 impl Clause for () {
-    fn deconstruct(self, _: &mut dyn term::Sink) -> Result<(), String> {
+    fn deconstruct(self, _: &mut dyn term::Sink) -> Result<(), error::AssembleError> {
         Ok(())
     }
 }
@@ -20,7 +158,7 @@ impl Clause for () {
 macro_rules! tuple_nonterminal_impl {
     ([$($t:ident),+], [$($index:tt),+]) => {
         impl<$($t: Clause),+> Clause for ($($t,)+) {
-            fn deconstruct(self, sink: &mut dyn term::Sink) -> Result<(), String> {
+            fn deconstruct(self, sink: &mut dyn term::Sink) -> Result<(), error::AssembleError> {
                 $(self.$index.deconstruct(sink)?;)+
                 Ok(())
             }
@@ -43,3 +181,51 @@ tuple_nonterminal_impl! { [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T1
 tuple_nonterminal_impl! { [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13] }
 tuple_nonterminal_impl! { [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14] }
 tuple_nonterminal_impl! { [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15] }
+
+/// A clause whose call patterns make up a default stub: tried after all other call patterns, and allowed to go uncalled.
+pub(crate) struct DefaultStub<C>(pub C);
+
+impl<C: Clause> Clause for DefaultStub<C> {
+    fn deconstruct(self, sink: &mut dyn term::Sink) -> Result<(), error::AssembleError> {
+        self.0.deconstruct(&mut DefaultStubSink { sink })
+    }
+}
+
+struct DefaultStubSink<'s> {
+    sink: &'s mut dyn term::Sink,
+}
+
+impl term::Sink for DefaultStubSink<'_> {
+    fn push(
+        &mut self,
+        info: MockFnInfo,
+        mut builder: build::dyn_builder::DynCallPatternBuilder,
+    ) -> Result<(), error::AssembleError> {
+        builder.default_stub = true;
+        self.sink.push(info, builder)
+    }
+
+    fn push_fallback_mode(
+        &mut self,
+        info: MockFnInfo,
+        fallback: fn_mocker::Fallback,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_fallback_mode(info, fallback)
+    }
+
+    fn push_max_debug_len(
+        &mut self,
+        info: MockFnInfo,
+        max_debug_len: usize,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_max_debug_len(info, max_debug_len)
+    }
+
+    fn push_input_format(
+        &mut self,
+        info: MockFnInfo,
+        input_format: AnyBox,
+    ) -> Result<(), error::AssembleError> {
+        self.sink.push_input_format(info, input_format)
+    }
+}
@@ -0,0 +1,132 @@
+//! A mockable clock.
+//!
+//! Code that reads the time through [Clock] or [MonotonicClock] instead of calling [SystemTime::now] or [Instant::now] directly
+//! can be tested with scripted time.
+//! Production code passes [SystemClock], while tests pass a [Unimock].
+//!
+//! Both traits support unmocking, so a partially mocked instance created with [Unimock::new_partial] reads the real time
+//! unless the clock is overridden with a clause.
+//!
+//! ```rust
+//! use std::time::{Duration, SystemTime, UNIX_EPOCH};
+//! use unimock::*;
+//! use unimock::clock::{Clock, ClockMock};
+//!
+//! fn is_expired(clock: &impl Clock, deadline: SystemTime) -> bool {
+//!     clock.now() > deadline
+//! }
+//!
+//! let deadline = UNIX_EPOCH + Duration::from_secs(100);
+//!
+//! assert!(!is_expired(&Unimock::new(ClockMock::now.returns_epoch_plus(99)), deadline));
+//! assert!(is_expired(&Unimock::new(ClockMock::now.returns_epoch_plus(101)), deadline));
+//!
+//! // Real time:
+//! assert!(is_expired(&Unimock::new_partial(()), deadline));
+//! ```
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::{alloc::Arc, build::Quantify, property::InAnyOrder, unimock, MockFn, Unimock};
+
+/// A source of wall-clock time.
+#[unimock(prefix=crate, api=ClockMock, unmock_with=[real_now])]
+pub trait Clock {
+    /// The current time, see [SystemTime::now].
+    fn now(&self) -> SystemTime;
+}
+
+/// A source of monotonic time, for measuring elapsed time and timeouts.
+#[unimock(prefix=crate, api=MonotonicClockMock, unmock_with=[real_instant])]
+pub trait MonotonicClock {
+    /// The current instant, see [Instant::now].
+    fn now(&self) -> Instant;
+}
+
+/// The real clock, reading the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+impl MonotonicClock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+fn real_now(_: &Unimock) -> SystemTime {
+    SystemTime::now()
+}
+
+fn real_instant(_: &Unimock) -> Instant {
+    Instant::now()
+}
+
+impl ClockMock::now {
+    /// A clause which returns the given number of seconds after the [UNIX_EPOCH].
+    ///
+    /// The clause responds to any number of calls, which can be changed with the methods of [Quantify].
+    #[track_caller]
+    pub fn returns_epoch_plus(self, secs: u64) -> Quantify<'static, Self, InAnyOrder> {
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .returns(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    /// A clause for a clock that advances by itself.
+    ///
+    /// The first call returns `start`, and every following call returns `step` later than the previous one.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use unimock::*;
+    /// use unimock::clock::{Clock, ClockMock};
+    ///
+    /// let u = Unimock::new(ClockMock::now.ticks(UNIX_EPOCH, Duration::from_secs(1)));
+    ///
+    /// assert_eq!(UNIX_EPOCH, u.now());
+    /// assert_eq!(UNIX_EPOCH + Duration::from_secs(1), u.now());
+    /// assert_eq!(UNIX_EPOCH + Duration::from_secs(2), u.now());
+    /// ```
+    #[track_caller]
+    pub fn ticks(self, start: SystemTime, step: Duration) -> Quantify<'static, Self, InAnyOrder> {
+        let tick = AtomicU32::new(0);
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .answers_arc(Arc::new(move |_| {
+                start + step * tick.fetch_add(1, Ordering::SeqCst)
+            }))
+    }
+}
+
+impl MonotonicClockMock::now {
+    /// A clause for a clock that advances by itself.
+    ///
+    /// The first call returns `start`, and every following call returns `step` later than the previous one.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use unimock::*;
+    /// use unimock::clock::{MonotonicClock, MonotonicClockMock};
+    ///
+    /// let start = Instant::now();
+    /// let u = Unimock::new(MonotonicClockMock::now.ticks(start, Duration::from_millis(10)));
+    ///
+    /// let before = u.now();
+    /// let after = u.now();
+    /// assert_eq!(Duration::from_millis(10), after - before);
+    /// ```
+    #[track_caller]
+    pub fn ticks(self, start: Instant, step: Duration) -> Quantify<'static, Self, InAnyOrder> {
+        let tick = AtomicU32::new(0);
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .answers_arc(Arc::new(move |_| {
+                start + step * tick.fetch_add(1, Ordering::SeqCst)
+            }))
+    }
+}
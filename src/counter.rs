@@ -1,5 +1,5 @@
 use crate::alloc::{format, Vec};
-use crate::error::MockError;
+use crate::error::MockFailure;
 use crate::{debug, MockFnInfo};
 
 use core::{fmt::Display, sync::atomic::AtomicUsize};
@@ -7,35 +7,76 @@ use core::{fmt::Display, sync::atomic::AtomicUsize};
 pub(crate) struct CallCounter {
     actual_count: AtomicUsize,
     expectation: CallCountExpectation,
+    /// Calls beyond this count fail immediately, see [crate::Unimock::expect_no_more_calls]
+    call_limit: AtomicUsize,
 }
 
 impl CallCounter {
-    pub fn fetch_add(&self) -> usize {
+    /// Count a call if `accept` returns `true` for its index.
+    ///
+    /// The count is read once per attempt, so `accept` sees the index the call gets counted with.
+    pub fn fetch_add_if(&self, mut accept: impl FnMut(usize) -> bool) -> bool {
         self.actual_count
-            .fetch_add(1, core::sync::atomic::Ordering::SeqCst)
+            .fetch_update(
+                core::sync::atomic::Ordering::SeqCst,
+                core::sync::atomic::Ordering::SeqCst,
+                |count| accept(count).then_some(count + 1),
+            )
+            .is_ok()
+    }
+
+    pub fn count(&self) -> usize {
+        self.actual_count.load(core::sync::atomic::Ordering::SeqCst)
+    }
+
+    pub fn expectation(&self) -> &CallCountExpectation {
+        &self.expectation
+    }
+
+    /// Forbid further calls if the expectation is already satisfied.
+    pub fn close_if_satisfied(&self) {
+        if self.is_satisfied() {
+            self.call_limit
+                .store(self.count(), core::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    /// Whether the call with the given index exceeds the limit set by [Self::close_if_satisfied].
+    pub fn is_closed_at(&self, call_index: usize) -> bool {
+        call_index >= self.call_limit.load(core::sync::atomic::Ordering::SeqCst)
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        let actual_calls = self.count();
+        match self.expectation.exactness {
+            Exactness::Exact => actual_calls == self.expectation.lower_bound().0,
+            Exactness::AtLeast | Exactness::AtLeastPlusOne => {
+                actual_calls >= self.expectation.lower_bound().0
+            }
+        }
     }
 
     pub fn verify(
         &self,
         info: &MockFnInfo,
         debug_fn: impl Fn() -> debug::CallPatternDebug,
-        errors: &mut Vec<MockError>,
+        errors: &mut Vec<MockFailure>,
     ) -> NCalls {
         let path = &info.path;
-        let actual_calls = NCalls(self.actual_count.load(core::sync::atomic::Ordering::SeqCst));
+        let actual_calls = NCalls(self.count());
         let lower_bound = self.expectation.lower_bound();
 
         match self.expectation.exactness {
             Exactness::Exact => {
                 if actual_calls.0 != lower_bound.0 {
                     let pattern = debug_fn();
-                    errors.push(MockError::FailedVerification(format!("{path}: Expected {pattern} to match exactly {lower_bound}, but it actually matched {actual_calls}.")));
+                    errors.push(MockFailure::FailedVerification(format!("{path}: Expected {pattern} to match exactly {lower_bound}, but it actually matched {actual_calls}.")));
                 }
             }
             Exactness::AtLeast | Exactness::AtLeastPlusOne => {
                 if actual_calls.0 < lower_bound.0 {
                     let pattern = debug_fn();
-                    errors.push(MockError::FailedVerification(format!("{path}: Expected {pattern} to match at least {lower_bound}, but it actually matched {actual_calls}.")));
+                    errors.push(MockFailure::FailedVerification(format!("{path}: Expected {pattern} to match at least {lower_bound}, but it actually matched {actual_calls}.")));
                 }
             }
         };
@@ -77,6 +118,7 @@ impl CallCountExpectation {
         CallCounter {
             actual_count: AtomicUsize::new(0),
             expectation: self,
+            call_limit: AtomicUsize::new(usize::MAX),
         }
     }
 }
@@ -1,19 +1,92 @@
 use core::fmt::Display;
 
 use crate::alloc::{Box, String};
-use crate::{call_pattern::PatIndex, MockFnInfo};
+use crate::{call_pattern::PatIndex, MockFn, MockFnInfo};
+
+/// The default maximum length of the debug representation of one input, in bytes.
+pub(crate) const DEFAULT_MAX_DEBUG_LEN: usize = 4096;
+
+/// The number of bytes shown of byte buffers that are too long to be listed.
+pub(crate) const BYTES_PREFIX_LEN: usize = 16;
+
+/// Shorten a debug representation to at most `max_len` bytes, noting how many bytes were elided.
+pub(crate) fn elide(mut debug: String, max_len: usize) -> String {
+    if debug.len() <= max_len {
+        return debug;
+    }
+
+    let mut end = max_len;
+    while !debug.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let elided = debug.len() - end;
+    debug.truncate(end);
+    debug.push_str(&crate::alloc::format!("... ({elided} bytes elided)"));
+    debug
+}
+
+/// A user supplied formatter for all the inputs of a MockFn, registered with [MockFn::with_input_format](crate::MockFn::with_input_format).
+pub(crate) struct InputFormat<F: MockFn>(
+    #[allow(clippy::type_complexity)]
+    pub  Box<dyn (for<'i> Fn(&F::Inputs<'i>) -> String) + Send + Sync>,
+);
+
+#[derive(Clone)]
+pub(crate) enum InputsDebug {
+    /// The debug representation of each input, if available
+    Each(Box<[Option<String>]>),
+    /// All inputs, rendered by an [InputFormat]
+    Formatted(String),
+}
+
+impl InputsDebug {
+    pub fn elide(self, max_len: usize) -> Self {
+        match self {
+            Self::Each(mut inputs_debug) => {
+                for input_debug in inputs_debug.iter_mut() {
+                    if let Some(debug) = input_debug.take() {
+                        *input_debug = Some(elide(debug, max_len));
+                    }
+                }
+                Self::Each(inputs_debug)
+            }
+            Self::Formatted(debug) => Self::Formatted(elide(debug, max_len)),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub(crate) struct FnActualCall {
     pub info: MockFnInfo,
-    pub inputs_debug: Box<[Option<String>]>,
+    pub inputs_debug: InputsDebug,
+}
+
+impl FnActualCall {
+    /// The inputs of the call, formatted like in the call itself.
+    pub fn inputs_display(&self) -> impl core::fmt::Display + '_ {
+        InputsDisplay(&self.inputs_debug)
+    }
 }
 
 impl core::fmt::Display for FnActualCall {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}(", self.info.path)?;
+        write!(f, "{}{}", self.info.path, self.inputs_display())
+    }
+}
 
-        let mut iter = self.inputs_debug.iter().peekable();
+struct InputsDisplay<'a>(&'a InputsDebug);
+
+impl core::fmt::Display for InputsDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let inputs_debug = match self.0 {
+            InputsDebug::Each(inputs_debug) => inputs_debug,
+            InputsDebug::Formatted(debug) => return write!(f, "{debug}"),
+        };
+
+        write!(f, "(")?;
+
+        let mut iter = inputs_debug.iter().peekable();
         while let Some(next) = iter.next() {
             match next {
                 Some(debug) => write!(f, "{debug}")?,
@@ -29,12 +102,17 @@ impl core::fmt::Display for FnActualCall {
 
 #[derive(Clone, Copy)]
 pub(crate) struct TraitMethodPath {
+    module: &'static str,
     path: &'static [&'static str; 2],
 }
 
 impl TraitMethodPath {
     pub const fn from_path(path: &'static [&'static str; 2]) -> Self {
-        Self { path }
+        Self { module: "", path }
+    }
+
+    pub const fn with_module(self, module: &'static str) -> Self {
+        Self { module, ..self }
     }
 
     pub fn trait_ident(&self) -> &'static str {
@@ -48,12 +126,18 @@ impl TraitMethodPath {
 
 impl Default for TraitMethodPath {
     fn default() -> Self {
-        Self { path: &["?", "?"] }
+        Self {
+            module: "",
+            path: &["?", "?"],
+        }
     }
 }
 
 impl Display for TraitMethodPath {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.module.is_empty() {
+            write!(f, "{}::", self.module)?;
+        }
         write!(f, "{}::{}", self.trait_ident(), self.method_ident())
     }
 }
@@ -64,9 +148,17 @@ pub(crate) struct CallPatternDebug {
 }
 
 impl CallPatternDebug {
-    pub fn new(info: MockFnInfo, location: CallPatternLocation) -> Self {
+    pub fn new(
+        info: MockFnInfo,
+        location: CallPatternLocation,
+        label: Option<&'static str>,
+    ) -> Self {
         Self {
-            inner: Box::new(CallPatternDebugInner { info, location }),
+            inner: Box::new(CallPatternDebugInner {
+                info,
+                location,
+                label,
+            }),
         }
     }
 }
@@ -79,10 +171,19 @@ impl core::fmt::Display for CallPatternDebug {
                 file,
                 line,
             }) => {
-                write!(f, "{}{} at {file}:{line}", self.inner.info.path, pat_debug)
+                write!(f, "{}{}", self.inner.info.path, pat_debug)?;
+                if let Some(label) = self.inner.label {
+                    write!(f, " {label:?}")?;
+                }
+                write!(f, " at {file}:{line}")
             }
-            CallPatternLocation::PatIndex(pat_index) => {
-                write!(f, "call pattern {}[{pat_index}]", self.inner.info.path)
+            CallPatternLocation::PatIndex(pat_index, defined_at) => {
+                write!(f, "call pattern {}", self.inner.info.path)?;
+                match self.inner.label {
+                    Some(label) => write!(f, "[{label:?}]")?,
+                    None => write!(f, "[{pat_index}]")?,
+                }
+                write!(f, " defined at {}:{}", defined_at.file(), defined_at.line())
             }
         }
     }
@@ -92,12 +193,13 @@ impl core::fmt::Display for CallPatternDebug {
 struct CallPatternDebugInner {
     info: MockFnInfo,
     location: CallPatternLocation,
+    label: Option<&'static str>,
 }
 
 #[derive(Clone)]
 pub(crate) enum CallPatternLocation {
     Debug(InputMatcherDebug),
-    PatIndex(PatIndex),
+    PatIndex(PatIndex, &'static core::panic::Location<'static>),
 }
 
 #[derive(Copy, Clone)]
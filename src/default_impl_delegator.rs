@@ -67,6 +67,18 @@ impl DelegateToDefaultImpl for Unimock {
     }
 }
 
+impl DelegateToDefaultImpl for Box<Unimock> {
+    type Delegator = Box<DefaultImplDelegator>;
+
+    fn to_delegator(self) -> Self::Delegator {
+        Box::new(DefaultImplDelegator::__from_unimock(*self))
+    }
+
+    fn from_delegator(delegator: Self::Delegator) -> Self {
+        Box::new(delegator.unimock)
+    }
+}
+
 impl DelegateToDefaultImpl for Rc<Unimock> {
     type Delegator = Rc<DefaultImplDelegator>;
 
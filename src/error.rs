@@ -1,10 +1,15 @@
 use crate::alloc::{Box, String};
-use crate::{debug, mismatch::Mismatches, MockFnInfo};
+use crate::fn_mocker::{FallbackMode, PatternMatchMode};
+use crate::{
+    debug,
+    mismatch::{Mismatches, NoMatchHints},
+    MockFnInfo,
+};
 
-pub(crate) type MockResult<T> = Result<T, MockError>;
+pub(crate) type MockResult<T> = Result<T, MockFailure>;
 
 #[derive(Clone)]
-pub(crate) enum MockError {
+pub(crate) enum MockFailure {
     Downcast {
         fn_call: debug::FnActualCall,
         pattern: debug::CallPatternDebug,
@@ -19,11 +24,16 @@ pub(crate) enum MockError {
     NoMatchingCallPatterns {
         fn_call: debug::FnActualCall,
         mismatches: Mismatches,
+        hints: Box<NoMatchHints>,
     },
     NoOutputAvailableForCallPattern {
         fn_call: debug::FnActualCall,
         pattern: debug::CallPatternDebug,
     },
+    NoMoreCallsExpected {
+        fn_call: debug::FnActualCall,
+        pattern: debug::CallPatternDebug,
+    },
     MockNeverCalled {
         info: MockFnInfo,
     },
@@ -31,6 +41,7 @@ pub(crate) enum MockError {
         fn_call: debug::FnActualCall,
         actual_call_order: CallOrder,
         expected: Option<debug::CallPatternDebug>,
+        matched_in_order: Box<[debug::CallPatternDebug]>,
     },
     InputsNotMatchedInCallOrder {
         fn_call: debug::FnActualCall,
@@ -57,9 +68,45 @@ pub(crate) enum MockError {
         pattern: debug::CallPatternDebug,
         msg: Box<str>,
     },
+    UnmockRecursionLimit {
+        info: MockFnInfo,
+        limit: usize,
+    },
+    MustBeMocked {
+        fn_call: debug::FnActualCall,
+    },
+    ForwardingLimit {
+        info: MockFnInfo,
+        limit: usize,
+    },
+    #[cfg_attr(not(any(feature = "std", feature = "spin-lock")), allow(dead_code))]
+    UnknownStateKey {
+        info: MockFnInfo,
+        key: String,
+    },
+    FutureDroppedUnresolved {
+        info: MockFnInfo,
+    },
+    #[cfg(feature = "mock-tokio-1")]
+    DelayNotAwaited {
+        info: MockFnInfo,
+    },
+    #[cfg(all(feature = "serde", feature = "std"))]
+    ReplayMismatch {
+        fn_call: debug::FnActualCall,
+        expected: Box<debug::FnActualCall>,
+    },
+    #[cfg(all(feature = "serde", feature = "std"))]
+    ReplayExhausted {
+        fn_call: debug::FnActualCall,
+    },
+    CannotReplay {
+        info: MockFnInfo,
+        reason: Box<str>,
+    },
 }
 
-impl core::fmt::Display for MockError {
+impl core::fmt::Display for MockFailure {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Downcast { fn_call, pattern } => {
@@ -77,8 +124,23 @@ impl core::fmt::Display for MockError {
             Self::NoMatchingCallPatterns {
                 fn_call,
                 mismatches,
+                hints,
             } => {
-                write!(f, "{fn_call}: No matching call patterns. {mismatches}")
+                write!(f, "{fn_call}: No matching call patterns. {mismatches}")?;
+                for debug_hint in hints.debug_hints.iter() {
+                    writeln!(
+                        f,
+                        "Tried '{debug_hint}' for args {inputs}.",
+                        inputs = fn_call.inputs_display()
+                    )?;
+                }
+                if let Some(closest) = &hints.closest {
+                    write!(f, "{closest}")?;
+                }
+                if let Some(signature) = fn_call.info.signature {
+                    write!(f, "The signature is `{signature}`.")?;
+                }
+                Ok(())
             }
             Self::NoOutputAvailableForCallPattern { fn_call, pattern } => {
                 write!(
@@ -86,6 +148,12 @@ impl core::fmt::Display for MockError {
                     "{fn_call}: No output available for after matching {pattern}."
                 )
             }
+            Self::NoMoreCallsExpected { fn_call, pattern } => {
+                write!(
+                    f,
+                    "{fn_call}: No more calls expected to {pattern}, because it was already satisfied when Unimock::expect_no_more_calls was called."
+                )
+            }
             Self::MockNeverCalled { info } => {
                 write!(
                     f,
@@ -97,12 +165,24 @@ impl core::fmt::Display for MockError {
                 fn_call,
                 actual_call_order,
                 expected,
+                matched_in_order,
             } => {
                 if let Some(expected) = expected {
-                    write!(f, "{fn_call}: Method matched in wrong order. Expected a call matching {expected}.")
+                    write!(f, "{fn_call}: Method matched in wrong order. Expected a call matching {expected}. This was ordered call ({actual_call_order}).")?;
                 } else {
-                    write!(f, "{fn_call}: Ordered call ({actual_call_order}) out of range: There were no more ordered call patterns in line for selection.")
+                    write!(f, "{fn_call}: Ordered call ({actual_call_order}) out of range: There were no more ordered call patterns in line for selection.")?;
                 }
+                if !matched_in_order.is_empty() {
+                    write!(f, " Matched in order so far: ")?;
+                    for (index, pattern) in matched_in_order.iter().enumerate() {
+                        if index > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{pattern}")?;
+                    }
+                    write!(f, ".")?;
+                }
+                Ok(())
             }
             Self::InputsNotMatchedInCallOrder {
                 fn_call,
@@ -142,6 +222,108 @@ impl core::fmt::Display for MockError {
                 pattern,
                 msg,
             } => write!(f, "{fn_call}: Explicit panic from {pattern}: {msg}"),
+            Self::UnmockRecursionLimit { info, limit } => {
+                write!(
+                    f,
+                    "{path} recursed into its unmocked implementation more than {limit} times. Is a clause for the base case missing?",
+                    path = info.path
+                )
+            }
+            Self::ForwardingLimit { info, limit } => {
+                write!(
+                    f,
+                    "{path} was forwarded between Unimock instances more than {limit} times. Do two instances forward to each other?",
+                    path = info.path
+                )
+            }
+            Self::UnknownStateKey { info, key } => {
+                write!(
+                    f,
+                    "{path}: No value was set for the key {key}.",
+                    path = info.path
+                )
+            }
+            Self::FutureDroppedUnresolved { info } => {
+                write!(
+                    f,
+                    "The future for {path} was dropped unresolved.",
+                    path = info.path
+                )
+            }
+            #[cfg(feature = "mock-tokio-1")]
+            Self::DelayNotAwaited { info } => {
+                write!(
+                    f,
+                    "{path} has a delayed response, but a delay can only be awaited by async methods without a &mut self or Pin receiver.",
+                    path = info.path
+                )
+            }
+            Self::MustBeMocked { fn_call } => {
+                write!(
+                    f,
+                    "{fn_call}: No call pattern matched, and {path} must be mocked instead of falling back to its unmocked implementation.",
+                    path = fn_call.info.path
+                )
+            }
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::ReplayMismatch { fn_call, expected } => {
+                write!(f, "{fn_call}: The next replayed call is {expected}.")
+            }
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::ReplayExhausted { fn_call } => {
+                write!(
+                    f,
+                    "{fn_call}: All replayed calls to {path} have already been made.",
+                    path = fn_call.info.path
+                )
+            }
+            Self::CannotReplay { info, reason } => {
+                write!(
+                    f,
+                    "{path}: Cannot replay output: {reason}",
+                    path = info.path
+                )
+            }
+        }
+    }
+}
+
+impl MockFailure {
+    fn kind(&self) -> MockErrorKind {
+        match self {
+            Self::Downcast { .. } => MockErrorKind::Downcast,
+            Self::NoMockImplementation { .. } => MockErrorKind::NoMockImplementation,
+            Self::NoMatcherFunction { .. } => MockErrorKind::NoMatcherFunction,
+            Self::NoMatchingCallPatterns { .. } => MockErrorKind::NoMatchingCallPatterns,
+            Self::NoOutputAvailableForCallPattern { .. } => {
+                MockErrorKind::NoOutputAvailableForCallPattern
+            }
+            Self::NoMoreCallsExpected { .. } => MockErrorKind::NoMoreCallsExpected,
+            Self::MockNeverCalled { .. } => MockErrorKind::MockNeverCalled,
+            Self::CallOrderNotMatchedForMockFn { .. } => {
+                MockErrorKind::CallOrderNotMatchedForMockFn
+            }
+            Self::InputsNotMatchedInCallOrder { .. } => MockErrorKind::InputsNotMatchedInCallOrder,
+            Self::CannotReturnValueMoreThanOnce { .. } => {
+                MockErrorKind::CannotReturnValueMoreThanOnce
+            }
+            Self::FailedVerification(_) => MockErrorKind::FailedVerification,
+            Self::CannotUnmock { .. } => MockErrorKind::CannotUnmock,
+            Self::NoDefaultImpl { .. } => MockErrorKind::NoDefaultImpl,
+            Self::NotAnswered { .. } => MockErrorKind::NotAnswered,
+            Self::ExplicitPanic { .. } => MockErrorKind::ExplicitPanic,
+            Self::UnmockRecursionLimit { .. } => MockErrorKind::UnmockRecursionLimit,
+            Self::MustBeMocked { .. } => MockErrorKind::MustBeMocked,
+            Self::ForwardingLimit { .. } => MockErrorKind::ForwardingLimit,
+            Self::UnknownStateKey { .. } => MockErrorKind::UnknownStateKey,
+            Self::FutureDroppedUnresolved { .. } => MockErrorKind::FutureDroppedUnresolved,
+            #[cfg(feature = "mock-tokio-1")]
+            Self::DelayNotAwaited { .. } => MockErrorKind::DelayNotAwaited,
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::ReplayMismatch { .. } => MockErrorKind::ReplayMismatch,
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::ReplayExhausted { .. } => MockErrorKind::ReplayExhausted,
+            Self::CannotReplay { .. } => MockErrorKind::CannotReplay,
         }
     }
 }
@@ -149,8 +331,253 @@ impl core::fmt::Display for MockError {
 #[derive(Clone)]
 pub struct CallOrder(pub usize);
 
+/// An error from evaluating a call to a mocked function, returned by [Unimock::try_eval](crate::Unimock::try_eval).
+///
+/// Its [Display](core::fmt::Display) output is the message unimock would otherwise panic with,
+/// followed by the [location](MockError::location) of the failing call, if known.
+#[derive(Clone)]
+pub struct MockError {
+    pub(crate) failure: Box<MockFailure>,
+    pub(crate) called_at: Option<&'static core::panic::Location<'static>>,
+}
+
+impl MockError {
+    pub(crate) fn new(failure: MockFailure) -> Self {
+        Self {
+            failure: Box::new(failure),
+            called_at: None,
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn at_caller(failure: MockFailure) -> Self {
+        Self {
+            failure: Box::new(failure),
+            called_at: Some(core::panic::Location::caller()),
+        }
+    }
+
+    /// What kind of error this is.
+    pub fn kind(&self) -> MockErrorKind {
+        self.failure.kind()
+    }
+
+    /// The source location of the call that failed.
+    ///
+    /// This is `None` for errors that don't stem from a single call, like verification errors.
+    pub fn location(&self) -> Option<&'static core::panic::Location<'static>> {
+        self.called_at
+    }
+}
+
+impl core::fmt::Display for MockError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.failure.fmt(f)?;
+        if let Some(called_at) = self.called_at {
+            write!(f, "\nCalled at {called_at}.")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for MockError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MockError")
+            .field("kind", &self.kind())
+            .field("message", &crate::alloc::format!("{}", self.failure))
+            .field("location", &self.called_at)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MockError {}
+
+/// The kind of a [MockError].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MockErrorKind {
+    /// Internal error: A response had an unexpected type.
+    Downcast,
+    /// The function was called, but no clause was registered for it.
+    NoMockImplementation,
+    /// Internal error: A call pattern had no function for matching inputs.
+    NoMatcherFunction,
+    /// None of the call patterns matched the inputs.
+    NoMatchingCallPatterns,
+    /// A call pattern matched, but had no response for this call.
+    NoOutputAvailableForCallPattern,
+    /// A call pattern matched after it was closed by [Unimock::expect_no_more_calls](crate::Unimock::expect_no_more_calls).
+    NoMoreCallsExpected,
+    /// A function with a registered clause was never called.
+    MockNeverCalled,
+    /// An ordered call was made to a different function than expected.
+    CallOrderNotMatchedForMockFn,
+    /// An ordered call was made to the expected function, but the inputs didn't match.
+    InputsNotMatchedInCallOrder,
+    /// A value that could only be returned once was requested again.
+    CannotReturnValueMoreThanOnce,
+    /// A call count expectation was not met.
+    FailedVerification,
+    /// The function was set up to be unmocked, but has no unmocked implementation.
+    CannotUnmock,
+    /// The function was set up to apply its default implementation, but has none.
+    NoDefaultImpl,
+    /// Internal error: An answer function was not applied.
+    NotAnswered,
+    /// The response was set up with [panics](crate::build::DefineMultipleResponses::panics).
+    ExplicitPanic,
+    /// The unmocked implementation recursed into itself too deeply,
+    /// see [UnimockBuilder::unmock_recursion_limit](crate::build::UnimockBuilder::unmock_recursion_limit).
+    UnmockRecursionLimit,
+    /// The function was not matched by any call pattern, and must not fall back to its unmocked implementation,
+    /// see [MockFn::must_be_mocked](crate::MockFn::must_be_mocked).
+    MustBeMocked,
+    /// A call was forwarded between instances too many times,
+    /// see [forwards_to](crate::build::DefineResponse::forwards_to).
+    ForwardingLimit,
+    /// A key was read from a [state_map](crate::state_map) without being set first.
+    UnknownStateKey,
+    /// The future of an `async` call was dropped while pending, after it was required to resolve.
+    FutureDroppedUnresolved,
+    /// A delayed response was set up for a method that can't await it,
+    /// see [with_delay](crate::build::Quantify::with_delay).
+    DelayNotAwaited,
+    /// The inputs of a call differed from the next call replayed from a fixture file.
+    ReplayMismatch,
+    /// A call was made after all of its calls replayed from a fixture file had been made.
+    ReplayExhausted,
+    /// A replayed output could not be turned into the output of the function.
+    CannotReplay,
+}
+
 impl core::fmt::Display for CallOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.0 + 1)
     }
 }
+
+/// An error in the mock setup, detected while assembling the passed [Clause](crate::Clause)s into a [Unimock](crate::Unimock).
+///
+/// [UnimockBuilder::try_build](crate::build::UnimockBuilder::try_build) returns this error instead of panicking.
+/// Each `path` field is the `Trait::method` path of the [MockFn](crate::MockFn) the error is about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssembleError {
+    /// A stub was defined without any call patterns.
+    EmptyStub {
+        /// Path of the MockFn
+        path: String,
+    },
+    /// Clauses for the same MockFn used both ordered and unordered call patterns.
+    IncompatiblePatternMatchMode {
+        /// Path of the MockFn
+        path: String,
+        /// The mode of the first registered clause
+        old_mode: PatternMatchMode,
+        /// The mode of the conflicting clause
+        new_mode: PatternMatchMode,
+    },
+    /// Two different fallback modes were registered for the same MockFn.
+    IncompatibleFallbackMode {
+        /// Path of the MockFn
+        path: String,
+        /// The first registered fallback mode
+        old_mode: FallbackMode,
+        /// The conflicting fallback mode
+        new_mode: FallbackMode,
+    },
+    /// An ordered call pattern did not expect an exact number of calls.
+    MockHasNoExactExpectation {
+        /// Path of the MockFn
+        path: String,
+        /// The [label](crate::build::Quantify::named) of the call pattern, if any
+        label: Option<String>,
+    },
+    /// A response needs to be owned, but a borrowed value was passed.
+    OwnershipRequired {
+        /// Path of the MockFn
+        path: String,
+        /// The [label](crate::build::Quantify::named) of the call pattern, if any
+        label: Option<String>,
+    },
+    /// A response needs a Mutex, but none is available in this configuration.
+    NoMutexApi {
+        /// Path of the MockFn
+        path: String,
+        /// The [label](crate::build::Quantify::named) of the call pattern, if any
+        label: Option<String>,
+    },
+    /// [Unimock::replace](crate::Unimock::replace) was called while clones of the instance were alive.
+    ReplaceInSharedInstance {
+        /// Path of the MockFn
+        path: String,
+    },
+    /// The clause passed to [Unimock::replace](crate::Unimock::replace) set up another MockFn.
+    ReplaceWithOtherMockFn {
+        /// Path of the MockFn being replaced
+        path: String,
+    },
+}
+
+impl core::fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::EmptyStub { path } => {
+                write!(f, "{path}: Stub contained no call patterns")
+            }
+            Self::IncompatiblePatternMatchMode {
+                path,
+                old_mode,
+                new_mode,
+            } => write!(
+                f,
+                "A clause for {path} has already been registered as {old_mode:?}, but got re-registered as {new_mode:?}. They cannot be mixed for the same MockFn."
+            ),
+            Self::IncompatibleFallbackMode {
+                path,
+                old_mode,
+                new_mode,
+            } => write!(
+                f,
+                "A fallback mode for {path} has already been registered as {old_mode:?}, but got re-registered as {new_mode:?}."
+            ),
+            Self::MockHasNoExactExpectation { path, label } => {
+                write_labeled_path(f, path, label)?;
+                write!(f, ": Ordered call patterns must expect an exact number of calls.")
+            }
+            Self::OwnershipRequired { path, label } => {
+                write_labeled_path(f, path, label)?;
+                write!(f, ": Ownership required")
+            }
+            Self::NoMutexApi { path, label } => {
+                write_labeled_path(f, path, label)?;
+                write!(
+                    f,
+                    ": No Mutex API available. Enable the `spin-lock` feature in `no_std` mode, or use the `.answers` API instead of `.returns`."
+                )
+            }
+            Self::ReplaceInSharedInstance { path } => write!(
+                f,
+                "Called replace() on a shared instance. Drop all clones before replacing {path}."
+            ),
+            Self::ReplaceWithOtherMockFn { path } => {
+                write!(f, "The clause passed to replace() may only set up {path}.")
+            }
+        }
+    }
+}
+
+fn write_labeled_path(
+    f: &mut core::fmt::Formatter<'_>,
+    path: &str,
+    label: &Option<String>,
+) -> core::fmt::Result {
+    write!(f, "{path}")?;
+    if let Some(label) = label {
+        write!(f, " {label:?}")?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AssembleError {}
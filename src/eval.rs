@@ -1,83 +1,150 @@
-use crate::alloc::{Box, String};
-use crate::call_pattern::{CallPattern, PatIndex, PatternError, PatternResult};
+use crate::alloc::Box;
+use crate::call_pattern::{
+    CallPattern, CountedCall, PatIndex, PatternError, PatternResult, PendingPolls,
+};
 use crate::error::{self};
-use crate::error::{MockError, MockResult};
-use crate::fn_mocker::{FnMocker, PatternMatchMode};
-use crate::mismatch::Mismatches;
-use crate::private::{Continuation, Eval, MismatchReporter};
-use crate::responder::{DowncastResponder, DynResponder};
+use crate::error::{MockFailure, MockResult};
+use crate::fn_mocker::{Fallback, FallbackMode, FnMocker, PatternMatchMode};
+use crate::mismatch::{Mismatches, MismatchesBuilder, NoMatchHints};
+use crate::output::{GetOutput, Kind};
+#[cfg(all(feature = "serde", feature = "std"))]
+use crate::private::ReplayedOutput;
+use crate::private::{Continuation, Eval, MismatchReporter, OutputMapper};
+use crate::responder::{DowncastResponder, DynResponder, DynReturnResponder};
 use crate::state::SharedState;
-use crate::{debug, MockFnInfo, Unimock};
-use crate::{FallbackMode, MockFn};
+use crate::{debug, MockFn, MockFnInfo, Unimock};
 
-enum EvalResult<'u> {
+pub(crate) enum EvalResult<'u> {
     Responder(EvalResponder<'u>),
     Unmock,
     CallDefaultImpl,
+    #[cfg(all(feature = "serde", feature = "std"))]
+    Replay(serde_json::Value),
 }
 
-struct EvalResponder<'u> {
+impl<'u> EvalResult<'u> {
+    /// The time to wait before responding.
+    #[cfg(feature = "mock-tokio-1")]
+    pub fn delay(&self) -> Option<core::time::Duration> {
+        match self {
+            Self::Responder(eval_responder) => eval_responder.delay,
+            Self::Unmock | Self::CallDefaultImpl => None,
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::Replay(_) => None,
+        }
+    }
+
+    /// How an `async` response stays pending before resolving.
+    pub fn pending_polls(&self) -> Option<PendingPolls> {
+        match self {
+            Self::Responder(eval_responder) => eval_responder.pending_polls,
+            Self::Unmock | Self::CallDefaultImpl => None,
+            #[cfg(all(feature = "serde", feature = "std"))]
+            Self::Replay(_) => None,
+        }
+    }
+}
+
+pub(crate) struct EvalResponder<'u> {
     fn_mocker: &'u FnMocker,
     pat_index: PatIndex,
     dyn_responder: &'u DynResponder,
+    #[cfg_attr(not(feature = "mock-tokio-1"), allow(dead_code))]
+    delay: Option<core::time::Duration>,
+    pending_polls: Option<PendingPolls>,
 }
 
 pub(crate) fn eval<'u, 'i, F: MockFn>(
     unimock: &'u Unimock,
     inputs: F::Inputs<'i>,
 ) -> MockResult<Eval<'u, 'i, F>> {
+    let eval_result = eval_responder::<F>(unimock, &inputs)?;
+
+    // A delay can only be awaited, see `private::eval_async`
+    #[cfg(feature = "mock-tokio-1")]
+    if eval_result.delay().is_some() {
+        return Err(MockFailure::DelayNotAwaited { info: F::info() });
+    }
+
+    respond(unimock, inputs, eval_result)
+}
+
+/// Find the responder for a call, without producing the response.
+pub(crate) fn eval_responder<'u, F: MockFn>(
+    unimock: &'u Unimock,
+    inputs: &F::Inputs<'_>,
+) -> MockResult<EvalResult<'u>> {
     let dyn_ctx = DynCtx {
         info: F::info(),
         shared_state: &unimock.shared_state,
-        input_debugger: &|| F::debug_inputs(&inputs),
+        input_debugger: &|| debug_inputs::<F>(&unimock.shared_state, inputs),
     };
 
-    match dyn_ctx.eval_dyn(&|pattern, reporter| pattern.match_inputs::<F>(&inputs, reporter))? {
-        EvalResult::Responder(eval_responder) => match eval_responder.dyn_responder {
-            DynResponder::Return(dyn_return_responder) => {
-                match dyn_ctx
-                    .downcast_responder::<F, _>(dyn_return_responder, &eval_responder)?
-                    .get_output()
-                {
-                    Some(output) => Ok(Eval::Return(output)),
-                    None => Err(MockError::CannotReturnValueMoreThanOnce {
-                        fn_call: dyn_ctx.fn_call(),
-                        pattern: eval_responder
-                            .fn_mocker
-                            .debug_pattern(eval_responder.pat_index),
-                    }),
-                }
-            }
-            DynResponder::Answer(dyn_responder) => {
-                let answerer =
-                    dyn_ctx.downcast_responder::<F, _>(dyn_responder, &eval_responder)?;
-                Ok(Eval::Continue(
-                    Continuation::Answer(answerer.answer_closure.clone()),
-                    inputs,
-                ))
-            }
-            DynResponder::Panic(msg) => Err(MockError::ExplicitPanic {
-                fn_call: dyn_ctx.fn_call(),
-                pattern: eval_responder
-                    .fn_mocker
-                    .debug_pattern(eval_responder.pat_index),
-                msg: msg.clone(),
-            }),
-            DynResponder::Unmock => Ok(Eval::Continue(Continuation::Unmock, inputs)),
-            DynResponder::ApplyDefaultImpl => {
-                Ok(Eval::Continue(Continuation::CallDefaultImpl, inputs))
+    let eval_result =
+        dyn_ctx.eval_dyn(&|pattern, reporter| pattern.match_inputs::<F>(inputs, reporter))?;
+
+    if let EvalResult::Responder(eval_responder) = &eval_result {
+        eval_responder.fn_mocker.call_patterns[eval_responder.pat_index.0]
+            .record_inputs::<F>(inputs)
+            .map_err(|err| {
+                dyn_ctx.map_pattern_error(err, eval_responder.fn_mocker, eval_responder.pat_index)
+            })?;
+    }
+
+    Ok(eval_result)
+}
+
+/// Produce the response for a call, given its responder.
+pub(crate) fn respond<'u, 'i, F: MockFn>(
+    unimock: &'u Unimock,
+    inputs: F::Inputs<'i>,
+    eval_result: EvalResult<'u>,
+) -> MockResult<Eval<'u, 'i, F>> {
+    let dyn_ctx = DynCtx {
+        info: F::info(),
+        shared_state: &unimock.shared_state,
+        input_debugger: &|| debug_inputs::<F>(&unimock.shared_state, &inputs),
+    };
+
+    match eval_result {
+        EvalResult::Responder(eval_responder) => {
+            match dyn_ctx.respond_with::<F>(&eval_responder, eval_responder.dyn_responder)? {
+                Response::Return(output) => Ok(Eval::Return(output)),
+                Response::Continue(continuation) => Ok(Eval::Continue(continuation, inputs)),
             }
-        },
+        }
         EvalResult::Unmock => Ok(Eval::Continue(Continuation::Unmock, inputs)),
         EvalResult::CallDefaultImpl => Ok(Eval::Continue(Continuation::CallDefaultImpl, inputs)),
+        #[cfg(all(feature = "serde", feature = "std"))]
+        EvalResult::Replay(output) => Ok(Eval::Continue(
+            Continuation::Replay(ReplayedOutput(output)),
+            inputs,
+        )),
     }
 }
 
+/// Render the inputs of a call, preferring a formatter registered for `F`.
+fn debug_inputs<F: MockFn>(
+    shared_state: &SharedState,
+    inputs: &F::Inputs<'_>,
+) -> debug::InputsDebug {
+    match shared_state.input_format::<F>() {
+        Some(input_format) => debug::InputsDebug::Formatted((input_format.0)(inputs)),
+        None => debug::InputsDebug::Each(F::debug_inputs(inputs)),
+    }
+}
+
+/// The response of a responder, before being paired with the inputs.
+enum Response<'u, F: MockFn> {
+    Return(<<F::OutputKind as Kind>::Return as GetOutput>::Output<'u>),
+    Continue(Continuation<F>),
+}
+
 /// 'u = unimock instance, 's = stack
 struct DynCtx<'u, 's> {
     info: MockFnInfo,
     shared_state: &'u SharedState,
-    input_debugger: &'s dyn Fn() -> Box<[Option<String>]>,
+    input_debugger: &'s dyn Fn() -> debug::InputsDebug,
 }
 
 impl<'u, 's> DynCtx<'u, 's> {
@@ -86,86 +153,213 @@ impl<'u, 's> DynCtx<'u, 's> {
         &self,
         match_inputs: &dyn Fn(&CallPattern, Option<&mut MismatchReporter>) -> PatternResult<bool>,
     ) -> MockResult<EvalResult<'u>> {
-        let fn_mocker = match self.shared_state.fn_mockers.get(&self.info.type_id) {
+        self.shared_state.count_call(&self.info);
+        self.shared_state.log_call(|| self.fn_call());
+
+        let fn_mocker = match self.shared_state.fn_mocker(self.info.type_id) {
             None => {
+                #[cfg(all(feature = "serde", feature = "std"))]
+                if let Some(replay) = &self.shared_state.replay {
+                    if let Some(output) = replay.next_call(&self.info, || self.fn_call()) {
+                        return output.map(EvalResult::Replay);
+                    }
+                }
+
                 return if self.info.has_default_impl {
                     Ok(EvalResult::CallDefaultImpl)
-                } else if self.info.partial_by_default {
-                    Ok(EvalResult::Unmock)
                 } else {
-                    match self.shared_state.fallback_mode {
-                        FallbackMode::Error => Err(MockError::NoMockImplementation {
+                    match self.shared_state.unpatterned_fallback_mode_for(&self.info) {
+                        Fallback::Mode(FallbackMode::Error) => {
+                            Err(MockFailure::NoMockImplementation {
+                                fn_call: self.fn_call(),
+                            })
+                        }
+                        Fallback::Mode(FallbackMode::Unmock) => Ok(EvalResult::Unmock),
+                        Fallback::MustBeMocked => Err(MockFailure::MustBeMocked {
                             fn_call: self.fn_call(),
                         }),
-                        FallbackMode::Unmock => Ok(EvalResult::Unmock),
                     }
-                }
+                };
             }
             Some(fn_mocker) => fn_mocker,
         };
 
         match self.match_call_pattern(fn_mocker, match_inputs)? {
-            Some((pat_index, pattern)) => match pattern.next_responder() {
-                Some(dyn_responder) => Ok(EvalResult::Responder(EvalResponder {
+            Some((pat_index, CountedCall::Closed)) => Err(MockFailure::NoMoreCallsExpected {
+                fn_call: self.fn_call(),
+                pattern: fn_mocker.debug_pattern(pat_index),
+            }),
+            Some((pat_index, CountedCall::Responder(Some(call_order_responder)))) => {
+                Ok(EvalResult::Responder(EvalResponder {
                     fn_mocker,
                     pat_index,
-                    dyn_responder,
-                })),
-                None => Err(MockError::NoOutputAvailableForCallPattern {
+                    dyn_responder: &call_order_responder.responder,
+                    delay: call_order_responder.delay,
+                    pending_polls: call_order_responder.pending_polls,
+                }))
+            }
+            Some((pat_index, CountedCall::Responder(None))) => {
+                Err(MockFailure::NoOutputAvailableForCallPattern {
                     fn_call: self.fn_call(),
                     pattern: fn_mocker.debug_pattern(pat_index),
-                }),
-            },
-            None => match self.shared_state.fallback_mode {
-                FallbackMode::Error => {
-                    let mut builder = Mismatches::builder();
+                })
+            }
+            None | Some((_, CountedCall::Inactive)) => match self
+                .shared_state
+                .fallback_mode_for(&self.info)
+            {
+                Fallback::Mode(FallbackMode::Error) => {
+                    let mut builder = self.mismatches_builder();
                     for (pat_index, call_pattern) in fn_mocker.call_patterns.iter().enumerate() {
                         let mut mismatch_reporter = MismatchReporter::new_enabled();
                         let _ = match_inputs(call_pattern, Some(&mut mismatch_reporter));
-                        builder.collect_from_reporter(PatIndex(pat_index), mismatch_reporter);
+                        builder.collect_from_reporter(
+                            PatIndex(pat_index),
+                            call_pattern.label,
+                            mismatch_reporter,
+                        );
                     }
 
-                    Err(MockError::NoMatchingCallPatterns {
+                    let mismatches = builder.build();
+                    let hints = NoMatchHints {
+                        closest: mismatches.closest(|pat_index| fn_mocker.debug_pattern(pat_index)),
+                        debug_hints: fn_mocker
+                            .call_patterns
+                            .iter()
+                            .filter_map(|call_pattern| call_pattern.input_matcher.debug_hint)
+                            .collect(),
+                    };
+
+                    Err(MockFailure::NoMatchingCallPatterns {
                         fn_call: self.fn_call(),
-                        mismatches: builder.build(),
+                        mismatches,
+                        hints: Box::new(hints),
                     })
                 }
-                FallbackMode::Unmock => Ok(EvalResult::Unmock),
+                Fallback::Mode(FallbackMode::Unmock) => Ok(EvalResult::Unmock),
+                Fallback::MustBeMocked => Err(MockFailure::MustBeMocked {
+                    fn_call: self.fn_call(),
+                }),
             },
         }
     }
 
+    fn respond_with<F: MockFn>(
+        &self,
+        eval_responder: &EvalResponder<'u>,
+        dyn_responder: &'u DynResponder,
+    ) -> MockResult<Response<'u, F>> {
+        match dyn_responder {
+            DynResponder::Return(dyn_return_responder) => {
+                match self.try_return::<F>(eval_responder, dyn_return_responder)? {
+                    Some(output) => Ok(Response::Return(output)),
+                    None => Err(MockFailure::CannotReturnValueMoreThanOnce {
+                        fn_call: self.fn_call(),
+                        pattern: eval_responder
+                            .fn_mocker
+                            .debug_pattern(eval_responder.pat_index),
+                    }),
+                }
+            }
+            DynResponder::Answer(dyn_responder) => {
+                let answerer = self.downcast_responder::<F, _>(dyn_responder, eval_responder)?;
+                Ok(Response::Continue(Continuation::Answer(
+                    answerer.answer_closure.clone(),
+                )))
+            }
+            DynResponder::Panic(msg) => Err(MockFailure::ExplicitPanic {
+                fn_call: self.fn_call(),
+                pattern: eval_responder
+                    .fn_mocker
+                    .debug_pattern(eval_responder.pat_index),
+                msg: msg.clone(),
+            }),
+            DynResponder::Unmock => Ok(Response::Continue(Continuation::Unmock)),
+            DynResponder::UnmockMap(map_fn) => Ok(Response::Continue(Continuation::UnmockMap(
+                OutputMapper(map_fn.clone()),
+            ))),
+            DynResponder::ApplyDefaultImpl => Ok(Response::Continue(Continuation::CallDefaultImpl)),
+            DynResponder::Conditional(_, inner) => self.respond_with(eval_responder, inner),
+            DynResponder::Intermittent(intermittent) => {
+                self.respond_with(eval_responder, intermittent.next_responder())
+            }
+            DynResponder::FallbackChain(chain) => {
+                let Some((last, fallible)) = chain.split_last() else {
+                    return Err(MockFailure::NoOutputAvailableForCallPattern {
+                        fn_call: self.fn_call(),
+                        pattern: eval_responder
+                            .fn_mocker
+                            .debug_pattern(eval_responder.pat_index),
+                    });
+                };
+
+                for dyn_responder in fallible {
+                    match dyn_responder {
+                        DynResponder::Return(dyn_return_responder) => {
+                            if let Some(output) =
+                                self.try_return::<F>(eval_responder, dyn_return_responder)?
+                            {
+                                return Ok(Response::Return(output));
+                            }
+                        }
+                        DynResponder::Panic(_) => {}
+                        other => return self.respond_with(eval_responder, other),
+                    }
+                }
+
+                // The last responder reports its own failure
+                self.respond_with(eval_responder, last)
+            }
+        }
+    }
+
+    fn try_return<F: MockFn>(
+        &self,
+        eval_responder: &EvalResponder<'u>,
+        dyn_return_responder: &'u DynReturnResponder,
+    ) -> MockResult<Option<<<F::OutputKind as Kind>::Return as GetOutput>::Output<'u>>> {
+        Ok(self
+            .downcast_responder::<F, _>(dyn_return_responder, eval_responder)?
+            .get_output())
+    }
+
     fn match_call_pattern(
         &self,
         fn_mocker: &'u FnMocker,
         match_inputs: &dyn Fn(&CallPattern, Option<&mut MismatchReporter>) -> PatternResult<bool>,
-    ) -> MockResult<Option<(PatIndex, &'u CallPattern)>> {
+    ) -> MockResult<Option<(PatIndex, CountedCall<'u>)>> {
         match fn_mocker.pattern_match_mode {
-            PatternMatchMode::InAnyOrder => fn_mocker
-                .call_patterns
-                .iter()
-                .enumerate()
-                .filter_map(
-                    |(pat_index, call_pattern)| match match_inputs(call_pattern, None) {
-                        Ok(false) => None,
-                        Ok(true) => Some(Ok((PatIndex(pat_index), call_pattern))),
-                        Err(err) => Some(Err((PatIndex(pat_index), err))),
-                    },
-                )
-                .next()
-                .transpose()
-                .map_err(|(pat_index, err)| self.map_pattern_error(err, fn_mocker, pat_index)),
+            PatternMatchMode::InAnyOrder => {
+                for (pat_index, call_pattern) in fn_mocker.prioritized_call_patterns() {
+                    if !match_inputs(call_pattern, None)
+                        .map_err(|err| self.map_pattern_error(err, fn_mocker, pat_index))?
+                    {
+                        continue;
+                    }
+
+                    // A call pattern with an inactive responder is treated as not matching
+                    match call_pattern.count_call() {
+                        CountedCall::Inactive => continue,
+                        counted_call => return Ok(Some((pat_index, counted_call))),
+                    }
+                }
+
+                Ok(None)
+            }
             PatternMatchMode::InOrder => {
                 let ordered_call_index = self.shared_state.bump_ordered_call_index();
 
                 let (pat_index, pattern) = fn_mocker
                     .find_call_pattern_for_call_order(ordered_call_index)
-                    .ok_or_else(|| MockError::CallOrderNotMatchedForMockFn {
+                    .ok_or_else(|| MockFailure::CallOrderNotMatchedForMockFn {
                         fn_call: self.fn_call(),
                         actual_call_order: error::CallOrder(ordered_call_index),
                         expected: self
                             .shared_state
                             .find_ordered_expected_call_pattern_debug(ordered_call_index),
+                        matched_in_order: self
+                            .shared_state
+                            .find_ordered_matched_call_patterns_debug(ordered_call_index),
                     })?;
 
                 let mut mismatch_reporter = MismatchReporter::new_enabled();
@@ -173,10 +367,10 @@ impl<'u, 's> DynCtx<'u, 's> {
                 if !match_inputs(pattern, Some(&mut mismatch_reporter))
                     .map_err(|err| self.map_pattern_error(err, fn_mocker, pat_index))?
                 {
-                    let mut builder = Mismatches::builder();
-                    builder.collect_from_reporter(pat_index, mismatch_reporter);
+                    let mut builder = self.mismatches_builder();
+                    builder.collect_from_reporter(pat_index, pattern.label, mismatch_reporter);
 
-                    return Err(MockError::InputsNotMatchedInCallOrder {
+                    return Err(MockFailure::InputsNotMatchedInCallOrder {
                         fn_call: self.fn_call(),
                         actual_call_order: error::CallOrder(ordered_call_index),
                         pattern: fn_mocker.debug_pattern(pat_index),
@@ -184,7 +378,7 @@ impl<'u, 's> DynCtx<'u, 's> {
                     });
                 }
 
-                Ok(Some((pat_index, pattern)))
+                Ok(Some((pat_index, pattern.count_call())))
             }
         }
     }
@@ -209,13 +403,13 @@ impl<'u, 's> DynCtx<'u, 's> {
         pattern_error: PatternError,
         fn_mocker: &FnMocker,
         pat_index: PatIndex,
-    ) -> MockError {
+    ) -> MockFailure {
         match pattern_error {
-            PatternError::Downcast => MockError::Downcast {
+            PatternError::Downcast => MockFailure::Downcast {
                 fn_call: self.fn_call(),
                 pattern: fn_mocker.debug_pattern(pat_index),
             },
-            PatternError::NoMatcherFunction => MockError::NoMatcherFunction {
+            PatternError::NoMatcherFunction => MockFailure::NoMatcherFunction {
                 fn_call: self.fn_call(),
                 pattern: fn_mocker.debug_pattern(pat_index),
             },
@@ -229,7 +423,16 @@ impl<'u, 's> DynCtx<'u, 's> {
         }
     }
 
-    fn debug_inputs(&self) -> Box<[Option<String>]> {
-        (self.input_debugger)()
+    fn max_debug_len(&self) -> usize {
+        self.shared_state.max_debug_len_for(&self.info)
+    }
+
+    fn mismatches_builder(&self) -> MismatchesBuilder {
+        Mismatches::builder(self.max_debug_len())
+            .hide_actual(self.shared_state.has_input_format(&self.info))
+    }
+
+    fn debug_inputs(&self) -> debug::InputsDebug {
+        (self.input_debugger)().elide(self.max_debug_len())
     }
 }
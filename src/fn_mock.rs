@@ -0,0 +1,62 @@
+use core::marker::PhantomData;
+
+use crate::private::{Continuation, Eval};
+use crate::{output, MockFn, MockFnInfo, Unimock};
+
+/// A ready-made [MockFn] for plain functions taking `I` and returning `O`, e.g. callbacks.
+///
+/// It avoids writing a mocked trait just to get a [MockFn] type for a one-off function.
+/// Like in generated mocks, a single input is passed as-is and several inputs are passed as a tuple.
+/// The inputs must be owned, and are not printed in error messages.
+///
+/// Every distinct `FnMock<I, O>` type is one [MockFn], so two callbacks with the same signature share their clauses.
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// fn describe(number: i32, describe_fn: impl Fn(i32) -> String) -> String {
+///     describe_fn(number)
+/// }
+///
+/// let u = Unimock::new(
+///     FnMock::<i32, String>::new()
+///         .next_call(matching!(1))
+///         .returns("one"),
+/// );
+///
+/// assert_eq!("one", describe(1, |n| FnMock::call(&u, n)));
+/// ```
+pub struct FnMock<I, O>(PhantomData<fn(I) -> O>);
+
+impl<I: 'static, O: 'static> FnMock<I, O> {
+    /// Create the [MockFn] value, for registering clauses.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Call the mocked function with the given inputs.
+    #[track_caller]
+    pub fn call(unimock: &Unimock, inputs: I) -> O {
+        match crate::private::eval::<Self>(unimock, inputs) {
+            Eval::Return(output) => output,
+            Eval::Continue(Continuation::Answer(answer_fn), inputs) => answer_fn(unimock, inputs),
+            Eval::Continue(continuation, _) => continuation.report(unimock),
+        }
+    }
+}
+
+impl<I: 'static, O: 'static> Default for FnMock<I, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: 'static, O: 'static> MockFn for FnMock<I, O> {
+    type Inputs<'i> = I;
+    type OutputKind = output::Owning<O>;
+    type AnswerFn = dyn Fn(&Unimock, I) -> O + Send + Sync;
+
+    fn info() -> MockFnInfo {
+        MockFnInfo::new::<Self>().path(&["FnMock", "call"])
+    }
+}
@@ -1,10 +1,14 @@
 use crate::alloc::Vec;
 use crate::call_pattern::PatIndex;
-use crate::error::MockError;
+use crate::error::MockFailure;
 use crate::*;
 
+/// How the call patterns of a [MockFn] are matched against calls.
+///
+/// [MockFn::each_call] and [MockFn::stub] set up patterns in any order,
+/// while [MockFn::next_call] sets up ordered patterns.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
-pub(crate) enum PatternMatchMode {
+pub enum PatternMatchMode {
     /// Each new call starts at the first call pattern, tries to
     /// match it and then goes on to the next one until success.
     InAnyOrder,
@@ -13,12 +17,47 @@ pub(crate) enum PatternMatchMode {
     InOrder,
 }
 
+/// What to do when a call is not matched by any call pattern.
+///
+/// [Unimock::new] uses [FallbackMode::Error], while [Unimock::new_partial] uses [FallbackMode::Unmock].
+/// The mode may be chosen explicitly using [Unimock::builder].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum FallbackMode {
+    /// Produce an error
+    Error,
+    /// Call the unmocked implementation
+    Unmock,
+}
+
+/// What to do when a call to one [MockFn] is not matched, set up by a [FallbackOverride](crate::build::FallbackOverride).
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Fallback {
+    /// Use this mode instead of the instance-wide one
+    Mode(FallbackMode),
+    /// Fail with an error saying that the [MockFn] must be mocked, see [MockFn::must_be_mocked]
+    MustBeMocked,
+}
+
+impl Fallback {
+    /// The mode that this fallback acts like.
+    pub fn mode(self) -> FallbackMode {
+        match self {
+            Self::Mode(mode) => mode,
+            Self::MustBeMocked => FallbackMode::Error,
+        }
+    }
+}
+
 /// Holds all the state for mocking one particular MockFn
 /// during Unimock's lifetime
 pub(crate) struct FnMocker {
     pub info: MockFnInfo,
     pub pattern_match_mode: PatternMatchMode,
     pub call_patterns: Vec<call_pattern::CallPattern>,
+    /// Indices into `call_patterns`, in the order they are tried for unordered calls
+    pub priority_order: Vec<usize>,
+    /// Set up only by a [Fixture](crate::build::Fixture), so it's fine if it is never called
+    pub optional: bool,
 }
 
 impl FnMocker {
@@ -36,14 +75,63 @@ impl FnMocker {
             .map(|(index, call_pattern)| (PatIndex(index), call_pattern))
     }
 
+    /// Compute the order of [Self::prioritized_call_patterns], after the call patterns have changed.
+    pub fn update_priority_order(&mut self) {
+        let call_patterns = &self.call_patterns;
+        self.priority_order = (0..call_patterns.len()).collect();
+        self.priority_order.sort_by_key(|index| {
+            (
+                call_patterns[*index].default_stub,
+                core::cmp::Reverse(call_patterns[*index].priority),
+            )
+        });
+    }
+
+    /// The call patterns in the order they are tried for unordered calls:
+    /// highest priority first, then in the order they were defined, with default stubs last.
+    pub fn prioritized_call_patterns(
+        &self,
+    ) -> impl Iterator<Item = (PatIndex, &call_pattern::CallPattern)> + '_ {
+        self.priority_order
+            .iter()
+            .map(|index| (PatIndex(*index), &self.call_patterns[*index]))
+    }
+
     pub fn debug_pattern(&self, pat_index: PatIndex) -> debug::CallPatternDebug {
+        let call_pattern = &self.call_patterns[pat_index.0];
         debug::CallPatternDebug::new(
             self.info,
-            self.call_patterns[pat_index.0].debug_location(pat_index),
+            call_pattern.debug_location(pat_index),
+            call_pattern.label,
         )
     }
 
-    pub fn verify(&self, errors: &mut Vec<MockError>) {
+    /// A bounded summary of this mocker's state, without any input or output values.
+    pub fn debug_summary(&self) -> FnMockerSummary<'_> {
+        FnMockerSummary(self)
+    }
+
+    pub fn verify(&self, errors: &mut Vec<MockFailure>) {
+        let total_calls = self.verify_call_counts(errors);
+
+        if total_calls == 0 && !self.optional {
+            errors.push(error::MockFailure::MockNeverCalled { info: self.info });
+        }
+    }
+
+    /// Forbid further calls to the call patterns whose expectations are already satisfied.
+    ///
+    /// Default stubs have no expectations, and stay callable.
+    pub fn close_satisfied_call_patterns(&self) {
+        for pattern in self.call_patterns.iter() {
+            if !pattern.default_stub {
+                pattern.call_counter.close_if_satisfied();
+            }
+        }
+    }
+
+    /// Verify the call counts of all call patterns against their expectations, returning the total number of calls.
+    pub fn verify_call_counts(&self, errors: &mut Vec<MockFailure>) -> usize {
         let mut total_calls = 0;
 
         for (pat_index, pattern) in self.call_patterns.iter().enumerate() {
@@ -57,8 +145,26 @@ impl FnMocker {
                 .0;
         }
 
-        if total_calls == 0 {
-            errors.push(error::MockError::MockNeverCalled { info: self.info });
-        }
+        total_calls
+    }
+}
+
+pub(crate) struct FnMockerSummary<'a>(&'a FnMocker);
+
+impl core::fmt::Debug for FnMockerSummary<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mocker = self.0;
+        let call_counts: Vec<usize> = mocker
+            .call_patterns
+            .iter()
+            .map(|pattern| pattern.call_counter.count())
+            .collect();
+
+        f.debug_struct("MockFn")
+            .field("path", &format_args!("{}", mocker.info.path))
+            .field("patterns", &mocker.call_patterns.len())
+            .field("mode", &mocker.pattern_match_mode)
+            .field("calls", &call_counts)
+            .finish()
     }
 }
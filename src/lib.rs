@@ -378,6 +378,42 @@
 //! Working with associated types in a mock environment like Unimock has its limitations.
 //! The nature of associated types is that there is one type per implementation, and there is only one mock implementation, so the type must be chosen carefully.
 //!
+//! Generic associated types are specified the same way, including their generic parameters:
+//!
+//! ```rust
+//! # use unimock::*;
+//! #[derive(Debug, PartialEq)]
+//! pub struct Word<'a>(&'a str);
+//!
+//! #[unimock(api = LenderMock, type Item<'a> = Word<'a>;)]
+//! pub trait Lender {
+//!     type Item<'a>
+//!     where
+//!         Self: 'a;
+//!
+//!     fn len(&self, item: Self::Item<'_>) -> usize;
+//! }
+//!
+//! let u = Unimock::new(
+//!     LenderMock::len
+//!         .each_call(matching!(Word("abc")))
+//!         .returns(3_usize),
+//! );
+//!
+//! let word = String::from("abc");
+//! assert_eq!(3, u.len(Word(&word)));
+//! ```
+//!
+//! Leaving out the type of an associated type without a default is a compile error pointing at the associated type:
+//!
+//! ```compile_fail
+//! # use unimock::*;
+//! #[unimock(api = LenderMock)]
+//! trait Lender {
+//!     type Item<'a>; // The associated type `Item` must be given a concrete type for the mock, e.g. `#[unimock(type Item<'a> = ...;)]`
+//! }
+//! ```
+//!
 //! #### Associated constants
 //! Associated constants in traits may be specified using the `const` keyword in the unimock macro:
 //!
@@ -437,6 +473,7 @@ extern crate std;
 pub mod alloc {
     extern crate alloc;
 
+    pub use alloc::borrow::{Cow, ToOwned};
     pub use alloc::boxed::Box;
     pub use alloc::collections::btree_map::Entry;
     pub use alloc::collections::BTreeMap;
@@ -445,7 +482,7 @@ pub mod alloc {
     pub use alloc::rc::Rc;
     pub use alloc::string::String;
     pub use alloc::string::ToString;
-    pub use alloc::sync::Arc;
+    pub use alloc::sync::{Arc, Weak};
     pub use alloc::vec;
     pub use alloc::vec::Vec;
 }
@@ -462,6 +499,11 @@ pub mod property;
 /// Mock APIs for various crates.
 pub mod mock;
 
+#[cfg(feature = "clock")]
+pub mod clock;
+
+pub mod macro_api;
+
 /// APIs used by macros, etc
 #[doc(hidden)]
 pub mod private;
@@ -486,11 +528,20 @@ mod counter;
 mod debug;
 mod error;
 mod eval;
+mod fn_mock;
 mod fn_mocker;
 mod mismatch;
+mod recording;
+#[cfg(all(feature = "serde", feature = "std"))]
+mod replay;
+mod report;
 mod responder;
 mod state;
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod state_map;
 mod teardown;
+#[cfg(feature = "std")]
+mod verify_handle;
 
 use core::any::Any;
 use core::any::TypeId;
@@ -501,7 +552,6 @@ use core::panic::UnwindSafe;
 use once_cell::sync::OnceCell;
 
 use alloc::Box;
-use assemble::MockAssembler;
 use call_pattern::DynInputMatcher;
 use debug::TraitMethodPath;
 use output::Kind;
@@ -543,7 +593,7 @@ use private::{DefaultImplDelegator, Matching};
 /// # Unmocking
 /// _Unmocking_ of a mocked function means falling back to a true implementation.
 ///
-/// A true implementation must be a standalone function, not part of a trait,
+/// A true implementation is usually a standalone function, not part of a trait,
 /// where the first parameter is generic (a `self`-replacement), and the rest of the parameters are
 /// identical to [MockFn::Inputs]:
 ///
@@ -560,6 +610,70 @@ use private::{DefaultImplDelegator, Matching};
 /// }
 /// ```
 ///
+/// The true implementation may also be an associated function of some type, as long as its first parameter is the `self`-replacement.
+/// For small adaptations, a closure can be given instead of a path.
+/// It receives the `self`-replacement followed by the inputs of the method, and the number of parameters is checked by the macro:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[derive(Default)]
+/// struct RealGateway;
+///
+/// impl RealGateway {
+///     fn charge<T>(_: T, amount: u32) -> u32 {
+///         amount
+///     }
+///
+///     fn charge_with<T>(&self, _: T, amount: u32) -> u32 {
+///         amount + 1
+///     }
+/// }
+///
+/// #[unimock(unmock_with=[
+///     RealGateway::charge,
+///     |deps, amount| RealGateway::default().charge_with(deps, amount)
+/// ])]
+/// trait Gateway {
+///     fn charge(&self, amount: u32) -> u32;
+///     fn charge_with_fee(&self, amount: u32) -> u32;
+/// }
+/// ```
+///
+/// For `async` methods, and methods returning `impl Future`, the true implementation is awaited.
+/// It can be an `async fn`, or any function or closure returning a future:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=ShopMock, unmock_with=[real_total, _])]
+/// trait Shop {
+///     async fn total(&self, items: u32) -> u32;
+///     async fn price(&self) -> u32;
+/// }
+///
+/// // `shop.price()` still passes through unimock:
+/// async fn real_total(shop: &impl Shop, items: u32) -> u32 {
+///     shop.price().await * items
+/// }
+/// ```
+///
+/// For generic methods, the true implementation must be generic over the same type parameters, in the same order.
+/// The method's parameters are forwarded to it explicitly, so they need not be inferable from the inputs.
+/// A path given with its own generic arguments is called with those instead, and their number is checked by the macro:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=NamerMock, unmock_with=[real_name])]
+/// trait Namer {
+///     fn name<T: 'static>(&self) -> &'static str;
+/// }
+///
+/// fn real_name<T: 'static>(_: &impl core::any::Any) -> &'static str {
+///     core::any::type_name::<T>()
+/// }
+///
+/// assert_eq!("u8", Unimock::new_partial(()).name::<u8>());
+/// ```
+///
 /// The unmock feature makes sense when the reason to define a mockable trait is _solely_ for the purpose of inversion-of-control at test-time:
 ///   Release code need only one way to double a number.
 ///
@@ -594,18 +708,109 @@ use private::{DefaultImplDelegator, Matching};
 /// );
 /// ```
 ///
+/// ## Partially mocking a concrete type
+/// Unimock only mocks traits, so code that calls inherent methods on a concrete type can't be redirected to mocks.
+/// To mock some methods of a concrete type while keeping the real behaviour of the others,
+/// extract a trait, store the real object in the instance using [Unimock::with_value],
+/// and let the unmock functions delegate to it through [AsValueStore]:
+///
+/// ```rust
+/// # use unimock::*;
+/// struct Database {
+///     rows: Vec<&'static str>,
+/// }
+///
+/// #[unimock(api=DbMock, unmock_with=[real_count, real_first])]
+/// trait Db {
+///     fn count(&self) -> usize;
+///     fn first(&self) -> Option<&'static str>;
+/// }
+///
+/// impl Db for Database {
+///     fn count(&self) -> usize {
+///         self.rows.len()
+///     }
+///     fn first(&self) -> Option<&'static str> {
+///         self.rows.first().copied()
+///     }
+/// }
+///
+/// fn real_count(store: &impl AsValueStore) -> usize {
+///     store.get_value::<Database>().unwrap().count()
+/// }
+/// fn real_first(store: &impl AsValueStore) -> Option<&'static str> {
+///     store.get_value::<Database>().unwrap().first()
+/// }
+///
+/// let u = Unimock::new_partial(DbMock::count.some_call(matching!()).returns(0_usize))
+///     .with_value(Database { rows: vec!["a", "b"] });
+///
+/// assert_eq!(0, u.count());
+/// assert_eq!(Some("a"), u.first());
+/// ```
+///
+/// # Diverging methods
+/// A method returning the never type `!` gets [core::convert::Infallible] as its output type.
+/// Since no such value can be constructed, the only useful responses are `panics` and closures passed to `answers` that diverge.
+///
+/// # Trait aliases
+/// With the `trait-alias` feature, the macro also accepts a nightly trait alias, e.g. `#[unimock] trait Service = Read + Write;`.
+/// An alias is implemented by every type implementing its constituent traits, so each of those needs its own `#[unimock]`.
+/// The macro checks that [Unimock] implements the alias, so that a missing mock is reported at the alias.
+/// Only the `prefix` argument is accepted.
+///
+/// # Test helpers
+/// `#[unimock(test_helpers)]` leaves out the stub factories a method's output kind can't support.
+/// Whether an owned output implements [Default] can't be seen from the signature, though,
+/// so `stub_<trait>_<method>_default()` is also generated for owned outputs without a [Default] implementation.
+/// Such a factory fails to compile where it is used:
+///
+/// ```compile_fail
+/// # use unimock::*;
+/// mod source {
+///     # use unimock::*;
+///     pub struct NoDefault;
+///
+///     #[unimock(api=SourceMock, test_helpers)]
+///     pub trait Source {
+///         fn get(&self) -> NoDefault;
+///     }
+/// }
+///
+/// // the trait bound `NoDefault: Default` is not satisfied
+/// let u = Unimock::new(source::test_helpers::stub_source_get_default());
+/// ```
 ///
 /// # Arguments
 /// The unimock macro accepts a number of comma or colon-separated key-value configuration parameters:
 ///
 /// * `#[unimock(api=#ident), ]`: Export a mocking API as a module with the given name
 /// * `#[unimock(api=[method1, method2, ..], )]`: Instead of generating a module, generate top-level mock structs for the methods in the trait,
-///     with the names of those structs passed with array-like syntax in the same order as the methods appear in the trait definition.
+///   with the names of those structs passed with array-like syntax in the same order as the methods appear in the trait definition.
+/// * `#[unimock(rename_fn="{Trait}{Method}Mock", )]`: Name the mock structs using a template instead.
+///   The placeholders `{Trait}` and `{method}` are replaced by the trait and method names as written, `{trait}` by the trait name in snake_case
+///   and `{Method}` by the method name in PascalCase. Without `api`, the structs are generated at the top level, otherwise inside the `api` module.
+///   It is a compile error if the template produces an invalid identifier.
 /// * `#[unimock(unmock_with=[a, b, _], )]`: Given there are e.g. 3 methods in the annotated trait, uses the given paths as unmock implementations.
-///     The functions are assigned to the methods in the same order as the methods are listed in the trait.
-///     A value of `_` means _no unmock support_ for that method.
+///   The functions are assigned to the methods in the same order as the methods are listed in the trait.
+///   A value of `_` means _no unmock support_ for that method.
+///   Each entry may be a function path, an associated function path or a closure taking the `self`-replacement followed by the method inputs.
 /// * `#[unimock(prefix=path, )]`: Makes unimock use a different path prefix than `::unimock`, in case the crate has been re-exported through another crate.
+/// * `#[unimock(derive_debug, )]`: Derive [Debug](core::fmt::Debug) for the mock structs, so they can be used with e.g. `assert_eq!` or `dbg!`.
+///   Each struct is formatted as its name.
+/// * `#[unimock(derive_default_stub, )]`: Generate a `default_stub()` function in the `api` module, returning a [Clause] that
+///   stubs every non-generic method whose output implements [Default] to return a default value for every call.
+///   Other clauses take precedence over the default stub, and the stubbed methods may go uncalled.
+///   Requires `api` to be set.
 /// * `#[unimock(type #ident = #assoc; )]`: Specify the value of the associated type `#ident`.
+/// * `#[unimock(test_helpers, )]`: Generate a `mod test_helpers` next to the trait, containing the stub factories
+///   `stub_<trait>_<method>_default()` and `stub_<trait>_<method>_returns(value)` for each non-generic method.
+///   Each factory returns a [Clause] that responds to every call to that method.
+///   `_default` is only generated for owned outputs, and `_returns` for all outputs except `&mut` references and `!`.
+///   A `_default` factory for an output that doesn't implement [Default] fails to compile when used, see [Test helpers](#test-helpers).
+///   The module is not gated on `cfg(test)`, it exists wherever the mock does. For use from integration tests,
+///   enable the mock with a feature of your choice, e.g. `#[cfg_attr(feature = "mocks", unimock(api=TraitMock, test_helpers))]`.
+///   Requires `api` to be set. Use `test_helpers=#ident` to choose another module name, e.g. when several traits share a module.
 pub use unimock_macros::unimock;
 
 ///
@@ -643,6 +848,24 @@ pub use unimock_macros::unimock;
 /// }
 /// ```
 ///
+/// # Argument count
+///
+/// A tuple pattern listing several arguments is checked against [MockFn::N_INPUTS] when the test is built.
+/// This catches patterns that would otherwise match the fields of a single tuple argument:
+///
+/// ```compile_fail
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn pair(&self, pair: (i32, i32));
+/// }
+///
+/// fn args(_: &dyn Fn(&mut unimock::private::Matching<Mock::pair>)) {}
+/// args(matching!(1, 2)); // this matcher expects a different number of arguments than MockFn::N_INPUTS
+/// ```
+///
+/// A pattern with a single argument, e.g. `matching!(_)`, may match the whole inputs tuple and is not checked.
+///
 /// # Auto-"coercions"
 ///
 /// Since the input expression being matched is generated by the macro,
@@ -721,14 +944,300 @@ pub use unimock_macros::unimock;
 /// assert_eq!("other", <Unimock as Trait>::func(&u, Data(vec![0])));
 /// ```
 ///
+/// # Matching `io::Error` kinds
+///
+/// [std::io::Error] does not implement `PartialEq`, so an [`io::Result`](std::io::Result) argument can't be matched using `eq!`.
+/// Enclosing an [ErrorKind](std::io::ErrorKind) within `io_error!(_)` instead matches any `Err` with that kind:
+///
+/// ```rust
+/// # use unimock::*;
+/// use std::io::{self, ErrorKind};
+///
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn func(&self, arg: io::Result<()>) -> &str;
+/// }
+///
+/// let u = Unimock::new((
+///     Mock::func
+///         .each_call(matching!(io_error!(ErrorKind::NotFound)))
+///         .returns("not found"),
+///     Mock::func
+///         .each_call(matching!(_))
+///         .returns("other")
+/// ));
+///
+/// assert_eq!("not found", <Unimock as Trait>::func(&u, Err(ErrorKind::NotFound.into())));
+/// assert_eq!("other", <Unimock as Trait>::func(&u, Ok(())));
+/// ```
+///
+/// # Matching JSON
+///
+/// With the `mock-serde` feature enabled, an argument holding JSON text can be matched structurally by enclosing a
+/// [`serde_json::json!`](https://docs.rs/serde_json/latest/serde_json/macro.json.html) literal within `json!(_)`,
+/// e.g. `matching!(json!({ "key": "value" }))`.
+/// The argument is parsed as JSON and compared to the literal, so whitespace and key order do not matter.
+/// Arguments that are not valid JSON do not match.
+/// This works for any argument type implementing `AsRef<[u8]>`, like `&str`, `String`, `Vec<u8>` and `bytes::Bytes`.
+///
+/// # Matching log messages
+///
+/// With the `mock-log-0-4` feature enabled, a `log::Record` argument can be matched by its formatted message
+/// by enclosing a substring within `msg_contains!(_)`, e.g. `matching!(msg_contains!("retrying"))`.
+/// See [mock::log_0_4] for how to capture log output with unimock.
+///
+/// # Matching membership in a collection
+///
+/// An argument can be checked against a collection computed at runtime, by enclosing the collection within `within!(_)`.
+/// The collection is cloned when the call pattern is registered, and must have a `contains` method accepting a reference to the argument,
+/// like `Vec`, arrays, `HashSet` and `BTreeSet` do:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn func(&self, id: u32) -> &str;
+/// }
+///
+/// let valid_ids: Vec<u32> = (1..=3).collect();
+///
+/// let u = Unimock::new((
+///     Mock::func
+///         .each_call(matching!(within!(valid_ids)))
+///         .returns("valid"),
+///     Mock::func
+///         .each_call(matching!(_))
+///         .returns("invalid"),
+/// ));
+///
+/// assert_eq!("valid", u.func(2));
+/// assert_eq!("invalid", u.func(4));
+/// ```
+///
+/// # Matching arguments by position
+///
+/// For functions with many arguments, writing a `_` for every ignored argument gets tedious.
+/// Instead, single arguments can be compared by their zero-based position using `arg[n] == value` or `arg[n] != value`.
+/// Several comparisons can be combined with `&&`, and all arguments not mentioned are ignored:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn func(&self, a: i32, b: &str, c: i32, d: i32) -> &str;
+/// }
+///
+/// let u = Unimock::new((
+///     Mock::func
+///         .each_call(matching!(arg[1] == "bar"))
+///         .returns("bar"),
+///     Mock::func
+///         .each_call(matching!(arg[0] == 1 && arg[3] != 0))
+///         .returns("other"),
+/// ));
+///
+/// assert_eq!("bar", u.func(0, "bar", 0, 0));
+/// assert_eq!("other", u.func(1, "foo", 0, 1));
+/// ```
+///
+/// This form only applies to functions with at least two arguments, and can't be mixed with patterns or guards.
+///
+/// # External matcher functions
+///
+/// Matching logic that is shared between tests can live in a regular function, referenced using `match_fn!(path)`.
+/// The function receives a reference to the inputs ([MockFn::Inputs]), i.e. the argument itself for single-argument functions
+/// and a tuple of all arguments otherwise, and returns whether the call matches:
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=Mock)]
+/// trait Trait {
+///     fn func(&self, a: i32, b: i32) -> &str;
+/// }
+///
+/// mod matchers {
+///     pub fn ascending(&(a, b): &(i32, i32)) -> bool {
+///         a < b
+///     }
+/// }
+///
+/// let u = Unimock::new((
+///     Mock::func
+///         .each_call(matching!(match_fn!(matchers::ascending)))
+///         .returns("ascending"),
+///     Mock::func.each_call(matching!(_, _)).returns("other"),
+/// ));
+///
+/// assert_eq!("ascending", u.func(1, 2));
+/// assert_eq!("other", u.func(2, 1));
+/// ```
+///
+/// `match_fn!` can't be combined with any other matching syntax.
+///
 pub use unimock_macros::matching;
 
-#[derive(Clone, Copy)]
-enum FallbackMode {
-    Error,
-    Unmock,
+/// Like [matching], with a description that is listed when no call pattern matches a call.
+///
+/// The first argument is the description, and the rest is the input of [matching].
+/// When a call matches none of the call patterns of its [MockFn], the error message lists the
+/// description of every call pattern built with this macro, together with the inputs of the call.
+///
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=PricesMock)]
+/// trait Prices {
+///     fn price(&self, item: &str, amount: u32) -> u32;
+/// }
+///
+/// let u = Unimock::new((
+///     PricesMock::price
+///         .each_call(matching_debug_hint!("apples in bulk", "apple", 100..))
+///         .returns(1_u32),
+///     PricesMock::price
+///         .each_call(matching_debug_hint!("single pears", "pear", 1))
+///         .returns(2_u32),
+/// ));
+///
+/// let error = u.try_eval::<PricesMock::price>(("apple", 1)).err().unwrap();
+/// assert!(error.to_string().contains("Tried 'apples in bulk' for args (\"apple\", 1)."));
+/// assert!(error.to_string().contains("Tried 'single pears' for args (\"apple\", 1)."));
+///
+/// assert_eq!(1, u.price("apple", 200));
+/// ```
+pub use unimock_macros::matching_debug_hint;
+
+/// Derive [MockFn] for a unit struct, mocking a trait method without annotating the trait with [unimock].
+///
+/// This is useful for traits that can not be annotated, e.g. traits defined in other crates.
+/// The method is described by the `#[mock_fn(..)]` attribute, which takes these options as string literals:
+///
+/// * `name`: The path of the method, e.g. `"Trait::method"`. A longer path like `"other_crate::Trait::method"`
+///   also names the module of the trait in error messages, which otherwise is the module of the struct.
+/// * `inputs`: The tuple of input types, excluding the receiver, e.g. `"(i32, String)"`. Defaults to no inputs.
+/// * `output`: The output type, e.g. `"bool"`. Defaults to `()`.
+///
+/// The method is assumed to take `&self`.
+/// Besides the [MockFn] implementation, the struct gets a `call` function that evaluates a call against the clauses of a [Unimock].
+/// An implementation of the trait for a type wrapping a [Unimock] calls it to forward each call:
+///
+/// ```rust
+/// use unimock::*;
+///
+/// // A trait that can't be annotated with `#[unimock]`
+/// trait Greeter {
+///     fn greet(&self, name: &str, times: usize) -> String;
+/// }
+///
+/// #[derive(MockFn)]
+/// #[mock_fn(name = "Greeter::greet", inputs = "(&str, usize)", output = "String")]
+/// struct Greet;
+///
+/// struct MockGreeter(Unimock);
+///
+/// impl Greeter for MockGreeter {
+///     fn greet(&self, name: &str, times: usize) -> String {
+///         Greet::call(&self.0, name, times)
+///     }
+/// }
+///
+/// let greeter = MockGreeter(Unimock::new(
+///     Greet
+///         .next_call(matching!("Bob", 2))
+///         .answers(&|_, name, times| format!("Hello {name}! ").repeat(times)),
+/// ));
+///
+/// assert_eq!("Hello Bob! Hello Bob! ", greeter.greet("Bob", 2));
+/// ```
+pub use unimock_macros::MockFn;
+
+/// Assert that the call patterns of one [MockFn] are satisfied at this point of a test.
+///
+/// `assert_satisfied!(unimock, FooMock::fetch)` is a shorthand for [`unimock.assert_satisfied::<FooMock::fetch>()`](Unimock::assert_satisfied).
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=FooMock)]
+/// trait Foo {
+///     fn fetch(&self) -> i32;
+///     fn store(&self, value: i32);
+/// }
+///
+/// let u = Unimock::new((
+///     FooMock::fetch.each_call(matching!()).returns(1).n_times(2),
+///     FooMock::store.each_call(matching!(_)).returns(()).once(),
+/// ));
+///
+/// u.fetch();
+/// u.fetch();
+/// assert_satisfied!(u, FooMock::fetch);
+///
+/// u.store(2);
+/// ```
+#[macro_export]
+macro_rules! assert_satisfied {
+    ($unimock:expr, $mock_fn:ty $(,)?) => {
+        $unimock.assert_satisfied::<$mock_fn>()
+    };
+}
+
+/// Implement [BoxFrom](output::BoxFrom) for a boxed trait object, so it can be used with `returns_boxed`.
+///
+/// The input lists the bounds of the trait object, e.g. `impl_box_from!(Handler + Send)` for `Box<dyn Handler + Send>`.
+/// Any value implementing those bounds can then be returned for that box.
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// pub trait Handler {
+///     fn handle(&self) -> i32;
+/// }
+///
+/// impl_box_from!(Handler);
+///
+/// #[derive(Clone)]
+/// struct Fixed(i32);
+///
+/// impl Handler for Fixed {
+///     fn handle(&self) -> i32 {
+///         self.0
+///     }
+/// }
+///
+/// #[unimock(api=FactoryMock)]
+/// trait Factory {
+///     fn make(&self) -> Box<dyn Handler>;
+/// }
+///
+/// let u = Unimock::new(FactoryMock::make.each_call(matching!()).returns_boxed(Fixed(42)));
+///
+/// assert_eq!(42, u.make().handle());
+/// assert_eq!(42, u.make().handle());
+/// ```
+#[macro_export]
+macro_rules! impl_box_from {
+    ($($bounds:tt)+) => {
+        impl<T: $($bounds)+ + 'static> $crate::output::BoxFrom<T> for $crate::alloc::Box<dyn $($bounds)+> {
+            fn box_from(value: T) -> Self {
+                $crate::alloc::Box::new(value)
+            }
+        }
+    };
 }
 
+pub use build::EachExt;
+pub use error::{AssembleError, MockError, MockErrorKind};
+pub use fn_mock::FnMock;
+pub use fn_mocker::{FallbackMode, PatternMatchMode};
+pub use recording::{RecordedCall, RecordingHandle};
+#[cfg(all(feature = "serde", feature = "std"))]
+pub use replay::{SerializedCall, SerializedClause};
+pub use report::{CallPatternReport, MockFnReport, VerificationReport};
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+pub use state_map::{state_map, StateMap};
+#[cfg(feature = "std")]
+pub use verify_handle::VerifyHandle;
+
 /// A type whose purpose is to provide mocked behaviour for the traits that it implements.
 ///
 /// All traits implemented by Unimock can be considered mock implementations, except _marker traits_, [Clone] and [Drop].
@@ -776,6 +1285,10 @@ pub struct Unimock {
     // E.g. a failing `assert!` failing will still break the test, with bad debug output.
     #[cfg(not(feature = "std"))]
     panicked: private::MutexIsh<bool>,
+
+    // Must be declared after `shared_state`, see `NotifyOnDrop`.
+    #[cfg(feature = "std")]
+    drop_notifier: Option<verify_handle::NotifyOnDrop>,
 }
 
 impl Unimock {
@@ -797,10 +1310,10 @@ impl Unimock {
     /// ```
     #[track_caller]
     pub fn new(setup: impl Clause) -> Self {
-        Self::from_assembler(
-            assemble::MockAssembler::try_from_clause(setup),
-            FallbackMode::Error,
-        )
+        Self::builder()
+            .fallback(FallbackMode::Error)
+            .clause(setup)
+            .build()
     }
 
     /// Construct a unimock instance using _partial mocking_.
@@ -831,39 +1344,344 @@ impl Unimock {
     /// ```
     #[track_caller]
     pub fn new_partial(setup: impl Clause) -> Self {
-        Self::from_assembler(
-            assemble::MockAssembler::try_from_clause(setup),
-            FallbackMode::Unmock,
-        )
+        Self::builder()
+            .fallback(FallbackMode::Unmock)
+            .clause(setup)
+            .build()
     }
 
-    /// Turn off auto-verification within [Drop::drop].
+    /// Construct a partially mocked unimock instance, where the [MockFn]s excluded in `exclusions` must be mocked explicitly.
     ///
-    /// The current use case for this is `[no_std]`. In `[no_std]` there is no thread API,
-    /// and therefore no way to check if the current thread is already panicking.
+    /// This is a spy with real implementations everywhere except the excluded functions.
+    /// An unmatched call to an excluded function fails instead of invoking its unmocked implementation.
     ///
-    /// Leaving verify-in-drop on in such circumstances (in the context of panicking unit tests)
-    /// easily leads to double panics, which are quite hard to debug.
-    pub fn no_verify_in_drop(mut self) -> Self {
-        if !self.original_instance {
-            panic!("Called no_verify_on_drop() on a cloned instance. Configure the original instance instead.");
-        }
-
-        self.verify_in_drop = false;
-        self
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=HttpMock, unmock_with=[real_send])]
+    /// trait Http {
+    ///     fn send(&self, url: &str) -> u16;
+    /// }
+    ///
+    /// fn real_send(_: &impl std::any::Any, url: &str) -> u16 {
+    ///     panic!("no network in tests: {url}")
+    /// }
+    ///
+    /// let u = Unimock::spy_except(
+    ///     HttpMock::send.must_be_mocked(),
+    ///     HttpMock::send.each_call(matching!("/health")).returns(200_u16),
+    /// );
+    /// assert_eq!(200, u.send("/health"));
+    /// ```
+    #[track_caller]
+    pub fn spy_except(exclusions: impl Clause, setup: impl Clause) -> Self {
+        Self::new_partial((exclusions, setup))
     }
 
-    /// Explicitly verify this unimock instance.
+    /// Construct a partially mocked unimock instance that records every call made to it.
     ///
-    /// There is no need to do this explicitly unless [Self::no_verify_in_drop] has been called.
-    pub fn verify(mut self) {
-        if !self.original_instance {
-            panic!("Called verify() on a cloned instance. Verify the original instance instead.");
-        }
-
+    /// Calls are recorded regardless of whether they get mocked or unmocked responses,
+    /// and are available through the returned [RecordingHandle].
+    /// This is useful for finding out what a piece of code actually calls, e.g. as a first step of writing a fully mocked test.
+    ///
+    /// Only the calls are recorded, not the values returned by the real implementations.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock, unmock_with=[real_foo])]
+    /// trait Trait {
+    ///     fn foo(&self, arg: i32) -> i32;
+    /// }
+    ///
+    /// fn real_foo(_: &impl std::any::Any, arg: i32) -> i32 {
+    ///     arg * 2
+    /// }
+    ///
+    /// let (u, recording) = Unimock::spy_record(());
+    /// assert_eq!(4, u.foo(2));
+    /// assert_eq!(6, u.foo(3));
+    ///
+    /// let calls = recording.calls();
+    /// assert_eq!(2, calls.len());
+    /// assert!(calls[0].ends_with("Trait::foo(2)"));
+    /// assert!(calls[1].ends_with("Trait::foo(3)"));
+    /// ```
+    #[track_caller]
+    pub fn spy_record(setup: impl Clause) -> (Self, RecordingHandle) {
+        let mut unimock = Self::new_partial(setup);
+        let call_log: recording::CallLog = alloc::Arc::new(private::MutexIsh::new(alloc::vec![]));
+        alloc::Arc::get_mut(&mut unimock.shared_state)
+            .expect("a new instance has no clones")
+            .call_log = Some(call_log.clone());
+
+        (unimock, RecordingHandle::new(call_log))
+    }
+
+    /// Construct a unimock instance replaying the calls of a JSON fixture file.
+    ///
+    /// The file contains a list of [SerializedClause]s.
+    /// The calls of each clause are expected in order: a call must have the same debug representation of its inputs as the next replayed call,
+    /// and returns the deserialized output of that call.
+    /// All replayed calls must be made before the instance is dropped.
+    ///
+    /// Only methods with an owned output implementing `serde::de::DeserializeOwned` can be replayed.
+    ///
+    /// # Errors
+    /// Fails if the file can't be read, or does not contain a list of [SerializedClause]s.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=PricesMock)]
+    /// trait Prices {
+    ///     fn price(&self, item: &str) -> Option<u32>;
+    /// }
+    ///
+    /// let path = std::env::temp_dir().join("unimock-doc-prices.json");
+    /// std::fs::write(&path, r#"[{
+    ///     "name": "rust_out::Prices::price",
+    ///     "calls": [
+    ///         { "inputs": ["\"apple\""], "output": 3 },
+    ///         { "inputs": ["\"pear\""], "output": null }
+    ///     ]
+    /// }]"#).unwrap();
+    ///
+    /// let u = Unimock::new_from_file(&path).unwrap();
+    /// assert_eq!(Some(3), u.price("apple"));
+    /// assert_eq!(None, u.price("pear"));
+    /// ```
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[track_caller]
+    pub fn new_from_file(path: &std::path::Path) -> std::io::Result<Self> {
+        let clauses: alloc::Vec<SerializedClause> =
+            serde_json::from_slice(&std::fs::read(path)?)
+                .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+
+        let mut unimock = Self::new(());
+        alloc::Arc::get_mut(&mut unimock.shared_state)
+            .expect("a new instance has no clones")
+            .replay = Some(replay::Replay::new(clauses));
+
+        Ok(unimock)
+    }
+
+    /// Construct a unimock instance that only counts calls to the listed [MockFn]s.
+    ///
+    /// There are no call patterns, and all calls are forwarded to the unmocked implementations.
+    /// The number of calls to each listed function can be read back using [Unimock::call_count].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// use core::any::TypeId;
+    ///
+    /// #[unimock(api=TraitMock, unmock_with=[real_foo])]
+    /// trait Trait {
+    ///     fn foo(&self) -> &'static str;
+    /// }
+    ///
+    /// fn real_foo(_: &impl std::any::Any) -> &'static str {
+    ///     "real thing"
+    /// }
+    ///
+    /// let u = Unimock::new_counting_only(&[TypeId::of::<TraitMock::foo>()]);
+    ///
+    /// assert_eq!("real thing", u.foo());
+    /// assert_eq!("real thing", u.foo());
+    /// assert_eq!(2, u.call_count::<TraitMock::foo>());
+    /// ```
+    #[track_caller]
+    pub fn new_counting_only(fn_list: &[TypeId]) -> Self {
+        Self::builder()
+            .fallback(FallbackMode::Unmock)
+            .count_calls(fn_list.iter().copied())
+            .build()
+    }
+
+    /// Start configuring a unimock instance using a [UnimockBuilder](build::UnimockBuilder).
+    ///
+    /// The builder defaults to [FallbackMode::Error], so `Unimock::builder().clause(setup).build()` is equivalent to `Unimock::new(setup)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock, unmock_with=[real_foo, _])]
+    /// trait Trait {
+    ///     fn foo(&self) -> &'static str;
+    ///     fn bar(&self) -> &'static str;
+    /// }
+    ///
+    /// fn real_foo(_: &impl std::any::Any) -> &'static str {
+    ///     "real thing"
+    /// }
+    ///
+    /// let u = Unimock::builder()
+    ///     .fallback(FallbackMode::Unmock)
+    ///     .clause(TraitMock::bar.some_call(matching!()).returns("mocked"))
+    ///     .build();
+    ///
+    /// assert_eq!("real thing", u.foo());
+    /// assert_eq!("mocked", u.bar());
+    /// ```
+    pub fn builder() -> build::UnimockBuilder {
+        build::UnimockBuilder::new()
+    }
+
+    /// Construct a unimock instance that gets verified through a [VerifyHandle] instead of in [Drop::drop].
+    ///
+    /// This is useful when clones get moved into spawned tasks or threads, which may still be running when the test body ends.
+    /// The handle waits until the returned instance and all of its clones have been dropped, and then verifies all calls made through them.
+    /// Neither the returned instance nor its clones verify anything when dropped.
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn foo(&self) -> i32;
+    /// }
+    ///
+    /// let (u, handle) = Unimock::new_with_verify_handle(
+    ///     TraitMock::foo.each_call(matching!()).returns(42).n_times(2),
+    /// );
+    ///
+    /// let clone = u.clone();
+    /// std::thread::spawn(move || assert_eq!(42, clone.foo()));
+    /// std::thread::spawn(move || assert_eq!(42, u.foo()));
+    ///
+    /// handle.join_blocking().unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_with_verify_handle(setup: impl Clause) -> (Self, VerifyHandle) {
+        let mut unimock = Self::new(setup);
+        let notifier = alloc::Arc::new(verify_handle::DropNotifier::default());
+        unimock.verify_in_drop = false;
+        unimock.drop_notifier = Some(verify_handle::NotifyOnDrop(notifier.clone()));
+
+        let handle = VerifyHandle::new(unimock.shared_state.clone(), notifier);
+        (unimock, handle)
+    }
+
+    /// Create an [Iterator] over items of type `T`, whose behaviour is mocked through [IteratorMock](mock::core::iter::IteratorMock).
+    ///
+    /// The iterator holds a clone of this instance.
+    #[cfg(feature = "mock-core")]
+    pub fn as_iterator<T>(&self) -> mock::core::iter::MockIterator<T> {
+        mock::core::iter::MockIterator::new(self.clone())
+    }
+
+    /// Create a [Stream](futures_core_0_3::Stream) of items of type `T`, whose behaviour is mocked through [StreamMock](mock::futures_0_3::stream::StreamMock).
+    ///
+    /// The stream holds a clone of this instance.
+    #[cfg(feature = "mock-futures-core-0-3")]
+    pub fn as_stream<T>(&self) -> mock::futures_0_3::stream::MockStream<T> {
+        mock::futures_0_3::stream::MockStream::new(self.clone())
+    }
+
+    /// Create a [Service](tower_service_0_3::Service) taking requests of type `Req`, whose behaviour is mocked through [ServiceMock](mock::tower_service_0_3::ServiceMock).
+    ///
+    /// The service holds a clone of this instance.
+    #[cfg(feature = "mock-tower-service-0-3")]
+    pub fn as_service<Req, Res, Err>(&self) -> mock::tower_service_0_3::MockService<Req, Res, Err> {
+        mock::tower_service_0_3::MockService::new(self.clone())
+    }
+
+    /// Turn off auto-verification within [Drop::drop].
+    ///
+    /// The current use case for this is `[no_std]`. In `[no_std]` there is no thread API,
+    /// and therefore no way to check if the current thread is already panicking.
+    ///
+    /// Leaving verify-in-drop on in such circumstances (in the context of panicking unit tests)
+    /// easily leads to double panics, which are quite hard to debug.
+    pub fn no_verify_in_drop(mut self) -> Self {
+        if !self.original_instance {
+            panic!("Called no_verify_on_drop() on a cloned instance. Configure the original instance instead.");
+        }
+
+        self.verify_in_drop = false;
+        self
+    }
+
+    /// Disarm all verification that normally happens when instances get dropped.
+    ///
+    /// This affects this instance and all of its clones, including clones made later.
+    /// After disarming, dropping the original instance does not check for uncalled mocks,
+    /// for clones still being alive, or for being dropped on another thread.
+    /// Dropping a clone never verifies anything in the first place.
+    ///
+    /// This is an escape hatch for situations where drop-time panics are pure noise,
+    /// like fuzz targets or tests that intentionally leak a clone into a background task.
+    /// Don't use it in regular tests, as they will silently stop detecting missing calls.
+    ///
+    /// [Self::verify] can still be called explicitly on the original instance.
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn foo(&self);
+    /// }
+    ///
+    /// let u = Unimock::new(TraitMock::foo.next_call(matching!()).returns(()));
+    /// let leaked = u.clone();
+    /// u.disarm();
+    ///
+    /// // Neither the missing call nor the living clone cause a panic:
+    /// drop(u);
+    /// # drop(leaked);
+    /// ```
+    pub fn disarm(&self) {
+        self.shared_state.disarm();
+    }
+
+    /// Explicitly verify this unimock instance.
+    ///
+    /// There is no need to do this explicitly unless [Self::no_verify_in_drop] or [Self::disarm] has been called.
+    pub fn verify(mut self) {
+        if !self.original_instance {
+            panic!("Called verify() on a cloned instance. Verify the original instance instead.");
+        }
+
         teardown::teardown_panic(&mut self);
     }
 
+    /// Verify this instance, returning the errors instead of panicking.
+    ///
+    /// The returned errors are the ones that dropping the original instance would otherwise panic with.
+    /// Afterwards the instance counts as verified, so dropping it does not verify again.
+    /// Like dropping, draining the errors of a clone verifies nothing.
+    ///
+    /// This is meant for test harnesses that catch panics and report all failures at once.
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn foo(&self);
+    /// }
+    ///
+    /// let mut u = Unimock::new(TraitMock::foo.next_call(matching!()).returns(()));
+    ///
+    /// let errors = u.drain_errors();
+    /// assert!(errors
+    ///     .iter()
+    ///     .any(|error| error.kind() == MockErrorKind::MockNeverCalled));
+    ///
+    /// // Does not panic:
+    /// drop(u);
+    /// ```
+    ///
+    /// # Panics
+    /// Like [Self::verify], this panics if the original instance still has living clones,
+    /// or if it is verified on another thread than the one it was created on.
+    #[track_caller]
+    pub fn drain_errors(&mut self) -> alloc::Vec<MockError> {
+        match teardown::teardown(self) {
+            Ok(()) => alloc::vec![],
+            Err(failures) => failures.into_iter().map(MockError::new).collect(),
+        }
+    }
+
     /// Convert the given value into a reference.
     ///
     /// This can be useful when returning references from `answers` functions.
@@ -877,6 +1695,346 @@ impl Unimock {
     pub fn make_mut<T: Send + Sync + 'static>(&mut self, value: T) -> &mut T {
         self.value_chain.push_mut(value)
     }
+
+    /// The number of calls made to `F` so far, by this instance and all its clones.
+    ///
+    /// # Panics
+    /// Panics if calls to `F` are not being counted.
+    /// Counting is enabled through [Unimock::new_counting_only] or [UnimockBuilder::count_calls](build::UnimockBuilder::count_calls).
+    #[track_caller]
+    pub fn call_count<F: MockFn>(&self) -> usize {
+        match self.shared_state.call_count(TypeId::of::<F>()) {
+            Some(count) => count,
+            None => panic!("Calls to {} are not being counted.", F::info().path),
+        }
+    }
+
+    /// Make every call pattern whose expectation is already met fail on any further call, instead of when the instance is verified.
+    ///
+    /// Useful for catching extra calls made after the tested operation as soon as they happen.
+    /// Call patterns that still expect more calls are left untouched, and so are default stubs.
+    /// This applies to this instance and all its clones.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn ping(&self) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(TraitMock::ping.each_call(matching!()).returns(1).at_least_times(1));
+    ///
+    /// assert_eq!(1, u.ping());
+    /// u.expect_no_more_calls();
+    ///
+    /// u.ping(); // panics: No more calls expected
+    /// ```
+    pub fn expect_no_more_calls(&self) {
+        self.shared_state.close_satisfied_call_patterns();
+    }
+
+    /// Assert that the call patterns of `F` have been called at least as many times as they expect, so far.
+    ///
+    /// Other MockFns are left untouched, and `F` is still verified when the instance is dropped.
+    /// A MockFn that has not been called yet is only unsatisfied when one of its call patterns expects calls.
+    /// [assert_satisfied!] is a shorthand for this method.
+    ///
+    /// # Panics
+    /// Panics with the usual verification errors of `F` if any of its call patterns are not satisfied.
+    #[track_caller]
+    pub fn assert_satisfied<F: MockFn>(&self) {
+        let errors = self.shared_state.verify_call_counts(TypeId::of::<F>());
+        if !errors.is_empty() {
+            let error_strings = errors
+                .iter()
+                .map(<error::MockFailure as alloc::ToString>::to_string)
+                .collect::<alloc::Vec<_>>();
+            #[cfg(not(feature = "std"))]
+            self.panicked.locked(|panicked| {
+                *panicked = true;
+            });
+            panic!("{}", error_strings.join("\n"));
+        }
+    }
+
+    /// Store a value in this instance, to be shared with unmocked code.
+    ///
+    /// There can be one stored value per type. Storing another value of the same type replaces the previous one.
+    /// The value is visible to all clones of this instance, through [Unimock::get_value] or [AsValueStore].
+    ///
+    /// # Panics
+    /// Panics if this instance has been cloned. Values must be stored before cloning.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// use std::sync::Mutex;
+    ///
+    /// #[unimock(api=LogMock, unmock_with=[log])]
+    /// trait Log {
+    ///     fn log(&self, msg: &'static str);
+    /// }
+    ///
+    /// fn log(store: &impl AsValueStore, msg: &'static str) {
+    ///     store.get_value::<Mutex<Vec<&str>>>().unwrap().lock().unwrap().push(msg);
+    /// }
+    ///
+    /// let u = Unimock::new_partial(()).with_value(Mutex::new(Vec::<&str>::new()));
+    /// u.log("hello");
+    ///
+    /// assert_eq!(vec!["hello"], *u.get_value::<Mutex<Vec<&str>>>().unwrap().lock().unwrap());
+    /// ```
+    #[track_caller]
+    pub fn with_value<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        match alloc::Arc::get_mut(&mut self.shared_state) {
+            Some(shared_state) => shared_state.insert_value(value),
+            None => panic!(
+                "Called with_value() on a shared instance. Store values before cloning the instance."
+            ),
+        }
+        self
+    }
+
+    /// Get a reference to a value previously stored with [Unimock::with_value].
+    pub fn get_value<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.shared_state.get_value()
+    }
+
+    /// Replace the setup of `F` with the one in `clause`, e.g. between the phases of a multi-phase test.
+    ///
+    /// The call patterns previously set up for `F` are verified and then discarded, including their call counts.
+    /// Verification failures of the previous setup are reported when this instance is verified.
+    /// Passing a clause that sets up nothing removes the setup of `F` altogether.
+    /// Ordered call patterns in `clause` continue from the current position in the call order.
+    ///
+    /// # Restriction
+    /// The setup can only be replaced while this is the only handle to the mocked state.
+    /// Outputs of earlier calls may still borrow from the setup being replaced, so it can't be swapped out under a lock instead.
+    /// Every clone of this instance must be dropped first,
+    /// including the ones held by the system under test, a [VerifyHandle] or a delegating [Unimock] target.
+    /// A test with several phases has to hand a fresh clone to the system under test for each phase.
+    ///
+    /// # Errors
+    /// Returns an error if this instance has been cloned and the clones are still alive,
+    /// or if `clause` is invalid or sets up any other [MockFn] than `F`.
+    /// The setup of `F` is left unchanged in that case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self) -> i32;
+    /// }
+    ///
+    /// let mut u = Unimock::new(FooMock::foo.next_call(matching!()).returns(1));
+    /// assert_eq!(1, u.foo());
+    ///
+    /// u.replace::<FooMock::foo>(FooMock::foo.next_call(matching!()).returns(2))
+    ///     .unwrap();
+    /// assert_eq!(2, u.foo());
+    ///
+    /// let clone = u.clone();
+    /// assert!(u.replace::<FooMock::foo>(()).is_err());
+    /// # drop(clone);
+    /// # u.replace::<FooMock::foo>(FooMock::foo.next_call(matching!()).returns(3)).unwrap();
+    /// # assert_eq!(3, u.foo());
+    /// ```
+    pub fn replace<F: MockFn>(
+        &mut self,
+        clause: impl Clause,
+    ) -> Result<&mut Self, error::AssembleError> {
+        self.replace_fn_mocker::<F>(clause, true)?;
+        Ok(self)
+    }
+
+    /// Like [Unimock::replace], but without verifying the previous setup of `F`.
+    pub fn replace_unchecked<F: MockFn>(
+        &mut self,
+        clause: impl Clause,
+    ) -> Result<&mut Self, error::AssembleError> {
+        self.replace_fn_mocker::<F>(clause, false)?;
+        Ok(self)
+    }
+
+    /// Add call patterns for `F` to an already constructed instance.
+    ///
+    /// This is like passing `F.stub(setup)` to the constructor, for adding setup after the fact.
+    /// The new call patterns are tried after any existing ones for `F`.
+    ///
+    /// # Panics
+    /// Panics if `setup` sets up no call patterns, if `F` has already been set up with [MockFn::next_call],
+    /// or if this instance has been cloned and the clones are still alive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self, arg: i32) -> i32;
+    /// }
+    ///
+    /// #[unimock(api=BarMock)]
+    /// trait Bar {
+    ///     fn bar(&self) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(FooMock::foo.each_call(matching!(1)).returns(10))
+    ///     .also::<FooMock::foo>(|each| {
+    ///         each.call(matching!(_)).returns(0);
+    ///     })
+    ///     .also::<BarMock::bar>(|each| {
+    ///         each.call(matching!()).returns(42);
+    ///     });
+    ///
+    /// assert_eq!(10, u.foo(1));
+    /// assert_eq!(0, u.foo(2));
+    /// assert_eq!(42, u.bar());
+    /// ```
+    #[track_caller]
+    pub fn also<F: MockFn>(mut self, setup: impl FnOnce(&mut build::Each<F>)) -> Self {
+        let path = F::info().path;
+
+        let Some(shared_state) = alloc::Arc::get_mut(&mut self.shared_state) else {
+            self.disarm();
+            panic!("Called also() on a shared instance. Drop all clones before adding setup for {path}.");
+        };
+
+        let mut assembler = assemble::MockAssembler::new()
+            .starting_at_call_index(shared_state.ordered_call_index());
+        let result = build::Each::from_fn(setup)
+            .deconstruct(&mut assembler)
+            .and_then(|()| shared_state.extend_fn_mocker(TypeId::of::<F>(), assembler.finish()));
+
+        if let Err(error) = result {
+            // Without std, the drop of `self` can't tell that it is caused by this panic
+            self.disarm();
+            panic!("{error}");
+        }
+
+        self
+    }
+
+    /// Evaluate a call to `F` with the given inputs, returning an error instead of panicking.
+    ///
+    /// This is the non-panicking counterpart of [macro_api::eval], useful for testing mock setups themselves.
+    /// A returned error is not recorded in the instance, so it doesn't cause a panic when the instance is verified.
+    /// The call is still counted.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::macro_api::Eval;
+    ///
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self, input: i32) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(FooMock::foo.each_call(matching!(1)).returns(42));
+    ///
+    /// assert!(matches!(u.try_eval::<FooMock::foo>(1), Ok(Eval::Return(42))));
+    ///
+    /// let error = u.try_eval::<FooMock::foo>(2).err().unwrap();
+    /// assert_eq!(MockErrorKind::NoMatchingCallPatterns, error.kind());
+    /// assert_eq!(line!() - 2, error.location().unwrap().line());
+    ///
+    /// assert_eq!(42, u.foo(1));
+    /// ```
+    #[track_caller]
+    pub fn try_eval<'u, 'i, F>(
+        &'u self,
+        inputs: F::Inputs<'i>,
+    ) -> Result<macro_api::Eval<'u, 'i, F>, MockError>
+    where
+        F: MockFn + 'static,
+    {
+        match eval::eval(self, inputs) {
+            Ok(eval) => Ok(eval),
+            Err(failure) => Err(MockError::at_caller(failure)),
+        }
+    }
+
+    /// A compact, [Debug](core::fmt::Debug)-printable summary of this instance's configuration.
+    ///
+    /// For each mocked function, it lists the path, the number of call patterns, the pattern match mode
+    /// and the number of calls matched by each pattern so far. Inputs and outputs are never printed,
+    /// so the summary stays bounded in size.
+    ///
+    /// This is what `Unimock`'s own `Debug` implementation prints,
+    /// except when the `mock-core` feature is enabled, in which case `Debug` is a mockable trait instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(FooMock::foo.each_call(matching!()).returns(42));
+    /// assert_eq!(42, u.foo());
+    ///
+    /// let summary = format!("{:?}", u.debug_summary());
+    /// assert!(summary.contains("Foo::foo, patterns: 1, mode: InAnyOrder, calls: [1]"));
+    /// ```
+    pub fn debug_summary(&self) -> impl core::fmt::Debug + '_ {
+        UnimockSummary(self)
+    }
+
+    /// Create a structured report of how well the expectations of this instance are met so far.
+    ///
+    /// Unlike [Self::verify], this neither panics nor marks the instance as verified,
+    /// so it can be called at any time, also on clones.
+    /// With the `serde` feature, the report can be serialized, e.g. for collecting results across test suites.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock)]
+    /// trait Foo {
+    ///     fn foo(&self) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new(FooMock::foo.next_call(matching!()).returns(42));
+    /// assert!(!u.verification_report().satisfied);
+    ///
+    /// assert_eq!(42, u.foo());
+    /// let report = u.verification_report();
+    /// assert!(report.satisfied);
+    /// assert_eq!(1, report.mock_fns[0].call_patterns[0].actual_calls);
+    /// ```
+    pub fn verification_report(&self) -> VerificationReport {
+        report::verification_report(&self.shared_state)
+    }
+}
+
+struct UnimockSummary<'u>(&'u Unimock);
+
+impl core::fmt::Debug for UnimockSummary<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let shared_state = &self.0.shared_state;
+
+        f.debug_struct("Unimock")
+            .field("fallback_mode", &shared_state.fallback_mode)
+            .field(
+                "fns",
+                &shared_state
+                    .fn_mockers
+                    .iter()
+                    .map(|fn_mocker| fn_mocker.debug_summary())
+                    .collect::<alloc::Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "mock-core"))]
+impl core::fmt::Debug for Unimock {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        UnimockSummary(self).fmt(f)
+    }
 }
 
 #[cfg(feature = "fragile")]
@@ -899,18 +2057,21 @@ impl Unimock {
 }
 
 impl Unimock {
-    #[track_caller]
-    fn from_assembler(
-        assembler_result: Result<MockAssembler, alloc::String>,
+    fn from_assembly(
+        assembly: assemble::Assembly,
         fallback_mode: FallbackMode,
+        counted_fns: alloc::Vec<TypeId>,
+        unmock_recursion_limit: usize,
+        max_debug_len: usize,
     ) -> Self {
-        let fn_mockers = match assembler_result {
-            Ok(assembler) => assembler.finish(),
-            Err(error) => panic!("{error}"),
-        };
-
         Self {
-            shared_state: alloc::Arc::new(state::SharedState::new(fn_mockers, fallback_mode)),
+            shared_state: alloc::Arc::new(state::SharedState::new(
+                assembly,
+                fallback_mode,
+                counted_fns,
+                unmock_recursion_limit,
+                max_debug_len,
+            )),
             value_chain: Default::default(),
             default_impl_delegator_cell: Default::default(),
             original_instance: true,
@@ -918,18 +2079,77 @@ impl Unimock {
             verify_in_drop: true,
             #[cfg(not(feature = "std"))]
             panicked: private::MutexIsh::new(false),
+            #[cfg(feature = "std")]
+            drop_notifier: None,
+        }
+    }
+
+    fn replace_fn_mocker<F: MockFn>(
+        &mut self,
+        clause: impl Clause,
+        verify: bool,
+    ) -> Result<(), error::AssembleError> {
+        let type_id = TypeId::of::<F>();
+        let path = F::info().path;
+
+        // the default impl delegator holds a clone, which is recreated on demand.
+        drop(self.default_impl_delegator_cell.take());
+
+        let Some(shared_state) = alloc::Arc::get_mut(&mut self.shared_state) else {
+            return Err(error::AssembleError::ReplaceInSharedInstance {
+                path: alloc::ToString::to_string(&path),
+            });
+        };
+
+        let mut assembler = assemble::MockAssembler::new()
+            .starting_at_call_index(shared_state.ordered_call_index());
+        clause.deconstruct(&mut assembler)?;
+        let assembly = assembler.finish();
+
+        if assembly
+            .fn_mockers
+            .keys()
+            .chain(assembly.fallback_modes.keys())
+            .chain(assembly.max_debug_lens.keys())
+            .chain(assembly.input_formats.keys())
+            .any(|other_type_id| *other_type_id != type_id)
+        {
+            return Err(error::AssembleError::ReplaceWithOtherMockFn {
+                path: alloc::ToString::to_string(&path),
+            });
         }
+
+        if verify {
+            if let Some(fn_mocker) = shared_state.fn_mocker(type_id) {
+                let mut errors = alloc::Vec::new();
+                fn_mocker.verify(&mut errors);
+                shared_state
+                    .panic_reasons
+                    .locked(move |reasons| reasons.extend(errors));
+            }
+        }
+
+        shared_state.replace_fn_mocker(type_id, assembly);
+        Ok(())
     }
 
     #[track_caller]
-    fn handle_error<T>(&self, result: Result<T, error::MockError>) -> T {
+    fn handle_error<T>(&self, result: Result<T, error::MockFailure>) -> T {
         match result {
             Ok(value) => value,
             Err(error) => self.induce_panic(error),
         }
     }
 
-    fn induce_panic(&self, error: error::MockError) -> ! {
+    /// Record a failure to be reported when this instance is verified, without panicking.
+    fn record_failure(&self, error: error::MockFailure) {
+        self.shared_state.panic_reasons.locked(move |reasons| {
+            reasons.push(error);
+        });
+    }
+
+    #[track_caller]
+    fn induce_panic(&self, error: error::MockFailure) -> ! {
         #[cfg(not(feature = "std"))]
         {
             self.panicked.locked(|panicked| {
@@ -939,9 +2159,13 @@ impl Unimock {
 
         let msg = alloc::format!("{error}");
 
-        self.shared_state.panic_reasons.locked(move |reasons| {
-            reasons.push(error);
-        });
+        // An explicit panic is configured behaviour rather than a mock failure.
+        // Not recording it keeps the instance verifiable if the panic gets caught.
+        if !matches!(error, error::MockFailure::ExplicitPanic { .. }) {
+            self.shared_state.panic_reasons.locked(move |reasons| {
+                reasons.push(error);
+            });
+        }
 
         panic!("{msg}")
     }
@@ -958,6 +2182,8 @@ impl Clone for Unimock {
             verify_in_drop: self.verify_in_drop,
             #[cfg(not(feature = "std"))]
             panicked: private::MutexIsh::new(false),
+            #[cfg(feature = "std")]
+            drop_notifier: self.drop_notifier.clone(),
         }
     }
 }
@@ -988,7 +2214,7 @@ impl Drop for Unimock {
             return;
         }
 
-        if self.verify_in_drop {
+        if self.verify_in_drop && !self.shared_state.is_disarmed() {
             teardown::teardown_panic(self);
         }
     }
@@ -1012,6 +2238,7 @@ impl Drop for Unimock {
 /// The `mock-std` feature also enables mocking of this trait through [mock::std::process::TerminationMock].
 /// This trait mock is partial by default: Unless explicitly mocked, it behaves as specified above.
 #[cfg(feature = "std")]
+#[allow(clippy::test_attr_in_doctest)]
 impl std::process::Termination for Unimock {
     #[cfg(feature = "mock-std")]
     fn report(mut self) -> std::process::ExitCode {
@@ -1078,6 +2305,12 @@ pub trait MockFn: Sized + 'static {
     /// The function type used for function application on a call pattern.
     type AnswerFn: ?Sized + Send + Sync;
 
+    /// The number of inputs to the mocked function, not counting the receiver, if known.
+    ///
+    /// [`matching!`](crate::matching) checks the arity of its tuple patterns against this number when the test is compiled.
+    /// [macro@unimock] always sets it, the default `None` disables the check.
+    const N_INPUTS: Option<usize> = None;
+
     /// Static information about the mocked method
     fn info() -> MockFnInfo;
 
@@ -1097,9 +2330,115 @@ pub trait MockFn: Sized + 'static {
     where
         E: FnOnce(&mut build::Each<Self>),
     {
-        let mut each = build::Each::new();
-        each_fn(&mut each);
-        each
+        build::Each::from_fn(each_fn)
+    }
+
+    /// Create a clause that makes this [MockFn] fall back to its unmocked implementation
+    /// whenever a call is not matched by any of its call patterns.
+    ///
+    /// This way, selected functions can be spied on within an otherwise strict [Unimock::new] instance.
+    /// Any call patterns registered for the same [MockFn] still take precedence.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock, unmock_with=[real_foo, _])]
+    /// trait Trait {
+    ///     fn foo(&self) -> i32;
+    ///     fn bar(&self) -> i32;
+    /// }
+    ///
+    /// fn real_foo(_: &impl std::any::Any) -> i32 {
+    ///     42
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     TraitMock::foo.spy_fallback(),
+    ///     TraitMock::bar.each_call(matching!()).returns(1),
+    /// ));
+    ///
+    /// assert_eq!(43, u.foo() + u.bar());
+    /// ```
+    #[must_use = "fallback overrides have no effect unless passed to Unimock as a Clause"]
+    fn spy_fallback(self) -> build::FallbackOverride<Self> {
+        build::FallbackOverride::new(fn_mocker::Fallback::Mode(FallbackMode::Unmock))
+    }
+
+    /// Create a clause that disables the unmock fallback for this [MockFn].
+    ///
+    /// In a partially mocked instance (see [Unimock::new_partial]), calls to this [MockFn]
+    /// that are not matched by any of its call patterns produce an error instead of invoking the unmocked implementation.
+    #[must_use = "fallback overrides have no effect unless passed to Unimock as a Clause"]
+    fn strict(self) -> build::FallbackOverride<Self> {
+        build::FallbackOverride::new(fn_mocker::Fallback::Mode(FallbackMode::Error))
+    }
+
+    /// Create a clause that excludes this [MockFn] from falling back to its unmocked implementation.
+    ///
+    /// Calls that are not matched by any call pattern fail with an error saying so, even when the instance is set up to spy.
+    /// This prevents e.g. accidental network calls when a stub is forgotten.
+    /// Like [MockFn::spy_fallback] and [MockFn::strict], this is a fallback override, so only one of them can be set up per [MockFn].
+    /// See also [Unimock::spy_except].
+    #[must_use = "fallback overrides have no effect unless passed to Unimock as a Clause"]
+    fn must_be_mocked(self) -> build::FallbackOverride<Self> {
+        build::FallbackOverride::new(fn_mocker::Fallback::MustBeMocked)
+    }
+
+    /// Create a clause that sets the maximum length, in bytes, of the debug representation of each of this [MockFn]'s inputs in error messages.
+    ///
+    /// This overrides [UnimockBuilder::max_debug_len](build::UnimockBuilder::max_debug_len) for this [MockFn] only.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=TraitMock)]
+    /// trait Trait {
+    ///     fn foo(&self, text: String) -> i32;
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     TraitMock::foo.max_debug_len(8),
+    ///     TraitMock::foo.each_call(matching!("short")).returns(1),
+    /// ));
+    ///
+    /// let error = u.try_eval::<TraitMock::foo>("a very long text".to_string()).err().unwrap();
+    /// assert!(error.to_string().contains("Trait::foo(\"a very ... (10 bytes elided)): No matching call patterns."));
+    /// # assert_eq!(1, u.foo("short".to_string()));
+    /// ```
+    #[must_use = "debug length overrides have no effect unless passed to Unimock as a Clause"]
+    fn max_debug_len(self, max_len: usize) -> build::MaxDebugLenOverride<Self> {
+        build::MaxDebugLenOverride::new(max_len)
+    }
+
+    /// Create a clause that replaces how this [MockFn]'s inputs are rendered,
+    /// for example to keep secrets out of test logs.
+    ///
+    /// The function receives all the inputs, and its output replaces the parenthesized input list
+    /// wherever a call is shown: in all error messages and in the calls recorded by [Unimock::spy_record].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=AuthMock)]
+    /// trait Auth {
+    ///     fn login(&self, user: &str, password: &str) -> bool;
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     AuthMock::login.with_input_format(|(user, _password)| format!("({user:?}, <redacted>)")),
+    ///     AuthMock::login.each_call(matching!("alice", "secret")).returns(true),
+    /// ));
+    ///
+    /// let error = u.try_eval::<AuthMock::login>(("alice", "hunter2")).err().unwrap();
+    /// assert!(error.to_string().contains("Auth::login(\"alice\", <redacted>): No matching call patterns."));
+    /// # assert!(u.login("alice", "secret"));
+    /// ```
+    #[must_use = "input formats have no effect unless passed to Unimock as a Clause"]
+    fn with_input_format<I>(self, input_format: I) -> build::InputFormatOverride<Self>
+    where
+        I: (for<'i> Fn(&Self::Inputs<'i>) -> alloc::String) + Send + Sync + 'static,
+    {
+        build::InputFormatOverride::new(debug::InputFormat(alloc::Box::new(input_format)))
     }
 
     /// Define a stub-like call pattern directly on this [MockFn].
@@ -1111,6 +2450,7 @@ pub trait MockFn: Sized + 'static {
     ///
     /// This call pattern variant supports return values that do not implement [Clone],
     /// therefore the call pattern can only be matched a single time.
+    #[track_caller]
     fn some_call(
         self,
         matching_fn: &dyn Fn(&mut Matching<Self>),
@@ -1128,6 +2468,7 @@ pub trait MockFn: Sized + 'static {
     /// that needs to be specified on this MockFn.
     ///
     /// This variant is specialized for functions called multiple times.
+    #[track_caller]
     fn each_call(
         self,
         matching_fn: &dyn Fn(&mut Matching<Self>),
@@ -1139,6 +2480,37 @@ pub trait MockFn: Sized + 'static {
         )
     }
 
+    /// Respond to every call to this [MockFn] with a static reference, whatever the inputs.
+    ///
+    /// This is a shorthand for `.each_call(matching!(..)).returns(value)`,
+    /// for functions returning e.g. a `&str` or a slice of a `const` array.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=NameMock)]
+    /// trait Name {
+    ///     fn name(&self, id: u32) -> &str;
+    /// }
+    ///
+    /// let u = Unimock::new(NameMock::name.static_stub("Bob"));
+    /// assert_eq!("Bob", u.name(1));
+    /// assert_eq!("Bob", u.name(2));
+    /// ```
+    #[track_caller]
+    #[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
+    fn static_stub<T>(
+        self,
+        value: &'static T,
+    ) -> build::Quantify<'static, Self, property::InAnyOrder>
+    where
+        T: ?Sized + Send + Sync + 'static,
+        &'static T: output::IntoReturn<Self::OutputKind>,
+        <Self::OutputKind as Kind>::Return: responder::IntoReturner<Self>,
+    {
+        self.each_call(&|m| m.func(|_, _| true)).returns(value)
+    }
+
     /// Initiate a call pattern builder intended to be used as a [Clause] with exact order verification.
     ///
     /// The chain of `next_call` call-patterns _must_ be matched (called) in the exact same order as they appear
@@ -1177,6 +2549,7 @@ pub trait MockFn: Sized + 'static {
     /// u.bar();
     /// u.foo(2);
     /// ```
+    #[track_caller]
     fn next_call(
         self,
         matching_fn: &dyn Fn(&mut Matching<Self>),
@@ -1189,11 +2562,68 @@ pub trait MockFn: Sized + 'static {
     }
 }
 
+/// Shorthands for the most common single-pattern stubs of a [MockFn].
+///
+/// Implemented for every [MockFn].
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=StoreMock)]
+/// trait Store {
+///     fn get(&self, key: &str) -> i32;
+///     fn put(&self, key: &str, value: i32);
+/// }
+///
+/// let u = Unimock::new((StoreMock::get.quick_mock(42), StoreMock::put.ignore()));
+/// u.put("a", 1);
+/// assert_eq!(42, u.get("a"));
+/// assert_eq!(42, u.get("b"));
+/// ```
+pub trait MockFnExt: MockFn {
+    /// Respond to every call with `value`, whatever the inputs.
+    ///
+    /// This is a shorthand for `.each_call(matching!(..)).returns(value)`.
+    #[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
+    fn quick_mock<T>(self, value: T) -> build::Quantify<'static, Self, property::InAnyOrder>
+    where
+        T: output::IntoReturn<Self::OutputKind>,
+        <Self::OutputKind as Kind>::Return: responder::IntoReturner<Self>;
+
+    /// Accept every call to a function returning `()`, whatever the inputs.
+    #[must_use = "call patterns have no effect unless passed to Unimock as a Clause"]
+    fn ignore(self) -> build::Quantify<'static, Self, property::InAnyOrder>
+    where
+        (): output::IntoReturn<Self::OutputKind>,
+        <Self::OutputKind as Kind>::Return: responder::IntoReturner<Self>;
+}
+
+impl<F: MockFn> MockFnExt for F {
+    #[track_caller]
+    fn quick_mock<T>(self, value: T) -> build::Quantify<'static, Self, property::InAnyOrder>
+    where
+        T: output::IntoReturn<Self::OutputKind>,
+        <Self::OutputKind as Kind>::Return: responder::IntoReturner<Self>,
+    {
+        self.each_call(&|m| m.func(|_, _| true)).returns(value)
+    }
+
+    #[track_caller]
+    fn ignore(self) -> build::Quantify<'static, Self, property::InAnyOrder>
+    where
+        (): output::IntoReturn<Self::OutputKind>,
+        <Self::OutputKind as Kind>::Return: responder::IntoReturner<Self>,
+    {
+        self.quick_mock(())
+    }
+}
+
 /// Static information about a method
 #[derive(Clone, Copy)]
 pub struct MockFnInfo {
     type_id: TypeId,
     path: TraitMethodPath,
+    signature: Option<&'static str>,
     has_default_impl: bool,
     partial_by_default: bool,
 }
@@ -1208,6 +2638,7 @@ impl MockFnInfo {
         Self {
             type_id,
             path: TraitMethodPath::default(),
+            signature: None,
             has_default_impl: false,
             partial_by_default: false,
         }
@@ -1221,6 +2652,24 @@ impl MockFnInfo {
         }
     }
 
+    /// Set the path of the module the trait is defined in, as returned by `module_path!()`.
+    ///
+    /// Error messages then name the method by its full path, e.g. `my_crate::billing::Gateway::charge`.
+    pub const fn module_path(self, module_path: &'static str) -> Self {
+        Self {
+            path: self.path.with_module(module_path),
+            ..self
+        }
+    }
+
+    /// Set the rendered signature of the method, shown when no call pattern matches a call.
+    pub const fn signature(self, signature: &'static str) -> Self {
+        Self {
+            signature: Some(signature),
+            ..self
+        }
+    }
+
     /// Mark the method as being a default implementation
     pub const fn default_impl(self) -> Self {
         Self {
@@ -1239,6 +2688,13 @@ pub struct Impossible;
 /// Clauses may be _terminal_ (basic) and _non-terminal_ (composite).
 /// Terminal clauses are created with unimock's builder API, non-terminals/composites are created by grouping other clauses in tuples.
 ///
+/// Clauses are consumed by the [Unimock] they configure, and do not implement [Clone]:
+/// They may own return values that can be returned only once, which could not be shared by several instances.
+/// To configure many instances the same way, write a function returning `impl Clause`, like `setup_foo_and_bar` below, and call it once per instance.
+///
+/// Terminal clauses are usually created through the [MockFn] methods. [build::Each::from_fn] is an alternative
+/// to [MockFn::stub] that takes the [MockFn] as a type parameter.
+///
 /// ```rust
 /// use unimock::*;
 /// #[unimock(api=FooMock)]
@@ -1274,7 +2730,60 @@ pub struct Impossible;
 #[must_use]
 pub trait Clause {
     #[doc(hidden)]
-    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), alloc::String>;
+    fn deconstruct(self, sink: &mut dyn clause::term::Sink) -> Result<(), AssembleError>;
+
+    /// Combine this clause with an `override_` clause, whose call patterns take priority.
+    ///
+    /// Unordered call patterns of `override_` are tried before the ones of `self`,
+    /// regardless of the order they were defined in.
+    /// The patterns of `self` are still matched by calls that `override_` doesn't match.
+    ///
+    /// # Example
+    /// ```
+    /// # use unimock::*;
+    /// #[unimock(api=ConfigMock)]
+    /// trait Config {
+    ///     fn get(&self, key: &str) -> Option<String>;
+    /// }
+    ///
+    /// fn base_fixture() -> impl Clause {
+    ///     (
+    ///         ConfigMock::get.each_call(matching!(_)).returns(None),
+    ///         ConfigMock::get.each_call(matching!("timeout")).returns(Some("30".to_string())),
+    ///     )
+    /// }
+    ///
+    /// let u = Unimock::new(base_fixture().with_override(
+    ///     ConfigMock::get.each_call(matching!("timeout")).returns(Some("5".to_string())),
+    /// ));
+    ///
+    /// assert_eq!(Some("5".to_string()), u.get("timeout"));
+    /// assert_eq!(None, u.get("retries"));
+    /// ```
+    fn with_override<C: Clause>(self, override_: C) -> build::WithOverride<Self, C>
+    where
+        Self: Sized,
+    {
+        build::WithOverride {
+            base: self,
+            override_,
+        }
+    }
+}
+
+/// Access to values stored in a [Unimock] using [Unimock::with_value].
+///
+/// Unmocked functions receive the mock instance as their first parameter,
+/// so a bound on this trait lets them reach shared test state.
+pub trait AsValueStore {
+    /// Get a reference to the stored value of type `T`, if any.
+    fn get_value<T: Send + Sync + 'static>(&self) -> Option<&T>;
+}
+
+impl AsValueStore for Unimock {
+    fn get_value<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        Unimock::get_value(self)
+    }
 }
 
 type AnyBox = Box<dyn Any + Send + Sync + 'static>;
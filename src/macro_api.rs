@@ -0,0 +1,68 @@
+//! Building blocks for implementing [MockFn](crate::MockFn) by hand.
+//!
+//! The [unimock](crate::unimock) macro generates a [MockFn](crate::MockFn) type for each trait method,
+//! and implements the trait for [Unimock](crate::Unimock) by calling into this module.
+//! Traits that the macro can't handle yet (e.g. exotic receivers) can be mocked by writing the same code by hand.
+//!
+//! The items in this module follow semantic versioning like the rest of the public API.
+//! The macro itself is free to use other, unstable helpers.
+//!
+//! # Example
+//! A hand-written mock consists of three parts:
+//! 1. A surrogate type for the method, implementing [MockFn](crate::MockFn).
+//!    Its [OutputKind](crate::MockFn::OutputKind) is one of the marker types in [output](crate::output),
+//!    e.g. [Owning](crate::output::Owning) for owned return values.
+//! 2. An implementation of the trait for [Unimock](crate::Unimock), which calls [eval] and handles the [Eval] result.
+//! 3. Clauses, which are built exactly like for generated mocks.
+//!
+//! ```rust
+//! use unimock::*;
+//! use unimock::macro_api::{eval, Continuation, Eval};
+//!
+//! trait Greet {
+//!     fn greet(&self, name: &str) -> String;
+//! }
+//!
+//! #[allow(non_snake_case)]
+//! mod GreetMock {
+//!     use unimock::*;
+//!
+//!     #[allow(non_camel_case_types)]
+//!     pub struct greet;
+//!
+//!     impl MockFn for greet {
+//!         // One parameter is passed as-is, several as a tuple.
+//!         type Inputs<'i> = &'i str;
+//!         type OutputKind = output::Owning<String>;
+//!         type AnswerFn = dyn Fn(&Unimock, &str) -> String + Send + Sync;
+//!
+//!         fn info() -> MockFnInfo {
+//!             MockFnInfo::new::<Self>().path(&["Greet", "greet"])
+//!         }
+//!
+//!         fn debug_inputs(name: &Self::Inputs<'_>) -> Box<[Option<String>]> {
+//!             Box::new([Some(format!("{name:?}"))])
+//!         }
+//!     }
+//! }
+//!
+//! impl Greet for Unimock {
+//!     fn greet(&self, name: &str) -> String {
+//!         match eval::<GreetMock::greet>(self, name) {
+//!             Eval::Return(output) => output,
+//!             Eval::Continue(Continuation::Answer(answer_fn), name) => answer_fn(self, name),
+//!             Eval::Continue(continuation, _) => continuation.report(self),
+//!         }
+//!     }
+//! }
+//!
+//! let u = Unimock::new(GreetMock::greet.stub(|each| {
+//!     each.call(matching!("world")).returns("Hello, world!");
+//!     each.call(matching!(_)).answers(&|_, name| format!("Hi, {name}!"));
+//! }));
+//!
+//! assert_eq!("Hello, world!", u.greet("world"));
+//! assert_eq!("Hi, you!", u.greet("you"));
+//! ```
+
+pub use crate::private::{eval, eval_async, AnswerClosure, Continuation, Eval};
@@ -1,18 +1,46 @@
 use core::fmt::Display;
 
-use crate::alloc::{vec, BTreeSet, Box, String, Vec};
+use crate::alloc::{vec, BTreeMap, BTreeSet, Box, String, Vec};
 use crate::{
     call_pattern::{InputIndex, PatIndex},
+    debug::{elide, CallPatternDebug},
     private::MismatchReporter,
 };
 
 pub(crate) struct MismatchesBuilder {
     mismatches: Vec<(PatIndex, InputIndex, Mismatch)>,
+    max_debug_len: usize,
+    hide_actual: bool,
 }
 
 impl MismatchesBuilder {
-    pub fn collect_from_reporter(&mut self, pat_index: PatIndex, reporter: MismatchReporter) {
+    /// Leave out the actual inputs, e.g. because a custom input format hides them.
+    pub fn hide_actual(mut self, hide_actual: bool) -> Self {
+        self.hide_actual = hide_actual;
+        self
+    }
+
+    pub fn collect_from_reporter(
+        &mut self,
+        pat_index: PatIndex,
+        label: Option<&'static str>,
+        reporter: MismatchReporter,
+    ) {
+        let max_debug_len = self.max_debug_len;
+        let elide = |debug: Option<String>| debug.map(|debug| elide(debug, max_debug_len));
+
         for (input_index, mismatch) in reporter.mismatches {
+            let mismatch = Mismatch {
+                kind: mismatch.kind,
+                actual: if self.hide_actual {
+                    None
+                } else {
+                    elide(mismatch.actual)
+                },
+                actual_hidden: self.hide_actual,
+                expected: elide(mismatch.expected),
+                label,
+            };
             self.mismatches.push((pat_index, input_index, mismatch));
         }
     }
@@ -30,8 +58,13 @@ pub(crate) struct Mismatches {
 }
 
 impl Mismatches {
-    pub fn builder() -> MismatchesBuilder {
-        MismatchesBuilder { mismatches: vec![] }
+    /// Collect mismatches, eliding debug representations longer than `max_debug_len`.
+    pub fn builder(max_debug_len: usize) -> MismatchesBuilder {
+        MismatchesBuilder {
+            mismatches: vec![],
+            max_debug_len,
+            hide_actual: false,
+        }
     }
 
     fn has_unique_pat_index(&self) -> bool {
@@ -42,6 +75,75 @@ impl Mismatches {
 
         pat_indexes.len() <= 1
     }
+
+    /// Find the call pattern with the fewest mismatched inputs.
+    ///
+    /// Only patterns that reported mismatches per input take part, and there must be more than one of them.
+    pub fn closest(
+        &self,
+        debug_pattern: impl FnOnce(PatIndex) -> CallPatternDebug,
+    ) -> Option<ClosestMatch> {
+        if self.has_unique_pat_index() {
+            return None;
+        }
+
+        let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+        for (pat_index, _, _) in self.mismatches.iter() {
+            *counts.entry(pat_index.0).or_default() += 1;
+        }
+
+        // `min_by_key` keeps the first of equal elements, i.e. the pattern defined first
+        let (closest_index, _) = counts.into_iter().min_by_key(|(_, count)| *count)?;
+
+        Some(ClosestMatch {
+            pattern: debug_pattern(PatIndex(closest_index)),
+            pat_index: PatIndex(closest_index),
+            inputs: self
+                .mismatches
+                .iter()
+                .filter(|(pat_index, _, _)| pat_index.0 == closest_index)
+                .map(|(_, input_index, mismatch)| (*input_index, mismatch.actual.clone()))
+                .collect(),
+        })
+    }
+}
+
+/// Clues about why no call pattern matched a call, besides the mismatches themselves.
+#[derive(Clone)]
+pub(crate) struct NoMatchHints {
+    pub closest: Option<ClosestMatch>,
+    /// Descriptions of the tried call patterns, see [Matching::debug_hint](crate::private::Matching::debug_hint)
+    pub debug_hints: Box<[&'static str]>,
+}
+
+/// The call pattern that was closest to matching a call, and the inputs that made it fail.
+#[derive(Clone)]
+pub(crate) struct ClosestMatch {
+    pattern: CallPatternDebug,
+    pat_index: PatIndex,
+    inputs: Box<[(InputIndex, Option<String>)]>,
+}
+
+impl Display for ClosestMatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Closest call pattern {} is {}, where ",
+            self.pat_index, self.pattern
+        )?;
+
+        for (index, (input_index, actual)) in self.inputs.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            match actual {
+                Some(actual) => write!(f, "input #{} was {actual}", input_index.0)?,
+                None => write!(f, "input #{} did not match", input_index.0)?,
+            }
+        }
+
+        write!(f, ".")
+    }
 }
 
 impl Display for Mismatches {
@@ -56,11 +158,21 @@ impl Display for Mismatches {
             let Mismatch {
                 kind,
                 actual,
+                actual_hidden,
                 expected,
+                label,
             } = mismatch;
-            let mut header_msg = MismatchMsg::new(*pat_index, *input_index, is_unique_pat, *kind);
+            let mut header_msg =
+                MismatchMsg::new(*pat_index, *label, *input_index, is_unique_pat, *kind);
 
             match (kind, actual, expected) {
+                (_, None, _) if *actual_hidden => {
+                    header_msg.fmt(f)?;
+                    writeln!(
+                        f,
+                        "The actual value is not shown, because the inputs have a custom format."
+                    )?;
+                }
                 (MismatchKind::Pattern, Some(actual), Some(expected)) => {
                     header_msg.has_comparison = true;
                     header_msg.fmt(f)?;
@@ -114,7 +226,11 @@ impl Display for Mismatches {
 pub(crate) struct Mismatch {
     pub kind: MismatchKind,
     pub actual: Option<String>,
+    /// Whether `actual` was left out on purpose
+    pub actual_hidden: bool,
     pub expected: Option<String>,
+    /// The label of the mismatched call pattern
+    pub label: Option<&'static str>,
 }
 
 #[derive(Clone, Copy)]
@@ -126,6 +242,7 @@ pub(crate) enum MismatchKind {
 
 struct MismatchMsg {
     pat_index: PatIndex,
+    label: Option<&'static str>,
     input_index: InputIndex,
     is_unique_pat: bool,
     mismatch_kind: MismatchKind,
@@ -135,12 +252,14 @@ struct MismatchMsg {
 impl MismatchMsg {
     fn new(
         pat_index: PatIndex,
+        label: Option<&'static str>,
         input_index: InputIndex,
         is_unique_pat: bool,
         mismatch_kind: MismatchKind,
     ) -> Self {
         Self {
             pat_index,
+            label,
             input_index,
             is_unique_pat,
             mismatch_kind,
@@ -159,14 +278,14 @@ impl Display for MismatchMsg {
 
         write!(f, "{initial_msg}")?;
 
-        if self.is_unique_pat {
-            write!(f, "input #{}", self.input_index.0)?;
-        } else {
-            write!(
+        match (self.label, self.is_unique_pat) {
+            (Some(label), _) => write!(f, "call pattern {label:?}, input #{}", self.input_index.0)?,
+            (None, true) => write!(f, "input #{}", self.input_index.0)?,
+            (None, false) => write!(
                 f,
                 "call pattern #{}, input #{}",
                 self.pat_index.0, self.input_index.0
-            )?;
+            )?,
         }
 
         if let MismatchKind::Pattern | MismatchKind::Eq = self.mismatch_kind {
@@ -16,6 +16,99 @@ pub mod fmt {
     }
 }
 
+/// Mock APIs for `core::iter` traits
+#[cfg(feature = "mock-core")]
+pub mod iter {
+    use core::marker::PhantomData;
+
+    use crate::{unimock, Clause, MockFn, Unimock};
+
+    /// Iteration over items of type `T`.
+    ///
+    /// [Unimock] can't implement [Iterator] directly, because the trait has only one `Item` type.
+    /// Instead, [Unimock::as_iterator] creates a [MockIterator] for a specific item type,
+    /// whose [Iterator] implementation is mocked through [IteratorMock](IteratorMock):
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::core::iter::{self, IteratorMock};
+    ///
+    /// fn sum(iter: impl Iterator<Item = i32>) -> i32 {
+    ///     iter.sum()
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     iter::returns_items([1, 2, 3]),
+    ///     IteratorMock::size_hint
+    ///         .with_types::<i32>()
+    ///         .some_call(matching!())
+    ///         .returns((3, Some(3))),
+    /// ));
+    ///
+    /// assert_eq!((3, Some(3)), u.as_iterator::<i32>().size_hint());
+    /// assert_eq!(6, sum(u.as_iterator()));
+    /// ```
+    #[unimock(prefix=crate, api=IteratorMock)]
+    pub trait IteratorOf<T> {
+        /// See [Iterator::next].
+        fn next(&mut self) -> Option<T>;
+
+        /// See [Iterator::size_hint].
+        ///
+        /// Unless mocked, this returns `(0, None)`.
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, None)
+        }
+    }
+
+    /// An [Iterator] over `T` that is backed by a [Unimock].
+    ///
+    /// Created by [Unimock::as_iterator].
+    pub struct MockIterator<T> {
+        unimock: Unimock,
+        item: PhantomData<fn() -> T>,
+    }
+
+    impl<T> MockIterator<T> {
+        pub(crate) fn new(unimock: Unimock) -> Self {
+            Self {
+                unimock,
+                item: PhantomData,
+            }
+        }
+    }
+
+    impl<T: 'static> Iterator for MockIterator<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            IteratorOf::<T>::next(&mut self.unimock)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            IteratorOf::<T>::size_hint(&self.unimock)
+        }
+    }
+
+    /// A clause that makes [MockIterator]s over `T` yield the given items, and then `None`.
+    ///
+    /// For the clause to verify, the iterator must be driven to its end, i.e. past the last item.
+    #[track_caller]
+    pub fn returns_items<T>(items: impl IntoIterator<Item = T>) -> impl Clause
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        // Not using `matching!`, which would point error messages at this function instead of the caller
+        let mut responses = IteratorMock::next
+            .with_types::<T>()
+            .each_call(&|matching| matching.func(|_, _| true));
+        for item in items {
+            responses = responses.returns(Some(item)).once().then();
+        }
+        responses.returns(None::<T>)
+    }
+}
+
 /// Mock APIs for `core::hash` traits
 #[cfg(feature = "mock-core")]
 pub mod hash {
@@ -1,5 +1,192 @@
 //! Mock APIs for `futures 0.3` traits
 
+/// Mock APIs for `futures::stream` traits
+#[cfg(feature = "mock-futures-core-0-3")]
+pub mod stream {
+    use core::marker::PhantomData;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use futures_core_0_3::Stream;
+
+    use crate::alloc::Vec;
+    use crate::build::{DefineMultipleResponses, QuantifiedResponse};
+    use crate::clause::DefinedAt;
+    use crate::output::{IntoReturn, Kind};
+    use crate::property::{Exact, InAnyOrder};
+    use crate::responder::IntoReturner;
+    use crate::{error, unimock, Clause, MockFn, Unimock};
+
+    /// A [Stream] of items of type `T`.
+    ///
+    /// Like with [IteratorOf](crate::mock::core::iter::IteratorOf), [Unimock] can't implement [Stream] directly,
+    /// because the trait has only one `Item` type.
+    /// Instead, [Unimock::as_stream] creates a [MockStream] for a specific item type,
+    /// whose [Stream] implementation is mocked through [StreamMock](StreamMock):
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::futures_0_3::stream;
+    /// use futures_util_0_3::StreamExt;
+    ///
+    /// # tokio_1::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let u = Unimock::new(stream::yields([1, 2]).then_pending().then_none());
+    ///
+    /// let items: Vec<i32> = u.as_stream::<i32>().collect().await;
+    /// assert_eq!(vec![1, 2], items);
+    /// # });
+    /// ```
+    #[unimock(prefix=crate, api=StreamMock)]
+    pub trait StreamOf<T> {
+        /// See [Stream::poll_next].
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>>;
+
+        /// See [Stream::size_hint].
+        ///
+        /// Unless mocked, this returns `(0, None)`.
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, None)
+        }
+    }
+
+    /// A [Stream] of `T` that is backed by a [Unimock].
+    ///
+    /// Created by [Unimock::as_stream].
+    pub struct MockStream<T> {
+        unimock: Unimock,
+        item: PhantomData<fn() -> T>,
+    }
+
+    impl<T> MockStream<T> {
+        pub(crate) fn new(unimock: Unimock) -> Self {
+            Self {
+                unimock,
+                item: PhantomData,
+            }
+        }
+    }
+
+    impl<T: 'static> Stream for MockStream<T> {
+        type Item = T;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            StreamOf::<T>::poll_next(Pin::new(&mut self.get_mut().unimock), cx)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            StreamOf::<T>::size_hint(&self.unimock)
+        }
+    }
+
+    /// A clause that makes [MockStream]s over `T` yield the given items.
+    ///
+    /// More polls are scripted by chaining the methods of [StreamScript].
+    #[track_caller]
+    pub fn yields<T>(items: impl IntoIterator<Item = T>) -> StreamScript<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        StreamScript {
+            steps: items.into_iter().map(StreamStep::Item).collect(),
+            defined_at: core::panic::Location::caller(),
+        }
+    }
+
+    /// A scripted sequence of responses to [StreamMock::poll_next](StreamMock::poll_next), created by [yields].
+    ///
+    /// Every step responds to exactly one poll, in order.
+    /// For the clause to verify, the stream must be polled through the whole script.
+    pub struct StreamScript<T> {
+        steps: Vec<StreamStep<T>>,
+        defined_at: &'static core::panic::Location<'static>,
+    }
+
+    enum StreamStep<T> {
+        Item(T),
+        Pending,
+        None,
+    }
+
+    impl<T> StreamScript<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        /// Yield the given items.
+        pub fn then_yields(mut self, items: impl IntoIterator<Item = T>) -> Self {
+            self.steps.extend(items.into_iter().map(StreamStep::Item));
+            self
+        }
+
+        /// Wake the task and return `Poll::Pending`.
+        pub fn then_pending(mut self) -> Self {
+            self.steps.push(StreamStep::Pending);
+            self
+        }
+
+        /// Signal the end of the stream by returning `Poll::Ready(None)`.
+        pub fn then_none(mut self) -> Self {
+            self.steps.push(StreamStep::None);
+            self
+        }
+    }
+
+    impl<T> Clause for StreamScript<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        fn deconstruct(
+            self,
+            sink: &mut dyn crate::clause::term::Sink,
+        ) -> Result<(), error::AssembleError> {
+            let mut steps = self.steps.into_iter();
+            let Some(first) = steps.next() else {
+                return Ok(());
+            };
+
+            // Not using `matching!`, which would point error messages at this function instead of the caller
+            let mut responses = respond(
+                StreamMock::poll_next
+                    .with_types::<T>()
+                    .each_call(&|matching| matching.func(|_, _| true)),
+                first,
+                &|_, cx| {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                },
+            );
+            for step in steps {
+                responses = respond(responses.then(), step, &|_, cx| {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                });
+            }
+
+            DefinedAt {
+                clause: responses,
+                defined_at: self.defined_at,
+            }
+            .deconstruct(sink)
+        }
+    }
+
+    fn respond<'p, F, T>(
+        responses: DefineMultipleResponses<'p, F, InAnyOrder>,
+        step: StreamStep<T>,
+        pending: &'static F::AnswerFn,
+    ) -> QuantifiedResponse<'p, F, InAnyOrder, Exact>
+    where
+        F: MockFn,
+        Poll<Option<T>>: IntoReturn<F::OutputKind>,
+        <F::OutputKind as Kind>::Return: IntoReturner<F>,
+    {
+        match step {
+            StreamStep::Item(item) => responses.returns(Poll::Ready(Some(item))).once(),
+            StreamStep::Pending => responses.answers(pending).once(),
+            StreamStep::None => responses.returns(Poll::Ready(None)).once(),
+        }
+    }
+}
+
 /// Mock APIs for `futures::io` traits
 #[cfg(feature = "mock-futures-io-0-3")]
 pub mod io {
@@ -0,0 +1,132 @@
+//! Mock APIs for `log` traits
+//!
+//! [Unimock] implements [log_0_4::Log], and [scoped] installs it as the global logger while running a closure.
+//! Log records can be matched by their level, e.g. `matching!((rec) if rec.level() == Level::Warn)`,
+//! or by their formatted message using the `msg_contains!` form of [matching!](crate::matching).
+
+#[cfg(feature = "mock-log-0-4")]
+use crate::{unimock, Unimock};
+#[cfg(feature = "mock-log-0-4")]
+use log_0_4::{Metadata, Record};
+#[cfg(feature = "mock-log-0-4")]
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+#[cfg(feature = "mock-log-0-4")]
+#[unimock(prefix=crate, api=LogMock, mirror=log_0_4::Log)]
+pub trait Log {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool;
+    fn log(&self, record: &Record<'_>);
+    fn flush(&self);
+}
+
+/// The Unimock instance receiving the records of the global logger.
+#[cfg(feature = "mock-log-0-4")]
+static CURRENT: Mutex<Option<Unimock>> = Mutex::new(None);
+
+/// Serializes [scoped] calls, because there is only one global logger.
+#[cfg(feature = "mock-log-0-4")]
+static SCOPE: Mutex<()> = Mutex::new(());
+
+/// Whether installing [ScopedLogger] as the global logger succeeded.
+#[cfg(feature = "mock-log-0-4")]
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+/// The global logger, forwarding to the Unimock instance of the current scope.
+///
+/// Records logged outside any scope are discarded.
+#[cfg(feature = "mock-log-0-4")]
+struct ScopedLogger;
+
+#[cfg(feature = "mock-log-0-4")]
+impl log_0_4::Log for ScopedLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        current().is_some_and(|u| log_0_4::Log::enabled(&u, metadata))
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if let Some(u) = current() {
+            log_0_4::Log::log(&u, record);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(u) = current() {
+            log_0_4::Log::flush(&u);
+        }
+    }
+}
+
+/// Clone the current instance out of the lock, so the mock is free to log recursively.
+#[cfg(feature = "mock-log-0-4")]
+fn current() -> Option<Unimock> {
+    lock(&CURRENT).clone()
+}
+
+#[cfg(feature = "mock-log-0-4")]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panicking test must not fail the tests running after it
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Resets the global logger when the scope ends, also when unwinding.
+#[cfg(feature = "mock-log-0-4")]
+struct ScopeGuard {
+    max_level: log_0_4::LevelFilter,
+}
+
+#[cfg(feature = "mock-log-0-4")]
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        *lock(&CURRENT) = None;
+        log_0_4::set_max_level(self.max_level);
+    }
+}
+
+/// Run `f` with the given [Unimock] installed as the global logger.
+///
+/// All records logged while `f` runs, from any thread, are passed to [LogMock::log].
+/// The maximum log level is raised to `Trace` during the scope, so that no records are filtered out before reaching the mock.
+/// Note that the `log` macros do not ask [LogMock::enabled] before logging, it only needs to be mocked for code using `log_enabled!`.
+///
+/// The global logger of the `log` crate can only be set once per process.
+/// The first call to this function installs a forwarding logger, and calls to it from parallel tests wait for each other.
+///
+/// # Panics
+/// Panics if a different global logger has already been installed.
+///
+/// # Example
+/// ```rust
+/// use log_0_4::Level;
+/// use unimock::*;
+/// use unimock::mock::log_0_4::{scoped, LogMock};
+///
+/// let u = Unimock::new(
+///     LogMock::log
+///         .next_call(matching!((rec) if rec.level() == Level::Warn))
+///         .returns(()),
+/// );
+///
+/// scoped(&u, || {
+///     log_0_4::warn!("retrying");
+/// });
+/// ```
+#[cfg(feature = "mock-log-0-4")]
+#[track_caller]
+pub fn scoped<R>(unimock: &Unimock, f: impl FnOnce() -> R) -> R {
+    let installed = *INSTALLED.get_or_init(|| {
+        static LOGGER: ScopedLogger = ScopedLogger;
+        log_0_4::set_logger(&LOGGER).is_ok()
+    });
+    if !installed {
+        panic!("Could not install Unimock as the global logger, because a different logger has already been installed.");
+    }
+
+    let _scope = lock(&SCOPE);
+    let _guard = ScopeGuard {
+        max_level: log_0_4::max_level(),
+    };
+    *lock(&CURRENT) = Some(unimock.clone());
+    log_0_4::set_max_level(log_0_4::LevelFilter::Trace);
+
+    f()
+}
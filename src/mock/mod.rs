@@ -1,5 +1,8 @@
 pub mod core;
 pub mod embedded_hal_1;
 pub mod futures_0_3;
+pub mod log_0_4;
+pub mod rand_core_0_6;
 pub mod std;
 pub mod tokio_1;
+pub mod tower_service_0_3;
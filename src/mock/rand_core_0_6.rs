@@ -0,0 +1,139 @@
+//! Mock APIs for `rand_core` traits
+//!
+//! [Unimock] implements [rand_core_0_6::RngCore] and [rand_core_0_6::CryptoRng],
+//! so it can be passed to anything that takes an `impl Rng`, like shuffling and sampling functions.
+//!
+//! Every random value is then a scripted response, which makes such code deterministic under test.
+
+#[cfg(feature = "mock-rand-core-0-6")]
+use crate::{
+    alloc::{Arc, Vec},
+    build::{QuantifiedResponse, Quantify},
+    property::{Exact, InAnyOrder},
+    unimock, MockFn, Unimock,
+};
+#[cfg(feature = "mock-rand-core-0-6")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "mock-rand-core-0-6")]
+#[unimock(prefix=crate, api=RngCoreMock, mirror=rand_core_0_6::RngCore)]
+pub trait RngCore {
+    fn next_u32(&mut self) -> u32;
+    fn next_u64(&mut self) -> u64;
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core_0_6::Error>;
+}
+
+/// Unimock only produces scripted values, so this marker is just a way to pass it to APIs requiring a [CryptoRng](rand_core_0_6::CryptoRng).
+#[cfg(feature = "mock-rand-core-0-6")]
+impl rand_core_0_6::CryptoRng for Unimock {}
+
+#[cfg(feature = "mock-rand-core-0-6")]
+impl RngCoreMock::next_u32 {
+    /// A clause which returns the given values in order, one per call.
+    ///
+    /// The clause expects to be called exactly once per value.
+    #[track_caller]
+    pub fn returns_sequence(
+        self,
+        values: impl IntoIterator<Item = u32>,
+    ) -> QuantifiedResponse<'static, Self, InAnyOrder, Exact> {
+        let values: Vec<u32> = values.into_iter().collect();
+        let n = values.len();
+        let index = AtomicUsize::new(0);
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .answers_arc(Arc::new(move |_| {
+                let Some(value) = values.get(index.fetch_add(1, Ordering::SeqCst)) else {
+                    panic!(
+                        "RngCore::next_u32: all {} scripted values have been returned",
+                        values.len()
+                    );
+                };
+                *value
+            }))
+            .n_times(n)
+    }
+}
+
+#[cfg(feature = "mock-rand-core-0-6")]
+impl RngCoreMock::next_u64 {
+    /// A clause which returns the given values in order, one per call.
+    ///
+    /// The clause expects to be called exactly once per value.
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use rand_core_0_6::RngCore;
+    /// use unimock::mock::rand_core_0_6::RngCoreMock;
+    ///
+    /// let mut u = Unimock::new(RngCoreMock::next_u64.returns_sequence([1, 2, 3]));
+    ///
+    /// assert_eq!(1, u.next_u64());
+    /// assert_eq!(2, u.next_u64());
+    /// assert_eq!(3, u.next_u64());
+    /// ```
+    #[track_caller]
+    pub fn returns_sequence(
+        self,
+        values: impl IntoIterator<Item = u64>,
+    ) -> QuantifiedResponse<'static, Self, InAnyOrder, Exact> {
+        let values: Vec<u64> = values.into_iter().collect();
+        let n = values.len();
+        let index = AtomicUsize::new(0);
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .answers_arc(Arc::new(move |_| {
+                let Some(value) = values.get(index.fetch_add(1, Ordering::SeqCst)) else {
+                    panic!(
+                        "RngCore::next_u64: all {} scripted values have been returned",
+                        values.len()
+                    );
+                };
+                *value
+            }))
+            .n_times(n)
+    }
+}
+
+#[cfg(feature = "mock-rand-core-0-6")]
+impl RngCoreMock::fill_bytes {
+    /// A clause which fills every destination buffer from the given bytes,
+    /// continuing where the previous call left off.
+    ///
+    /// Panics if a call asks for more bytes than what is left.
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use rand_core_0_6::RngCore;
+    /// use unimock::mock::rand_core_0_6::RngCoreMock;
+    ///
+    /// let mut u = Unimock::new(RngCoreMock::fill_bytes.fills_from([1, 2, 3, 4]));
+    ///
+    /// let mut buf = [0; 3];
+    /// u.fill_bytes(&mut buf);
+    /// assert_eq!([1, 2, 3], buf);
+    ///
+    /// let mut buf = [0; 1];
+    /// u.fill_bytes(&mut buf);
+    /// assert_eq!([4], buf);
+    /// ```
+    #[track_caller]
+    pub fn fills_from(
+        self,
+        bytes: impl IntoIterator<Item = u8>,
+    ) -> Quantify<'static, Self, InAnyOrder> {
+        let bytes: Vec<u8> = bytes.into_iter().collect();
+        let pos = AtomicUsize::new(0);
+        self.each_call(&|matching| matching.func(|_, _| true))
+            .answers_arc(Arc::new(move |_, dest| {
+                let start = pos.fetch_add(dest.len(), Ordering::SeqCst);
+                let Some(src) = bytes.get(start..start + dest.len()) else {
+                    panic!(
+                        "RngCore::fill_bytes: requested {} bytes, but only {} scripted bytes are left",
+                        dest.len(),
+                        bytes.len().saturating_sub(start)
+                    );
+                };
+                dest.copy_from_slice(src);
+            }))
+    }
+}
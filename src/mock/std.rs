@@ -1,11 +1,198 @@
 //! Mock APIs for `std` traits
 
+/// Mock APIs for `std::collections` types
+#[cfg(feature = "mock-std")]
+pub mod collections {
+    use core::ops::Bound;
+    use std::collections::BTreeMap;
+    use std::vec::{IntoIter, Vec};
+
+    use crate::unimock;
+
+    /// The operations of a sorted map, implemented by [BTreeMap] and mockable through [BTreeMapMock].
+    ///
+    /// `std` has no trait for maps, so code that wants to accept a mocked map can be generic over this one instead:
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use std::collections::BTreeMap;
+    /// use std::ops::Bound;
+    /// use unimock::mock::std::collections::{BTreeMapLike, BTreeMapMock};
+    ///
+    /// fn sum_below(map: &impl BTreeMapLike<i32, i32>, limit: i32) -> i32 {
+    ///     map.range((Bound::Unbounded, Bound::Excluded(&limit)))
+    ///         .map(|(_, value)| value)
+    ///         .sum()
+    /// }
+    ///
+    /// let real = BTreeMap::from([(1, 10), (2, 20), (3, 30)]);
+    /// assert_eq!(30, sum_below(&real, 3));
+    ///
+    /// let u = Unimock::new(
+    ///     BTreeMapMock::range
+    ///         .with_types::<i32, i32>()
+    ///         .next_call(matching!((Bound::Unbounded, Bound::Excluded(3))))
+    ///         .returns(vec![(1, 5)].into_iter()),
+    /// );
+    /// assert_eq!(5, sum_below(&u, 3));
+    /// ```
+    #[unimock(prefix=crate, api=BTreeMapMock)]
+    pub trait BTreeMapLike<K, V> {
+        /// Get a reference to the value of the given key.
+        fn get(&self, key: &K) -> Option<&V>;
+
+        /// Insert a key-value pair, returning the old value of the key.
+        fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+        /// Remove a key, returning its value.
+        fn remove(&mut self, key: &K) -> Option<V>;
+
+        /// Whether the map contains the given key.
+        fn contains_key(&self, key: &K) -> bool;
+
+        /// The key-value pairs within the given range of keys, in ascending key order.
+        ///
+        /// The pairs are cloned into a [Vec], so that a mock can return them by value.
+        fn range(&self, range: (Bound<&K>, Bound<&K>)) -> IntoIter<(K, V)>;
+    }
+
+    impl<K: Ord + Clone, V: Clone> BTreeMapLike<K, V> for BTreeMap<K, V> {
+        fn get(&self, key: &K) -> Option<&V> {
+            BTreeMap::get(self, key)
+        }
+
+        fn insert(&mut self, key: K, value: V) -> Option<V> {
+            BTreeMap::insert(self, key, value)
+        }
+
+        fn remove(&mut self, key: &K) -> Option<V> {
+            BTreeMap::remove(self, key)
+        }
+
+        fn contains_key(&self, key: &K) -> bool {
+            BTreeMap::contains_key(self, key)
+        }
+
+        fn range(&self, range: (Bound<&K>, Bound<&K>)) -> IntoIter<(K, V)> {
+            BTreeMap::range::<K, _>(self, range)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>()
+                .into_iter()
+        }
+    }
+}
+
+/// Mock APIs for `std::convert` traits
+#[cfg(feature = "mock-std")]
+pub mod convert {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+    use std::{string::String, vec::Vec};
+
+    use crate::{unimock, Unimock};
+
+    /// Conversion of a [Unimock] into some target type `T`.
+    ///
+    /// This is the mocked side of [`From<Unimock>`](From), which unimock implements for a selection of `std` types:
+    /// [String], [`Vec<u8>`](Vec), [PathBuf] and [OsString].
+    /// Code under test accepting e.g. `impl Into<String>` can then be passed a [Unimock],
+    /// and the conversion is controlled through [IntoMock::into](IntoMock::into), one target type at a time:
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::std::convert::IntoMock;
+    ///
+    /// fn greet(name: impl Into<String>) -> String {
+    ///     format!("Hello, {}!", name.into())
+    /// }
+    ///
+    /// let u = Unimock::new(
+    ///     IntoMock::into
+    ///         .with_types::<String>()
+    ///         .next_call(matching!())
+    ///         .returns("mock"),
+    /// );
+    ///
+    /// assert_eq!("Hello, mock!", greet(u));
+    /// ```
+    ///
+    /// Note that there is no corresponding mock for `From<T> for Unimock`:
+    /// [From::from] has no Unimock instance available to look up its responses in.
+    #[unimock(prefix=crate, api=IntoMock)]
+    pub trait IntoTarget<T> {
+        /// Convert this instance into `T`.
+        fn into(self) -> T;
+    }
+
+    impl From<Unimock> for String {
+        fn from(unimock: Unimock) -> Self {
+            IntoTarget::into(unimock)
+        }
+    }
+
+    impl From<Unimock> for Vec<u8> {
+        fn from(unimock: Unimock) -> Self {
+            IntoTarget::into(unimock)
+        }
+    }
+
+    impl From<Unimock> for PathBuf {
+        fn from(unimock: Unimock) -> Self {
+            IntoTarget::into(unimock)
+        }
+    }
+
+    impl From<Unimock> for OsString {
+        fn from(unimock: Unimock) -> Self {
+            IntoTarget::into(unimock)
+        }
+    }
+}
+
 /// Mock APIs for `std::error` traits
 #[cfg(feature = "mock-std")]
 pub mod error {
     use crate::unimock;
+    use core::borrow::Borrow;
+    use std::boxed::Box;
     use std::error::Error;
 
+    /// An error to return from a mocked [Error::source].
+    ///
+    /// The output of `source` borrows an error from Unimock, which requires a type that is
+    /// [Send], [Sync] and [Borrow]s a `dyn Error`, which plain error types and `Box<dyn Error>` are not.
+    /// This wrapper fulfills those requirements for any thread-safe error, including another [Unimock](crate::Unimock):
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use std::error::Error;
+    /// use unimock::mock::std::error::{ErrorMock, ErrorSource};
+    ///
+    /// let u = Unimock::new(
+    ///     ErrorMock::source
+    ///         .some_call(matching!())
+    ///         .returns(Some(ErrorSource::new(std::fmt::Error))),
+    /// );
+    ///
+    /// assert!(u.source().unwrap().is::<std::fmt::Error>());
+    /// ```
+    ///
+    /// `returns(None::<ErrorSource>)` makes a mocked error have no source.
+    pub struct ErrorSource(Box<dyn Error + Send + Sync>);
+
+    impl ErrorSource {
+        /// Wrap the given error.
+        pub fn new(error: impl Error + Send + Sync + 'static) -> Self {
+            Self(Box::new(error))
+        }
+    }
+
+    impl Borrow<dyn Error + 'static> for ErrorSource {
+        fn borrow(&self) -> &(dyn Error + 'static) {
+            self.0.as_ref()
+        }
+    }
+
     #[unimock(prefix=crate, api=ErrorMock, mirror=std::error::Error)]
     pub trait Error {
         fn source(&self) -> Option<&(dyn Error + 'static)> {}
@@ -19,12 +206,59 @@ pub mod error {
     }
 }
 
+/// Mock APIs for `std::fmt` traits
+#[cfg(feature = "mock-std")]
+pub mod fmt {
+    use std::fmt::Result;
+
+    use crate::build::Quantify;
+    use crate::property::InAnyOrder;
+    use crate::MockFn;
+    use unimock_macros::unimock;
+
+    #[unimock(prefix=crate, api=WriteMock, mirror=std::fmt::Write)]
+    pub trait Write {
+        fn write_str(&mut self, s: &str) -> Result;
+        fn write_char(&mut self, c: char) -> Result {}
+
+        // FIXME: This is not implemented (yet) because of self-lifetime in argument.
+        // It just uses the default implementation which delegates to `Self::write_str`.
+        // fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> Result {}
+    }
+
+    impl WriteMock::write_str {
+        /// A clause where the first `n` calls succeed, and every call after that fails with [std::fmt::Error].
+        ///
+        /// ```rust
+        /// # use unimock::*;
+        /// use std::fmt::Write;
+        /// use unimock::mock::std::fmt::WriteMock;
+        ///
+        /// let mut u = Unimock::new(WriteMock::write_str.fails_after(1));
+        ///
+        /// assert!(u.write_str("ok").is_ok());
+        /// assert!(u.write_str("fails").is_err());
+        /// ```
+        #[track_caller]
+        pub fn fails_after(self, n: usize) -> Quantify<'static, Self, InAnyOrder> {
+            self.each_call(&|matching| matching.func(|_, _| true))
+                .returns(Ok(()))
+                .n_times(n)
+                .then()
+                .returns(Err(std::fmt::Error))
+        }
+    }
+}
+
 /// Mock APIs for `std::io` traits
 #[cfg(feature = "mock-std")]
 pub mod io {
-    use std::io::{IoSlice, IoSliceMut, Result, SeekFrom};
-    use std::{string::String, vec::Vec};
+    use std::io::{ErrorKind, IoSlice, IoSliceMut, Result, SeekFrom};
+    use std::{string::String, sync::Arc, vec::Vec};
 
+    use crate::build::Quantify;
+    use crate::property::InAnyOrder;
+    use crate::MockFn;
     use unimock_macros::unimock;
 
     #[unimock(prefix=crate, api=BufReadMock, mirror=std::io::BufRead)]
@@ -68,6 +302,122 @@ pub mod io {
         // It just uses the default implementation which delegates to `Self::write`.
         // fn write_fmt(&mut self, fmt: std::fmt::Arguments<'_>) -> std::io::Result<()> {}
     }
+
+    /// Fault injection for [Write::write], for testing how callers handle partial and failing writes.
+    ///
+    /// These are ready-made clauses, which respond to any input.
+    impl WriteMock::write {
+        /// A clause where every call accepts at most `n` bytes, and returns how many were accepted.
+        ///
+        /// Callers have to loop to write a longer buffer, like [Write::write_all](std::io::Write::write_all) does:
+        ///
+        /// ```rust
+        /// # use unimock::*;
+        /// use std::io::Write;
+        /// use unimock::mock::std::io::WriteMock;
+        ///
+        /// let mut u = Unimock::new(WriteMock::write.short_writes(4));
+        ///
+        /// assert_eq!(4, u.write(b"hello").unwrap());
+        /// u.write_all(b"hello").unwrap();
+        /// ```
+        #[track_caller]
+        pub fn short_writes(self, n: usize) -> Quantify<'static, Self, InAnyOrder> {
+            self.each_call(&|matching| matching.func(|_, _| true))
+                .answers_arc(Arc::new(move |_, buf| Ok(buf.len().min(n))))
+        }
+
+        /// A clause where the first `n` calls accept the whole buffer, and every call after that fails with the given [ErrorKind].
+        ///
+        /// ```rust
+        /// # use unimock::*;
+        /// use std::io::{ErrorKind, Write};
+        /// use unimock::mock::std::io::WriteMock;
+        ///
+        /// let mut u = Unimock::new(WriteMock::write.fails_after(1, ErrorKind::BrokenPipe));
+        ///
+        /// assert_eq!(3, u.write(b"abc").unwrap());
+        /// assert_eq!(ErrorKind::BrokenPipe, u.write(b"abc").unwrap_err().kind());
+        /// ```
+        #[track_caller]
+        pub fn fails_after(self, n: usize, kind: ErrorKind) -> Quantify<'static, Self, InAnyOrder> {
+            self.each_call(&|matching| matching.func(|_, _| true))
+                .answers(&|_, buf| Ok(buf.len()))
+                .n_times(n)
+                .then()
+                .answers_arc(Arc::new(move |_, _| Err(kind.into())))
+        }
+    }
+}
+
+/// Mock APIs for `std::net` types
+#[cfg(feature = "mock-std")]
+pub mod net {
+    use std::io::{Read, Result, Write};
+    use std::sync::Arc;
+
+    use crate::build::Quantify;
+    use crate::property::InAnyOrder;
+    use crate::MockFn;
+    use unimock_macros::unimock;
+
+    /// The operations of a TCP stream, mockable through [TcpStreamMock] together with
+    /// [ReadMock](super::io::ReadMock) and [WriteMock](super::io::WriteMock).
+    ///
+    /// Code generic over this trait works with both a [std::net::TcpStream] and a [Unimock](crate::Unimock):
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use std::io::{Read, Write};
+    /// use unimock::mock::std::io::{ReadMock, WriteMock};
+    /// use unimock::mock::std::net::{TcpStreamLike, TcpStreamMock};
+    ///
+    /// fn ping(stream: &impl TcpStreamLike) -> std::io::Result<Vec<u8>> {
+    ///     let mut writer = stream.try_clone()?;
+    ///     writer.write_all(b"ping")?;
+    ///
+    ///     let mut reader = stream.try_clone()?;
+    ///     let mut reply = vec![0; 4];
+    ///     reader.read_exact(&mut reply)?;
+    ///     Ok(reply)
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     TcpStreamMock::try_clone.shares_state(),
+    ///     WriteMock::write_all
+    ///         .next_call(matching!(eq!(b"ping")))
+    ///         .returns(Ok(())),
+    ///     ReadMock::read
+    ///         .next_call(matching!(_))
+    ///         .answers(&|_, buf| {
+    ///             buf.copy_from_slice(b"pong");
+    ///             Ok(4)
+    ///         }),
+    /// ));
+    ///
+    /// assert_eq!(b"pong".to_vec(), ping(&u).unwrap());
+    /// ```
+    #[unimock(prefix=crate, api=TcpStreamMock)]
+    pub trait TcpStreamLike: Read + Write + Sized {
+        /// Create a new handle to the same stream, see [std::net::TcpStream::try_clone].
+        fn try_clone(&self) -> Result<Self>;
+    }
+
+    impl TcpStreamLike for std::net::TcpStream {
+        fn try_clone(&self) -> Result<Self> {
+            std::net::TcpStream::try_clone(self)
+        }
+    }
+
+    impl TcpStreamMock::try_clone {
+        /// A clause where every call returns a clone of the [Unimock](crate::Unimock) instance,
+        /// which shares its clauses and call counts with the original like [Clone::clone] does.
+        #[track_caller]
+        pub fn shares_state(self) -> Quantify<'static, Self, InAnyOrder> {
+            self.each_call(&|matching| matching.func(|_, _| true))
+                .answers_arc(Arc::new(|unimock| Ok(unimock.clone())))
+        }
+    }
 }
 
 /// Mock APIs for `std::process` traits
@@ -91,6 +441,8 @@ pub mod process {
             type OutputKind = Owning<std::process::ExitCode>;
             type AnswerFn = dyn Fn() -> Self + Send + Sync;
 
+            const N_INPUTS: Option<usize> = Some(0);
+
             fn info() -> crate::MockFnInfo {
                 let mut info = crate::MockFnInfo::new::<Self>().path(&["Termination", "report"]);
                 info.partial_by_default = true;
@@ -103,3 +455,296 @@ pub mod process {
         }
     }
 }
+
+/// Mock APIs for `std::str` traits
+///
+/// [FromStr::from_str](std::str::FromStr::from_str) has no receiver, so [Unimock] implements [FromStr](std::str::FromStr)
+/// by evaluating [FromStrMock::from_str] on the instance installed with [scoped].
+#[cfg(feature = "mock-std")]
+pub mod str {
+    use core::cell::RefCell;
+    use std::string::String;
+
+    use crate::private::{Continuation, Eval};
+    use crate::Unimock;
+
+    /// The [FromStr::Err](std::str::FromStr::Err) of parsing a [Unimock], for [FromStrMock::from_str] to respond with.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParseError(pub String);
+
+    impl ParseError {
+        /// Create a parse error with the given message.
+        pub fn new(message: impl Into<String>) -> Self {
+            Self(message.into())
+        }
+    }
+
+    impl core::fmt::Display for ParseError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for ParseError {}
+
+    /// Unimock mock API for [std::str::FromStr].
+    #[allow(non_snake_case)]
+    pub mod FromStrMock {
+        use super::ParseError;
+        use crate::{output::Owning, MockFn, Unimock};
+        use std::{boxed::Box, format, string::String};
+
+        #[allow(non_camel_case_types)]
+        /// MockFn for [`FromStr::from_str(s: &str) -> Result<Unimock, ParseError>`](std::str::FromStr::from_str).
+        ///
+        /// The answer function receives the instance installed with [scoped](super::scoped),
+        /// which may be cloned into the parsed value.
+        pub struct from_str;
+
+        impl MockFn for from_str {
+            type Inputs<'i> = &'i str;
+            type OutputKind = Owning<Result<Unimock, ParseError>>;
+            type AnswerFn = dyn Fn(&Unimock, &str) -> Result<Unimock, ParseError> + Send + Sync;
+
+            const N_INPUTS: Option<usize> = Some(1);
+
+            fn info() -> crate::MockFnInfo {
+                crate::MockFnInfo::new::<Self>()
+                    .path(&["std::str::FromStr", "from_str"])
+                    .signature("fn from_str(s: &str) -> Result<Self, Self::Err>")
+            }
+
+            fn debug_inputs(s: &Self::Inputs<'_>) -> Box<[Option<String>]> {
+                Box::new([Some(format!("{s:?}"))])
+            }
+        }
+    }
+
+    std::thread_local! {
+        /// The instance parsing strings on this thread.
+        static CURRENT: RefCell<Option<Unimock>> = const { RefCell::new(None) };
+    }
+
+    /// Restores the previously installed instance when the scope ends, also when unwinding.
+    struct ScopeGuard {
+        previous: Option<Unimock>,
+    }
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            let previous = self.previous.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    /// Run `f` with the given [Unimock] parsing all strings that get parsed into a [Unimock] on the current thread.
+    ///
+    /// Scopes may be nested, the innermost instance is the one that is used.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::std::str::{scoped, FromStrMock, ParseError};
+    ///
+    /// fn parse_all<T: std::str::FromStr>(inputs: &[&str]) -> Result<Vec<T>, T::Err> {
+    ///     inputs.iter().map(|input| input.parse()).collect()
+    /// }
+    ///
+    /// let u = Unimock::new((
+    ///     FromStrMock::from_str
+    ///         .each_call(matching!("valid"))
+    ///         .answers(&|u, _| Ok(u.clone())),
+    ///     FromStrMock::from_str
+    ///         .each_call(matching!(_))
+    ///         .returns(Err(ParseError::new("invalid input"))),
+    /// ));
+    ///
+    /// scoped(&u, || {
+    ///     assert_eq!(2, parse_all::<Unimock>(&["valid", "valid"]).unwrap().len());
+    ///     assert_eq!(
+    ///         Err(ParseError::new("invalid input")),
+    ///         parse_all::<Unimock>(&["valid", "oops"]).map(|parsed| parsed.len())
+    ///     );
+    /// });
+    /// ```
+    pub fn scoped<R>(unimock: &Unimock, f: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.with(|current| current.borrow_mut().replace(unimock.clone()));
+        let _guard = ScopeGuard { previous };
+
+        f()
+    }
+
+    /// Parsing evaluates [FromStrMock::from_str] on the instance installed with [scoped].
+    ///
+    /// # Panics
+    /// Panics if called outside of [scoped].
+    impl core::str::FromStr for Unimock {
+        type Err = ParseError;
+
+        #[track_caller]
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let Some(unimock) = CURRENT.with(|current| current.borrow().clone()) else {
+                panic!("Parsing a Unimock requires an instance installed with `unimock::mock::std::str::scoped`.");
+            };
+
+            match crate::private::eval::<FromStrMock::from_str>(&unimock, s) {
+                Eval::Return(output) => output,
+                Eval::Continue(Continuation::Answer(answer_fn), s) => answer_fn(&unimock, s),
+                Eval::Continue(continuation, _) => continuation.report(&unimock),
+            }
+        }
+    }
+}
+
+/// Mock APIs for `std::sync` types
+#[cfg(feature = "mock-std")]
+pub mod sync {
+    use core::ops::{Deref, DerefMut};
+    use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+    use crate::unimock;
+
+    /// The operations of a read-write lock, mockable through [RwLockMock].
+    ///
+    /// The guards returned by a mock hold a copy of the value inside a [LockedValue],
+    /// which the test pre-populates and inspects afterwards.
+    /// A [MockWriteGuard] stores its modified value back into the [LockedValue] when dropped,
+    /// so the modification is visible to subsequent reads:
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use std::sync::Arc;
+    /// use unimock::mock::std::sync::{LockedValue, RwLockLike, RwLockMock};
+    ///
+    /// fn increment(counter: &impl RwLockLike<u32>) -> u32 {
+    ///     *counter.write() += 1;
+    ///     *counter.read()
+    /// }
+    ///
+    /// let value = LockedValue::new(41);
+    /// let u = Unimock::new((
+    ///     RwLockMock::read.with_types::<u32>().each_call(matching!()).answers_arc({
+    ///         let value = value.clone();
+    ///         Arc::new(move |_| value.read())
+    ///     }),
+    ///     RwLockMock::write.with_types::<u32>().each_call(matching!()).answers_arc({
+    ///         let value = value.clone();
+    ///         Arc::new(move |_| value.write())
+    ///     }),
+    /// ));
+    ///
+    /// assert_eq!(42, increment(&u));
+    /// assert_eq!(42, value.get());
+    /// ```
+    #[unimock(prefix=crate, api=RwLockMock)]
+    pub trait RwLockLike<T> {
+        /// Acquire shared read access to the locked value.
+        fn read(&self) -> MockReadGuard<T>;
+
+        /// Acquire exclusive write access to the locked value.
+        fn write(&self) -> MockWriteGuard<T>;
+    }
+
+    /// A value behind a mocked lock, shared between a test and the guards it hands out.
+    ///
+    /// Clones refer to the same value.
+    pub struct LockedValue<T>(Arc<Mutex<T>>);
+
+    impl<T> LockedValue<T> {
+        /// Create a new locked value.
+        pub fn new(value: T) -> Self {
+            Self(Arc::new(Mutex::new(value)))
+        }
+
+        /// Replace the locked value.
+        pub fn set(&self, value: T) {
+            *self.lock() = value;
+        }
+
+        fn lock(&self) -> MutexGuard<'_, T> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl<T: Clone> LockedValue<T> {
+        /// A copy of the current locked value.
+        pub fn get(&self) -> T {
+            self.lock().clone()
+        }
+
+        /// A guard for reading the current locked value.
+        pub fn read(&self) -> MockReadGuard<T> {
+            MockReadGuard { value: self.get() }
+        }
+
+        /// A guard for modifying the locked value.
+        ///
+        /// The modified value is stored when the guard is dropped.
+        pub fn write(&self) -> MockWriteGuard<T> {
+            MockWriteGuard {
+                value: Some(self.get()),
+                target: self.clone(),
+            }
+        }
+    }
+
+    impl<T> Clone for LockedValue<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    /// An in-memory lock, for running the same code against a real implementation.
+    impl<T: Clone> RwLockLike<T> for LockedValue<T> {
+        fn read(&self) -> MockReadGuard<T> {
+            LockedValue::read(self)
+        }
+
+        fn write(&self) -> MockWriteGuard<T> {
+            LockedValue::write(self)
+        }
+    }
+
+    /// Read guard returned by [RwLockLike::read], dereferencing to a copy of a [LockedValue].
+    pub struct MockReadGuard<T> {
+        value: T,
+    }
+
+    impl<T> Deref for MockReadGuard<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.value
+        }
+    }
+
+    /// Write guard returned by [RwLockLike::write].
+    ///
+    /// Dereferences to a copy of a [LockedValue], which is stored back when the guard is dropped.
+    pub struct MockWriteGuard<T> {
+        value: Option<T>,
+        target: LockedValue<T>,
+    }
+
+    impl<T> Deref for MockWriteGuard<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.value.as_ref().expect("value is present until dropped")
+        }
+    }
+
+    impl<T> DerefMut for MockWriteGuard<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            self.value.as_mut().expect("value is present until dropped")
+        }
+    }
+
+    impl<T> Drop for MockWriteGuard<T> {
+        fn drop(&mut self) {
+            if let Some(value) = self.value.take() {
+                self.target.set(value);
+            }
+        }
+    }
+}
@@ -9,6 +9,10 @@ pub mod io {
 
     use tokio_1::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf, Result, SeekFrom};
 
+    use std::{sync::Arc, vec::Vec};
+
+    use crate::build::{DefineMultipleResponses, DefineResponse, QuantifiedResponse, Quantify};
+    use crate::property::{Exact, Ordering};
     use crate::unimock;
 
     #[unimock(prefix=crate, api=AsyncBufReadMock, mirror=AsyncBufRead)]
@@ -53,4 +57,166 @@ pub mod io {
 
         fn is_write_vectored(&self) -> bool {}
     }
+
+    /// Response helpers for [AsyncReadMock::poll_read](AsyncReadMock::poll_read).
+    ///
+    /// Implemented for the builders returned by e.g. [next_call](crate::MockFn::next_call) and [each_call](crate::MockFn::each_call),
+    /// so that a byte stream can be scripted chunk by chunk:
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::tokio_1::io::{AsyncReadMock, AsyncReadResponses};
+    /// use tokio_1::io::AsyncReadExt;
+    ///
+    /// # tokio_1::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut u = Unimock::new((
+    ///     AsyncReadMock::poll_read.next_call(matching!()).pending_once(),
+    ///     AsyncReadMock::poll_read.next_call(matching!()).reads(b"hello"),
+    /// ));
+    ///
+    /// let mut buf = [0; 16];
+    /// let n = u.read(&mut buf).await.unwrap();
+    /// assert_eq!(b"hello", &buf[..n]);
+    /// # });
+    /// ```
+    pub trait AsyncReadResponses<'p, O: Ordering> {
+        /// Fill the [ReadBuf] with the given bytes, and return `Poll::Ready(Ok(()))`.
+        ///
+        /// Panics when called if the bytes don't fit in the remaining capacity of the buffer.
+        fn reads(self, bytes: impl Into<Vec<u8>>) -> Quantify<'p, AsyncReadMock::poll_read, O>;
+
+        /// Wake the task and return `Poll::Pending`, once.
+        fn pending_once(self) -> QuantifiedResponse<'p, AsyncReadMock::poll_read, O, Exact>;
+    }
+
+    /// Response helpers for [AsyncWriteMock::poll_write](AsyncWriteMock::poll_write).
+    ///
+    /// See [AsyncReadResponses] for how these are used.
+    pub trait AsyncWriteResponses<'p, O: Ordering> {
+        /// Accept up to `n` bytes of the written buffer, and return how many were accepted.
+        fn write_accepts(self, n: usize) -> Quantify<'p, AsyncWriteMock::poll_write, O>;
+
+        /// Wake the task and return `Poll::Pending`, once.
+        fn pending_once(self) -> QuantifiedResponse<'p, AsyncWriteMock::poll_write, O, Exact>;
+    }
+
+    /// Response helpers for the `Poll<io::Result<T>>` methods that take no buffer:
+    /// [AsyncWriteMock::poll_flush](AsyncWriteMock::poll_flush), [AsyncWriteMock::poll_shutdown](AsyncWriteMock::poll_shutdown)
+    /// and [AsyncSeekMock::poll_complete](AsyncSeekMock::poll_complete).
+    ///
+    /// Since [std::io::Error] is not [Clone], a `Poll::Ready(Err(_))` can't be passed to `returns` for more than one call.
+    /// These helpers construct a fresh output for every call instead:
+    ///
+    /// ```rust
+    /// # use unimock::*;
+    /// use unimock::mock::tokio_1::io::{AsyncPollResponses, AsyncWriteMock};
+    /// use tokio_1::io::AsyncWriteExt;
+    ///
+    /// # tokio_1::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut u = Unimock::new((
+    ///     AsyncWriteMock::poll_flush.next_call(matching!()).pending_once(),
+    ///     AsyncWriteMock::poll_flush.next_call(matching!()).ready_ok(()),
+    ///     AsyncWriteMock::poll_shutdown
+    ///         .each_call(matching!())
+    ///         .ready_err(std::io::ErrorKind::BrokenPipe),
+    /// ));
+    ///
+    /// u.flush().await.unwrap();
+    /// assert_eq!(
+    ///     std::io::ErrorKind::BrokenPipe,
+    ///     u.shutdown().await.unwrap_err().kind()
+    /// );
+    /// # });
+    /// ```
+    pub trait AsyncPollResponses<'p, F: crate::MockFn, T, O: Ordering> {
+        /// Return `Poll::Ready(Ok(value))`.
+        fn ready_ok(self, value: T) -> Quantify<'p, F, O>;
+
+        /// Return `Poll::Ready(Err(_))` with a new error of the given kind.
+        fn ready_err(self, kind: std::io::ErrorKind) -> Quantify<'p, F, O>;
+
+        /// Wake the task and return `Poll::Pending`, once.
+        fn pending_once(self) -> QuantifiedResponse<'p, F, O, Exact>;
+    }
+
+    macro_rules! impl_async_poll_responses {
+        ($builder:ident, $mock_fn:ty, $ok:ty) => {
+            impl<'p, O: Ordering> AsyncPollResponses<'p, $mock_fn, $ok, O>
+                for $builder<'p, $mock_fn, O>
+            {
+                fn ready_ok(self, value: $ok) -> Quantify<'p, $mock_fn, O> {
+                    self.answers_arc(Arc::new(move |_, _| Poll::Ready(Ok(value.clone()))))
+                }
+
+                fn ready_err(self, kind: std::io::ErrorKind) -> Quantify<'p, $mock_fn, O> {
+                    self.answers_arc(Arc::new(move |_, _| Poll::Ready(Err(kind.into()))))
+                }
+
+                fn pending_once(self) -> QuantifiedResponse<'p, $mock_fn, O, Exact> {
+                    self.answers(&|_, cx| {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    })
+                    .once()
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_async_io_responses {
+        ($builder:ident) => {
+            impl<'p, O: Ordering> AsyncReadResponses<'p, O>
+                for $builder<'p, AsyncReadMock::poll_read, O>
+            {
+                fn reads(
+                    self,
+                    bytes: impl Into<Vec<u8>>,
+                ) -> Quantify<'p, AsyncReadMock::poll_read, O> {
+                    let bytes = bytes.into();
+                    self.answers_arc(Arc::new(move |_, _, buf| {
+                        buf.put_slice(&bytes);
+                        Poll::Ready(Ok(()))
+                    }))
+                }
+
+                fn pending_once(
+                    self,
+                ) -> QuantifiedResponse<'p, AsyncReadMock::poll_read, O, Exact> {
+                    self.answers(&|_, cx, _| {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    })
+                    .once()
+                }
+            }
+
+            impl<'p, O: Ordering> AsyncWriteResponses<'p, O>
+                for $builder<'p, AsyncWriteMock::poll_write, O>
+            {
+                fn write_accepts(self, n: usize) -> Quantify<'p, AsyncWriteMock::poll_write, O> {
+                    self.answers_arc(Arc::new(move |_, _, buf| Poll::Ready(Ok(buf.len().min(n)))))
+                }
+
+                fn pending_once(
+                    self,
+                ) -> QuantifiedResponse<'p, AsyncWriteMock::poll_write, O, Exact> {
+                    self.answers(&|_, cx, _| {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    })
+                    .once()
+                }
+            }
+        };
+    }
+
+    impl_async_io_responses!(DefineResponse);
+    impl_async_io_responses!(DefineMultipleResponses);
+
+    impl_async_poll_responses!(DefineResponse, AsyncWriteMock::poll_flush, ());
+    impl_async_poll_responses!(DefineMultipleResponses, AsyncWriteMock::poll_flush, ());
+    impl_async_poll_responses!(DefineResponse, AsyncWriteMock::poll_shutdown, ());
+    impl_async_poll_responses!(DefineMultipleResponses, AsyncWriteMock::poll_shutdown, ());
+    impl_async_poll_responses!(DefineResponse, AsyncSeekMock::poll_complete, u64);
+    impl_async_poll_responses!(DefineMultipleResponses, AsyncSeekMock::poll_complete, u64);
 }
@@ -0,0 +1,223 @@
+//! Mock APIs for `tower_service` traits
+
+#[cfg(feature = "mock-tower-service-0-3")]
+use core::future::Future;
+#[cfg(feature = "mock-tower-service-0-3")]
+use core::marker::PhantomData;
+#[cfg(feature = "mock-tower-service-0-3")]
+use core::pin::Pin;
+#[cfg(feature = "mock-tower-service-0-3")]
+use core::task::{Context, Poll};
+#[cfg(feature = "mock-tower-service-0-3")]
+use core::time::Duration;
+
+#[cfg(feature = "mock-tower-service-0-3")]
+use tower_service_0_3::Service;
+
+#[cfg(feature = "mock-tower-service-0-3")]
+use crate::alloc::{Arc, Box, Vec};
+#[cfg(feature = "mock-tower-service-0-3")]
+use crate::clause::DefinedAt;
+#[cfg(feature = "mock-tower-service-0-3")]
+use crate::{error, unimock, Clause, MockFn, Unimock};
+
+/// The future returned by a [MockService].
+#[cfg(feature = "mock-tower-service-0-3")]
+pub type ServiceFuture<Res, Err> = Pin<Box<dyn Future<Output = Result<Res, Err>> + Send>>;
+
+/// A [Service] taking requests of type `Req`.
+///
+/// [Unimock] can't implement [Service] directly, because the trait fixes the response and error types per request type.
+/// Instead, [Unimock::as_service] creates a [MockService] for specific types,
+/// whose [Service] implementation is mocked through [ServiceMock](ServiceMock):
+///
+/// ```rust
+/// # use unimock::*;
+/// use std::convert::Infallible;
+/// use tower_service_0_3::Service;
+/// use unimock::mock::tower_service_0_3::script;
+///
+/// # tokio_1::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let u = Unimock::new(script::<&str, String, Infallible>().responds("pong".to_string()));
+///
+/// let mut service = u.as_service::<&str, String, Infallible>();
+/// assert_eq!("pong", service.call("ping").await.unwrap());
+/// # });
+/// ```
+#[cfg(feature = "mock-tower-service-0-3")]
+#[unimock(prefix=crate, api=ServiceMock)]
+pub trait ServiceOf<Req, Res, Err> {
+    /// See [Service::poll_ready].
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Err>>;
+
+    /// See [Service::call].
+    fn call(&mut self, req: Req) -> ServiceFuture<Res, Err>;
+}
+
+/// A [Service] that is backed by a [Unimock].
+///
+/// Created by [Unimock::as_service].
+#[cfg(feature = "mock-tower-service-0-3")]
+pub struct MockService<Req, Res, Err> {
+    unimock: Unimock,
+    types: PhantomData<fn(Req) -> Result<Res, Err>>,
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+impl<Req, Res, Err> MockService<Req, Res, Err> {
+    pub(crate) fn new(unimock: Unimock) -> Self {
+        Self {
+            unimock,
+            types: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+impl<Req, Res, Err> Clone for MockService<Req, Res, Err> {
+    fn clone(&self) -> Self {
+        Self::new(self.unimock.clone())
+    }
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+impl<Req: 'static, Res: 'static, Err: 'static> Service<Req> for MockService<Req, Res, Err> {
+    type Response = Res;
+    type Error = Err;
+    type Future = ServiceFuture<Res, Err>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Err>> {
+        ServiceOf::<Req, Res, Err>::poll_ready(&mut self.unimock, cx)
+    }
+
+    fn call(&mut self, req: Req) -> ServiceFuture<Res, Err> {
+        ServiceOf::<Req, Res, Err>::call(&mut self.unimock, req)
+    }
+}
+
+/// A clause for [MockService]s taking `Req` and responding with `Res` or `Err`.
+///
+/// The script is set up by chaining the methods of [ServiceScript].
+#[cfg(feature = "mock-tower-service-0-3")]
+#[track_caller]
+pub fn script<Req, Res, Err>() -> ServiceScript<Req, Res, Err> {
+    ServiceScript {
+        ready: false,
+        responses: Vec::new(),
+        defined_at: core::panic::Location::caller(),
+        request: PhantomData,
+    }
+}
+
+/// A scripted [MockService], created by [script].
+///
+/// Every response answers exactly one call to [Service::call], in order.
+/// For the clause to verify, the service must be called once for every response.
+#[cfg(feature = "mock-tower-service-0-3")]
+pub struct ServiceScript<Req, Res, Err> {
+    ready: bool,
+    responses: Vec<(Option<Duration>, Result<Res, Err>)>,
+    defined_at: &'static core::panic::Location<'static>,
+    request: PhantomData<fn(Req)>,
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+impl<Req, Res, Err> ServiceScript<Req, Res, Err>
+where
+    Req: 'static,
+    Res: Clone + Send + Sync + 'static,
+    Err: Clone + Send + Sync + 'static,
+{
+    /// Make every call to [Service::poll_ready] return `Poll::Ready(Ok(()))`.
+    pub fn ready(mut self) -> Self {
+        self.ready = true;
+        self
+    }
+
+    /// Respond to the next call with the given response.
+    pub fn responds(mut self, response: Res) -> Self {
+        self.responses.push((None, Ok(response)));
+        self
+    }
+
+    /// Respond to the next call with the given error.
+    pub fn fails(mut self, error: Err) -> Self {
+        self.responses.push((None, Err(error)));
+        self
+    }
+
+    /// Respond to the next call with a future that completes with the given response after a delay.
+    ///
+    /// Useful for testing timeouts. The delay is a `tokio::time::sleep`, so the future must be awaited on a tokio runtime.
+    #[cfg(feature = "mock-tokio-1")]
+    pub fn responds_after(mut self, delay: Duration, response: Res) -> Self {
+        self.responses.push((Some(delay), Ok(response)));
+        self
+    }
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+impl<Req, Res, Err> Clause for ServiceScript<Req, Res, Err>
+where
+    Req: 'static,
+    Res: Clone + Send + Sync + 'static,
+    Err: Clone + Send + Sync + 'static,
+{
+    fn deconstruct(
+        self,
+        sink: &mut dyn crate::clause::term::Sink,
+    ) -> Result<(), error::AssembleError> {
+        // Not using `matching!`, which would point error messages at this function instead of the caller
+        if self.ready {
+            DefinedAt {
+                clause: ServiceMock::poll_ready
+                    .with_types::<Req, Res, Err>()
+                    .each_call(&|matching| matching.func(|_, _| true))
+                    .answers(&|_, _| Poll::Ready(Ok(()))),
+                defined_at: self.defined_at,
+            }
+            .deconstruct(sink)?;
+        }
+
+        let mut responses = self.responses.into_iter();
+        let Some(first) = responses.next() else {
+            return Ok(());
+        };
+
+        let call = ServiceMock::call
+            .with_types::<Req, Res, Err>()
+            .each_call(&|matching| matching.func(|_, _| true));
+        let mut calls = call.answers_arc(respond(first)).once();
+        for response in responses {
+            calls = calls.then().answers_arc(respond(response)).once();
+        }
+
+        DefinedAt {
+            clause: calls,
+            defined_at: self.defined_at,
+        }
+        .deconstruct(sink)
+    }
+}
+
+#[cfg(feature = "mock-tower-service-0-3")]
+#[allow(clippy::type_complexity)]
+#[cfg_attr(not(feature = "mock-tokio-1"), allow(unused_variables))]
+fn respond<Req, Res, Err>(
+    (delay, result): (Option<Duration>, Result<Res, Err>),
+) -> Arc<dyn Fn(&mut Unimock, Req) -> ServiceFuture<Res, Err> + Send + Sync>
+where
+    Res: Clone + Send + Sync + 'static,
+    Err: Clone + Send + Sync + 'static,
+{
+    Arc::new(move |_, _| {
+        let result = result.clone();
+        Box::pin(async move {
+            #[cfg(feature = "mock-tokio-1")]
+            if let Some(delay) = delay {
+                tokio_1::time::sleep(delay).await;
+            }
+            result
+        })
+    })
+}
@@ -2,6 +2,7 @@
 
 use crate::alloc::Box;
 
+pub(crate) mod cow_lending;
 pub(crate) mod deep;
 pub(crate) mod lending;
 pub(crate) mod mut_lending;
@@ -60,9 +61,48 @@ pub trait ReturnDefault<K: Kind> {
     fn return_default() -> K::Return;
 }
 
+/// A boxed value that can be created from a value of type `T`, used by `returns_boxed`.
+///
+/// This is implemented for `Box<T>`, and with the `std` feature also for the boxed [std::error::Error] trait objects.
+/// Use [impl_box_from!](crate::impl_box_from) to implement it for boxes of other trait objects.
+pub trait BoxFrom<T> {
+    /// Put the value into the box.
+    fn box_from(value: T) -> Self;
+}
+
+impl<T> BoxFrom<T> for Box<T> {
+    fn box_from(value: T) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> BoxFrom<E> for Box<dyn std::error::Error> {
+    fn box_from(value: E) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + Send + 'static> BoxFrom<E> for Box<dyn std::error::Error + Send> {
+    fn box_from(value: E) -> Self {
+        Box::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + Send + Sync + 'static> BoxFrom<E>
+    for Box<dyn std::error::Error + Send + Sync>
+{
+    fn box_from(value: E) -> Self {
+        Box::new(value)
+    }
+}
+
 /// A "marker" for mutable types
 pub struct Mutable<T>(pub(crate) T);
 
+pub use cow_lending::CowLending;
 pub use deep::Deep;
 pub use lending::Lending;
 pub use mut_lending::MutLending;
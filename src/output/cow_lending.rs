@@ -0,0 +1,81 @@
+use core::borrow::Borrow;
+
+use crate::alloc::{Cow, ToOwned};
+
+use super::*;
+
+/// A type category for `Cow` outputs, lent out from a value stored by Unimock.
+#[doc(hidden)]
+pub struct CowLending<T: ?Sized + 'static>(core::marker::PhantomData<fn() -> T>);
+
+impl<T: ?Sized + ToOwned + 'static> Kind for CowLending<T>
+where
+    T::Owned: 'static,
+{
+    type Return = CowLent<T>;
+}
+
+impl<T: ?Sized + ToOwned + 'static> Return for CowLending<T>
+where
+    T::Owned: Send + Sync + 'static,
+{
+    type Type = T::Owned;
+}
+
+impl<T0, T: ?Sized + ToOwned + 'static> IntoReturnOnce<CowLending<T>> for T0
+where
+    T::Owned: Send + Sync + 'static,
+    T0: Into<T::Owned>,
+{
+    fn into_return_once(self) -> OutputResult<CowLent<T>> {
+        Ok(CowLent::borrowed(self.into()))
+    }
+}
+
+impl<T0, T: ?Sized + ToOwned + 'static> IntoReturn<CowLending<T>> for T0
+where
+    T::Owned: Send + Sync + 'static,
+    T0: Into<T::Owned>,
+{
+    fn into_return(self) -> OutputResult<CowLent<T>> {
+        Ok(CowLent::borrowed(self.into()))
+    }
+}
+
+pub struct CowLent<T: ?Sized + ToOwned> {
+    value: T::Owned,
+    /// Whether a copy of the value is returned as [Cow::Owned] instead of lending it out
+    owned: bool,
+}
+
+impl<T: ?Sized + ToOwned> CowLent<T> {
+    pub(crate) fn borrowed(value: T::Owned) -> Self {
+        Self {
+            value,
+            owned: false,
+        }
+    }
+
+    pub(crate) fn owned(value: T::Owned) -> Self {
+        Self { value, owned: true }
+    }
+}
+
+impl<T: ?Sized + ToOwned + 'static> GetOutput for CowLent<T>
+where
+    T::Owned: 'static,
+{
+    type Output<'u>
+        = Cow<'u, T>
+    where
+        Self: 'u;
+
+    fn output(&self) -> Option<Self::Output<'_>> {
+        let value: &T = self.value.borrow();
+        if self.owned {
+            Some(Cow::Owned(value.to_owned()))
+        } else {
+            Some(Cow::Borrowed(value))
+        }
+    }
+}
@@ -23,12 +23,10 @@ impl<K: Kind> GetOutput for AsReturn<K>
 where
     Self: 'static,
 {
-    type Output<'u> =
-        Option<
-            <<K as Kind>::Return as GetOutput>::Output<'u>,
-        >
-        where
-            Self: 'u;
+    type Output<'u>
+        = Option<<<K as Kind>::Return as GetOutput>::Output<'u>>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         match self {
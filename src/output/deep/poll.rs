@@ -24,12 +24,10 @@ impl<K: Kind> GetOutput for AsReturn<K>
 where
     Self: 'static,
 {
-    type Output<'u> =
-        Poll<
-            <<K as Kind>::Return as GetOutput>::Output<'u>,
-        >
-        where
-            Self: 'u;
+    type Output<'u>
+        = Poll<<<K as Kind>::Return as GetOutput>::Output<'u>>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         match self {
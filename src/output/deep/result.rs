@@ -31,13 +31,13 @@ where
     EK: Kind,
     Self: 'static,
 {
-    type Output<'u> =
-        Result<
-            <<TK as Kind>::Return as GetOutput>::Output<'u>,
-            <<EK as Kind>::Return as GetOutput>::Output<'u>,
-        >
-        where
-            Self: 'u;
+    type Output<'u>
+        = Result<
+        <<TK as Kind>::Return as GetOutput>::Output<'u>,
+        <<EK as Kind>::Return as GetOutput>::Output<'u>,
+    >
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         match self {
@@ -25,8 +25,10 @@ where
     K: Kind,
     Self: 'static,
 {
-    type Output<'u> = Vec<<<K as Kind>::Return as GetOutput>::Output<'u>>
-        where Self: 'u;
+    type Output<'u>
+        = Vec<<<K as Kind>::Return as GetOutput>::Output<'u>>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         let mut out = Vec::new();
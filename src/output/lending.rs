@@ -35,9 +35,10 @@ where
 pub struct Lent<T: ?Sized>(Box<dyn Borrow<T> + Send + Sync>);
 
 impl<T: ?Sized + 'static> GetOutput for Lent<T> {
-    type Output<'u> = &'u T
-        where
-            Self: 'u;
+    type Output<'u>
+        = &'u T
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         Some(self.0.as_ref().borrow())
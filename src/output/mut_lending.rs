@@ -13,9 +13,10 @@ impl<T: ?Sized + 'static> Kind for MutLending<T> {
 pub struct MutLent<T: ?Sized>(PhantomData<T>);
 
 impl<T: ?Sized + 'static> GetOutput for MutLent<T> {
-    type Output<'u> = &'u mut T
-        where
-            Self: 'u;
+    type Output<'u>
+        = &'u mut T
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         None
@@ -49,8 +49,44 @@ impl<T: Default + 'static> ReturnDefault<Owning<T>> for T {
 
 pub struct Owned<T>(Box<dyn Fn() -> Option<T> + Send + Sync + 'static>);
 
+impl<T: 'static> Owned<T> {
+    /// Box a clone of `value` for every call.
+    pub(crate) fn boxed<C>(value: C) -> Self
+    where
+        T: BoxFrom<C>,
+        C: Clone + Send + Sync + 'static,
+    {
+        Self(Box::new(move || Some(T::box_from(value.clone()))))
+    }
+
+    /// Box `value` for the first call only.
+    #[cfg(any(feature = "std", feature = "spin-lock"))]
+    pub(crate) fn boxed_once<C>(value: C) -> OutputResult<Self>
+    where
+        T: BoxFrom<C>,
+        C: Send + Sync + 'static,
+    {
+        let mutex = crate::private::MutexIsh::new(Some(value));
+        Ok(Self(Box::new(move || {
+            mutex.locked(|option| option.take()).map(T::box_from)
+        })))
+    }
+
+    #[cfg(not(any(feature = "std", feature = "spin-lock")))]
+    pub(crate) fn boxed_once<C>(_value: C) -> OutputResult<Self>
+    where
+        T: BoxFrom<C>,
+        C: Send + Sync + 'static,
+    {
+        Err(OutputError::NoMutexApi)
+    }
+}
+
 impl<T: 'static> GetOutput for Owned<T> {
-    type Output<'u> = T where Self: 'u;
+    type Output<'u>
+        = T
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         (*self.0)()
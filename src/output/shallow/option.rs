@@ -21,9 +21,10 @@ impl<T: ?Sized> Return for Ref<T> {
 }
 
 impl<T: ?Sized + 'static> GetOutput for RefResponse<T> {
-    type Output<'u> = Option<&'u T>
-        where
-            Self: 'u;
+    type Output<'u>
+        = Option<&'u T>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         Some(self.as_ref().map(|val| val.as_ref().borrow()))
@@ -31,9 +32,10 @@ impl<T: ?Sized + 'static> GetOutput for RefResponse<T> {
 }
 
 impl<T: ?Sized + 'static> GetOutput for MutResponse<T> {
-    type Output<'u> = Option<&'u mut T>
-        where
-            Self: 'u;
+    type Output<'u>
+        = Option<&'u mut T>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         None
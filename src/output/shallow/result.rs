@@ -26,9 +26,10 @@ where
 }
 
 impl<T: ?Sized + 'static, E: 'static> GetOutput for RefResponse<T, E> {
-    type Output<'u> = Result<&'u T, E>
-        where
-            Self: 'u;
+    type Output<'u>
+        = Result<&'u T, E>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         Some(match self {
@@ -39,9 +40,10 @@ impl<T: ?Sized + 'static, E: 'static> GetOutput for RefResponse<T, E> {
 }
 
 impl<T: ?Sized + 'static, E: 'static> GetOutput for MutResponse<T, E> {
-    type Output<'u> = Result<&'u mut T, E>
-        where
-            Self: 'u;
+    type Output<'u>
+        = Result<&'u mut T, E>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         match self {
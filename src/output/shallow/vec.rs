@@ -14,9 +14,10 @@ impl<T: ?Sized> Kind for Mix<T> {
 }
 
 impl<T: ?Sized + 'static> GetOutput for Response<T> {
-    type Output<'u> = Vec<&'u T>
-        where
-            Self: 'u;
+    type Output<'u>
+        = Vec<&'u T>
+    where
+        Self: 'u;
 
     fn output(&self) -> Option<Self::Output<'_>> {
         Some(self.iter().map(|el| el.as_ref().borrow()).collect())
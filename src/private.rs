@@ -9,7 +9,6 @@ use crate::{call_pattern::MatchingFn, *};
 pub use crate::default_impl_delegator::*;
 
 /// The result of a [MockFn] evaluation.
-#[doc(hidden)]
 pub enum Eval<'u, 'i, F: MockFn> {
     /// An output should be returned.
     Return(<<<F as MockFn>::OutputKind as Kind>::Return as GetOutput>::Output<'u>),
@@ -28,6 +27,27 @@ pub enum Continuation<F: MockFn> {
     Unmock,
     /// Default implementation should be invoked
     CallDefaultImpl,
+    /// Unmocked implementation should be invoked, and its output passed through the mapper
+    UnmockMap(OutputMapper),
+    /// The output should be deserialized from a replayed call, see [ReplayProbe]
+    Replay(ReplayedOutput),
+}
+
+/// A function transforming the output of an unmocked implementation, see [build::Quantify::map_output].
+pub struct OutputMapper(pub(crate) Arc<dyn Any + Send + Sync>);
+
+/// The type stored inside an [OutputMapper], with `T` the owned output type.
+pub(crate) type OutputMapFn<T> = Box<dyn Fn(T) -> T + Send + Sync>;
+
+impl OutputMapper {
+    /// Map the output of the unmocked implementation.
+    #[track_caller]
+    pub fn apply<T: 'static>(self, output: T) -> T {
+        match self.0.downcast_ref::<OutputMapFn<T>>() {
+            Some(map_fn) => map_fn(output),
+            None => panic!("Output mapper has an unexpected type"),
+        }
+    }
 }
 
 impl<F: MockFn> Continuation<F> {
@@ -36,16 +56,155 @@ impl<F: MockFn> Continuation<F> {
     #[track_caller]
     pub fn report(self, unimock: &Unimock) -> ! {
         let error = match self {
-            Self::Answer(..) => error::MockError::NotAnswered { info: F::info() },
-            Self::Unmock => error::MockError::CannotUnmock { info: F::info() },
-            Self::CallDefaultImpl => error::MockError::NoDefaultImpl { info: F::info() },
+            Self::Answer(..) => error::MockFailure::NotAnswered { info: F::info() },
+            Self::Unmock | Self::UnmockMap(_) => {
+                error::MockFailure::CannotUnmock { info: F::info() }
+            }
+            Self::CallDefaultImpl => error::MockFailure::NoDefaultImpl { info: F::info() },
+            Self::Replay(_) => error::MockFailure::CannotReplay {
+                info: F::info(),
+                reason: "the output borrows its data".into(),
+            },
         };
 
         unimock.induce_panic(error)
     }
 }
 
-#[doc(hidden)]
+/// Tracks the recursion depth of an unmocked implementation while it runs.
+pub struct UnmockGuard {
+    _nesting: NestingGuard,
+}
+
+impl UnmockGuard {
+    /// Enter the unmocked implementation of `F`, panicking if the recursion limit is exceeded.
+    #[track_caller]
+    pub fn enter<F: MockFn + 'static>(unimock: &Unimock) -> Self {
+        let info = F::info();
+        let (guard, depth) = NestingGuard::enter(unimock, info.type_id, Nesting::Unmock);
+        let limit = unimock.shared_state.unmock_recursion_limit;
+
+        // Created before a potential panic, so unwinding restores the depth
+        let guard = Self { _nesting: guard };
+
+        if depth > limit {
+            unimock.induce_panic(error::MockFailure::UnmockRecursionLimit { info, limit });
+        }
+
+        guard
+    }
+}
+
+/// Guard around a call forwarded by [DelegateTo], counting the forwarding depth when the target is a [Unimock].
+pub struct DelegateGuard {
+    _nesting: Option<NestingGuard>,
+}
+
+impl DelegateGuard {
+    /// Enter a call of `F` delegated to `instance`, panicking if the forwarding limit of a [Unimock] target is exceeded.
+    #[track_caller]
+    pub fn enter<F: MockFn + 'static, T: 'static>(instance: &T) -> Self {
+        let Some(unimock) = (instance as &dyn core::any::Any).downcast_ref::<Unimock>() else {
+            return Self { _nesting: None };
+        };
+
+        let info = F::info();
+        let (guard, depth) = NestingGuard::enter(unimock, info.type_id, Nesting::Forward);
+        let limit = unimock.shared_state.unmock_recursion_limit;
+
+        // Created before a potential panic, so unwinding restores the depth
+        let guard = Self {
+            _nesting: Some(guard),
+        };
+
+        if depth > limit {
+            unimock.induce_panic(error::MockFailure::ForwardingLimit { info, limit });
+        }
+
+        guard
+    }
+}
+
+/// The kind of nested call counted by a [NestingGuard].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Nesting {
+    Unmock,
+    Forward,
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Nesting depths of the current thread, keyed by the address of the shared state they count for.
+    static NESTING_DEPTHS: core::cell::RefCell<crate::alloc::BTreeMap<(usize, TypeId, Nesting), usize>> =
+        const { core::cell::RefCell::new(crate::alloc::BTreeMap::new()) };
+}
+
+/// Counts one level of nesting for a MockFn of one instance.
+///
+/// The guard doesn't keep the instance alive, so a by-value receiver can still be dropped while the guard is held.
+/// With `std`, the depth is counted per thread, so calls on other threads or clones don't add to it.
+struct NestingGuard {
+    #[cfg(feature = "std")]
+    key: (usize, TypeId, Nesting),
+    #[cfg(not(feature = "std"))]
+    shared_state: crate::alloc::Weak<crate::state::SharedState>,
+    #[cfg(not(feature = "std"))]
+    key: (TypeId, Nesting),
+}
+
+impl NestingGuard {
+    #[cfg(feature = "std")]
+    fn enter(unimock: &Unimock, type_id: TypeId, nesting: Nesting) -> (Self, usize) {
+        let key = (
+            Arc::as_ptr(&unimock.shared_state) as usize,
+            type_id,
+            nesting,
+        );
+        let depth = NESTING_DEPTHS.with(|depths| {
+            let mut depths = depths.borrow_mut();
+            let depth = depths.entry(key).or_insert(0);
+            *depth += 1;
+            *depth
+        });
+        (Self { key }, depth)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn enter(unimock: &Unimock, type_id: TypeId, nesting: Nesting) -> (Self, usize) {
+        let key = (type_id, nesting);
+        let depth = unimock.shared_state.enter_nesting(key);
+        let guard = Self {
+            shared_state: Arc::downgrade(&unimock.shared_state),
+            key,
+        };
+        (guard, depth)
+    }
+}
+
+impl Drop for NestingGuard {
+    #[cfg(feature = "std")]
+    fn drop(&mut self) {
+        // The thread local may already be gone when a guard is dropped during thread teardown
+        let _ = NESTING_DEPTHS.try_with(|depths| {
+            let mut depths = depths.borrow_mut();
+            if let Some(depth) = depths.get_mut(&self.key) {
+                *depth -= 1;
+                if *depth == 0 {
+                    depths.remove(&self.key);
+                }
+            }
+        });
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn drop(&mut self) {
+        if let Some(shared_state) = self.shared_state.upgrade() {
+            shared_state.exit_nesting(self.key);
+        }
+    }
+}
+
+/// An answer function registered with `answers`, dereferencing to [MockFn::AnswerFn].
 pub struct AnswerClosure<F: MockFn>(pub(crate) AnswerClosureInner<F>);
 
 pub(crate) enum AnswerClosureInner<F: MockFn> {
@@ -78,6 +237,7 @@ pub struct Matching<F: MockFn> {
     pub(crate) mock_fn: core::marker::PhantomData<F>,
     pub(crate) matching_fn: Option<MatchingFn<F>>,
     pub(crate) matcher_debug: Option<debug::InputMatcherDebug>,
+    pub(crate) debug_hint: Option<&'static str>,
 }
 
 impl<F> Matching<F>
@@ -89,6 +249,7 @@ where
             mock_fn: core::marker::PhantomData,
             matching_fn: None,
             matcher_debug: None,
+            debug_hint: None,
         }
     }
 
@@ -105,6 +266,13 @@ where
         self.matching_fn = Some(MatchingFn(Box::new(matching_fn)));
     }
 
+    /// Check at compile time that a pattern with `N` arguments fits [MockFn::N_INPUTS].
+    #[inline]
+    pub fn arity<const N: usize>(&mut self) {
+        #[allow(clippy::let_unit_value)]
+        let () = ArityCheck::<F, N>::OK;
+    }
+
     /// Register debug info on the matching builder.
     ///
     /// This way, a mismatch may be easier to debug, as the debug info can be printed as part of panic messages.
@@ -115,6 +283,35 @@ where
             line,
         });
     }
+
+    /// Register a description of the pattern, listed in the error message when no call pattern matches a call.
+    ///
+    /// Used by the [matching_debug_hint](crate::matching_debug_hint) macro.
+    pub fn debug_hint(&mut self, debug_hint: &'static str) {
+        self.debug_hint = Some(debug_hint);
+    }
+}
+
+/// Apply the matcher built by `matching_fn` to `matching`.
+///
+/// Used by the [matching_debug_hint](crate::matching_debug_hint) macro to extend the matcher of [matching].
+pub fn apply_matching<F: MockFn>(
+    matching: &mut Matching<F>,
+    matching_fn: &dyn Fn(&mut Matching<F>),
+) {
+    matching_fn(matching);
+}
+
+struct ArityCheck<F, const N: usize>(core::marker::PhantomData<F>);
+
+impl<F: MockFn, const N: usize> ArityCheck<F, N> {
+    // Const panics cannot format numbers, but the compiler names `N` in the failing evaluation.
+    const OK: () = match F::N_INPUTS {
+        Some(n_inputs) if n_inputs != N => {
+            panic!("this matcher expects a different number of arguments than MockFn::N_INPUTS")
+        }
+        _ => (),
+    };
 }
 
 /// A reporter used in call pattern matchers in case of mismatched inputs.
@@ -159,7 +356,9 @@ impl MismatchReporter {
             Mismatch {
                 kind: MismatchKind::Pattern,
                 actual: actual.map(|dbg| dbg.into()),
+                actual_hidden: false,
                 expected: expected.map(|dbg| dbg.into()),
+                label: None,
             },
         ));
     }
@@ -176,7 +375,9 @@ impl MismatchReporter {
             Mismatch {
                 kind: MismatchKind::Eq,
                 actual: actual.map(|dbg| dbg.into()),
+                actual_hidden: false,
                 expected: expected.map(|dbg| dbg.into()),
+                label: None,
             },
         ));
     }
@@ -193,7 +394,9 @@ impl MismatchReporter {
             Mismatch {
                 kind: MismatchKind::Ne,
                 actual: actual.map(|dbg| dbg.into()),
+                actual_hidden: false,
                 expected: expected.map(|dbg| dbg.into()),
+                label: None,
             },
         ));
     }
@@ -208,11 +411,94 @@ where
     unimock.handle_error(eval::eval(unimock, inputs))
 }
 
+/// Evaluate a [MockFn] given some inputs, in an `async` context.
+///
+/// This differs from [eval] in that a delayed response is awaited.
+pub async fn eval_async<'u, 'i, F>(unimock: &'u Unimock, inputs: F::Inputs<'i>) -> Eval<'u, 'i, F>
+where
+    F: MockFn + 'static,
+{
+    let eval_result = unimock.handle_error(eval::eval_responder::<F>(unimock, &inputs));
+    let pending_polls = eval_result.pending_polls();
+    let mut unresolved_guard = UnresolvedGuard {
+        unimock,
+        info: pending_polls
+            .filter(|pending_polls| pending_polls.must_resolve)
+            .map(|_| F::info()),
+    };
+
+    #[cfg(feature = "mock-tokio-1")]
+    if let Some(delay) = eval_result.delay() {
+        tokio_1::time::sleep(delay).await;
+    }
+
+    if let Some(pending_polls) = pending_polls {
+        PendingTimes(pending_polls.times).await;
+    }
+    unresolved_guard.info = None;
+
+    unimock.handle_error(eval::respond(unimock, inputs, eval_result))
+}
+
+/// Records a failure if a future that must resolve is dropped while still pending.
+struct UnresolvedGuard<'u> {
+    unimock: &'u Unimock,
+    info: Option<MockFnInfo>,
+}
+
+impl Drop for UnresolvedGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(info) = self.info.take() {
+            self.unimock
+                .record_failure(error::MockFailure::FutureDroppedUnresolved { info });
+        }
+    }
+}
+
+/// A future that is pending for the given number of polls, waking the task every time.
+struct PendingTimes(usize);
+
+impl core::future::Future for PendingTimes {
+    type Output = ();
+
+    fn poll(
+        mut self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<()> {
+        if self.0 == 0 {
+            return core::task::Poll::Ready(());
+        }
+
+        self.0 -= 1;
+        cx.waker().wake_by_ref();
+        core::task::Poll::Pending
+    }
+}
+
 /// Clone a Unimock instance
 pub fn clone_unimock(unimock: &Unimock) -> Unimock {
     unimock.clone()
 }
 
+/// Debug representation of a byte buffer.
+///
+/// Buffers longer than 16 bytes are shown as their length and a hex prefix of 16 bytes, instead of listing every byte.
+pub fn debug_bytes<B: AsRef<[u8]> + ?Sized>(bytes: &B) -> String {
+    use core::fmt::Write;
+
+    let bytes = bytes.as_ref();
+    if bytes.len() <= debug::BYTES_PREFIX_LEN {
+        return crate::alloc::format!("{bytes:?}");
+    }
+
+    let mut debug = crate::alloc::format!("<{} bytes: ", bytes.len());
+    for byte in &bytes[..debug::BYTES_PREFIX_LEN] {
+        let _ = write!(debug, "{byte:02x}");
+    }
+    debug.push_str("..>");
+    debug
+}
+
 /// Trait for computing the proper [std::fmt::Debug] representation of a value.
 pub trait ProperDebug {
     /// Optionally format a debug representation.
@@ -276,6 +562,235 @@ where
     <T as AsMut<U>>::as_mut(input)
 }
 
+/// Access to the argument at position `N` of a multi-argument input tuple.
+///
+/// Used by `matching!(arg[N] == value)`.
+pub trait TupleArg<const N: usize> {
+    /// The type of the argument
+    type Arg;
+
+    /// Borrow the argument
+    fn arg(&self) -> &Self::Arg;
+}
+
+macro_rules! tuple_arg_impl {
+    ($all:tt, [$($index:tt $t:ident),+]) => {
+        $(tuple_arg_impl!(@arg $all, $index, $t);)+
+    };
+    (@arg [$($all:ident),+], $index:tt, $t:ident) => {
+        impl<$($all),+> TupleArg<$index> for ($($all,)+) {
+            type Arg = $t;
+
+            fn arg(&self) -> &$t {
+                &self.$index
+            }
+        }
+    };
+}
+
+tuple_arg_impl! { [T0, T1], [0 T0, 1 T1] }
+tuple_arg_impl! { [T0, T1, T2], [0 T0, 1 T1, 2 T2] }
+tuple_arg_impl! { [T0, T1, T2, T3], [0 T0, 1 T1, 2 T2, 3 T3] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11, 12 T12] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11, 12 T12, 13 T13] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11, 12 T12, 13 T13, 14 T14] }
+tuple_arg_impl! { [T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15], [0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11, 12 T12, 13 T13, 14 T14, 15 T15] }
+
+#[cfg(feature = "mock-serde")]
+pub use serde_json;
+
+/// Parse a JSON-encoded argument for the `json!` form of `matching!`.
+#[cfg(feature = "mock-serde")]
+pub fn parse_json<T: AsRef<[u8]> + ?Sized>(arg: &T) -> Option<serde_json::Value> {
+    serde_json::from_slice(arg.as_ref()).ok()
+}
+
+/// The formatted message of a `log::Record`, for the `msg_contains!` form of `matching!`.
+#[cfg(feature = "mock-log-0-4")]
+pub struct LogMessage(String);
+
+#[cfg(feature = "mock-log-0-4")]
+impl LogMessage {
+    pub fn of(record: &log_0_4::Record<'_>) -> Self {
+        Self(alloc::format!("{}", record.args()))
+    }
+}
+
+#[cfg(feature = "mock-log-0-4")]
+impl core::fmt::Debug for LogMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// The right hand side of a `msg_contains!` comparison.
+#[cfg(feature = "mock-log-0-4")]
+pub struct Contains<S>(pub S);
+
+#[cfg(feature = "mock-log-0-4")]
+impl<S: AsRef<str>> core::fmt::Debug for Contains<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "msg_contains!({:?})", self.0.as_ref())
+    }
+}
+
+#[cfg(feature = "mock-log-0-4")]
+impl<S: AsRef<str>> PartialEq<Contains<S>> for LogMessage {
+    fn eq(&self, other: &Contains<S>) -> bool {
+        self.0.contains(other.0.as_ref())
+    }
+}
+
+/// Stub factory used by `#[unimock(test_helpers)]`: respond to every call with a default value.
+///
+/// Expressed as a trait so that generated helpers for outputs that can't be defaulted still compile.
+pub trait EachCallReturnsDefault: MockFn {
+    fn each_call_returns_default(self) -> build::Quantify<'static, Self, property::InAnyOrder>;
+}
+
+impl<F> EachCallReturnsDefault for F
+where
+    F: MockFn,
+    F::OutputKind: output::Return,
+    <F::OutputKind as output::Return>::Type: output::ReturnDefault<F::OutputKind>,
+    <F::OutputKind as Kind>::Return: responder::IntoReturner<F>,
+{
+    fn each_call_returns_default(self) -> build::Quantify<'static, Self, property::InAnyOrder> {
+        self.each_call(&|m| m.func(|_, _| true)).returns_default()
+    }
+}
+
+/// Stub factory used by `#[unimock(test_helpers)]`: respond to every call with `value`.
+pub trait EachCallReturns<T>: MockFn {
+    fn each_call_returns(self, value: T) -> build::Quantify<'static, Self, property::InAnyOrder>;
+}
+
+impl<F, T> EachCallReturns<T> for F
+where
+    F: MockFn,
+    T: output::IntoReturn<F::OutputKind>,
+    <F::OutputKind as Kind>::Return: responder::IntoReturner<F>,
+{
+    fn each_call_returns(self, value: T) -> build::Quantify<'static, Self, property::InAnyOrder> {
+        self.each_call(&|m| m.func(|_, _| true)).returns(value)
+    }
+}
+
+/// Probe used by `#[unimock(derive_default_stub)]` to find out whether a MockFn can be stubbed with a default value.
+///
+/// Method resolution picks [DefaultStubOf] when the output implements `Default`, and [NoDefaultStub] otherwise.
+pub struct DefaultStubProbe<F>(core::cell::Cell<Option<F>>);
+
+impl<F> DefaultStubProbe<F> {
+    pub fn new(mock_fn: F) -> Self {
+        Self(core::cell::Cell::new(Some(mock_fn)))
+    }
+}
+
+pub trait DefaultStubOf {
+    fn push_default_stub(&self, clauses: &mut build::Clauses);
+}
+
+impl<F> DefaultStubOf for DefaultStubProbe<F>
+where
+    F: EachCallReturnsDefault,
+{
+    fn push_default_stub(&self, clauses: &mut build::Clauses) {
+        if let Some(mock_fn) = self.0.take() {
+            clauses.push(mock_fn.each_call_returns_default());
+        }
+    }
+}
+
+pub trait NoDefaultStub {
+    fn push_default_stub(&self, _clauses: &mut build::Clauses) {}
+}
+
+impl<F> NoDefaultStub for &DefaultStubProbe<F> {}
+
+/// The clause generated by `#[unimock(derive_default_stub)]`.
+pub fn default_stub(clauses: build::Clauses) -> impl Clause {
+    clause::DefaultStub(clauses)
+}
+
+/// Implemented by the [MockFn] of each `&self` method that can forward its calls to another implementation `T` of the mocked trait,
+/// see [delegates_to](crate::build::DefineResponse::delegates_to).
+pub trait DelegateTo<T>: MockFn {
+    /// An answer function calling the method on `instance`.
+    fn delegate_answer(instance: Arc<T>) -> Arc<Self::AnswerFn>;
+}
+
+/// The serialized output of a call replayed by [Unimock::new_from_file](crate::Unimock::new_from_file).
+pub struct ReplayedOutput(
+    #[cfg(all(feature = "serde", feature = "std"))] pub(crate) serde_json::Value,
+    #[cfg(not(all(feature = "serde", feature = "std")))] core::convert::Infallible,
+);
+
+/// Probe used by generated methods with an owned output to deserialize a [ReplayedOutput].
+///
+/// Method resolution picks [ReplayOutputOf] when the output implements `DeserializeOwned`, and [NoReplayOutput] otherwise.
+pub struct ReplayProbe<F>(
+    core::cell::Cell<Option<ReplayedOutput>>,
+    core::marker::PhantomData<F>,
+);
+
+impl<F: MockFn> ReplayProbe<F> {
+    pub fn new(continuation: Continuation<F>) -> Self {
+        let replayed = match continuation {
+            Continuation::Replay(replayed) => Some(replayed),
+            _ => None,
+        };
+        Self(core::cell::Cell::new(replayed), core::marker::PhantomData)
+    }
+}
+
+pub trait ReplayOutputOf<T> {
+    fn replay(&self, unimock: &Unimock) -> T;
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<F> ReplayOutputOf<<F::OutputKind as output::Return>::Type> for ReplayProbe<F>
+where
+    F: MockFn,
+    F::OutputKind: output::Return,
+    <F::OutputKind as output::Return>::Type: serde::de::DeserializeOwned,
+{
+    fn replay(&self, unimock: &Unimock) -> <F::OutputKind as output::Return>::Type {
+        let ReplayedOutput(value) = self.0.take().expect("replayed output is taken once");
+        unimock.handle_error(serde_json::from_value(value).map_err(|error| {
+            error::MockFailure::CannotReplay {
+                info: F::info(),
+                reason: crate::alloc::format!("{error}").into(),
+            }
+        }))
+    }
+}
+
+pub trait NoReplayOutput<T> {
+    fn replay(&self, unimock: &Unimock) -> T;
+}
+
+impl<F> NoReplayOutput<<F::OutputKind as output::Return>::Type> for &ReplayProbe<F>
+where
+    F: MockFn,
+    F::OutputKind: output::Return,
+{
+    fn replay(&self, unimock: &Unimock) -> <F::OutputKind as output::Return>::Type {
+        unimock.induce_panic(error::MockFailure::CannotReplay {
+            info: F::info(),
+            reason: "the output does not implement `serde::de::DeserializeOwned`".into(),
+        })
+    }
+}
+
 pub struct MutexIsh<T> {
     #[cfg(feature = "std")]
     inner: ::std::sync::Mutex<T>,
@@ -296,7 +811,11 @@ impl<T> MutexIsh<T> {
     }
 
     pub fn locked<U>(&self, func: impl FnOnce(&mut T) -> U) -> U {
-        let mut lock = self.inner.lock().unwrap();
+        // A panic while the lock was held must not mask the original failure.
+        let mut lock = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
         func(&mut *lock)
     }
 }
@@ -327,3 +846,23 @@ impl<T> MutexIsh<T> {
         func(&mut self.inner.borrow_mut())
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutex_ish_recovers_from_poisoning() {
+        let mutex = MutexIsh::new(0);
+        let result = std::panic::catch_unwind(|| mutex.locked(|_| panic!("poison")));
+        assert!(result.is_err());
+
+        assert_eq!(
+            1,
+            mutex.locked(|value| {
+                *value += 1;
+                *value
+            })
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use core::any::TypeId;
+
+use crate::alloc::{Arc, Box, String, ToString, Vec};
+use crate::debug::{FnActualCall, InputsDebug};
+use crate::private::MutexIsh;
+use crate::MockFn;
+
+pub(crate) type CallLog = Arc<MutexIsh<Vec<RecordedCall>>>;
+
+/// A handle to the calls recorded by a [Unimock](crate::Unimock) created with [Unimock::spy_record](crate::Unimock::spy_record).
+///
+/// The handle stays usable after the instance has been dropped.
+pub struct RecordingHandle {
+    call_log: CallLog,
+}
+
+impl RecordingHandle {
+    pub(crate) fn new(call_log: CallLog) -> Self {
+        Self { call_log }
+    }
+
+    /// All calls made so far, by the instance and all its clones, in call order.
+    ///
+    /// Each call is rendered like in unimock's error messages, e.g. `Trait::method(1, "a")`.
+    /// Inputs that don't implement [Debug](core::fmt::Debug) are rendered as `?`.
+    pub fn calls(&self) -> Vec<String> {
+        self.call_log
+            .locked(|calls| calls.iter().map(ToString::to_string).collect())
+    }
+
+    /// The calls made so far to the [MockFn] `F`, in call order.
+    ///
+    /// This makes it possible to verify a spy after the fact,
+    /// instead of setting up expectations up front.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use unimock::*;
+    /// #[unimock(api=FooMock, unmock_with=[real_fetch, real_store])]
+    /// trait Foo {
+    ///     fn fetch(&self, key: &str) -> i32;
+    ///     fn store(&self, key: &str, value: i32);
+    /// }
+    ///
+    /// fn real_fetch(_: &impl std::any::Any, key: &str) -> i32 {
+    ///     key.len() as i32
+    /// }
+    ///
+    /// fn real_store(_: &impl std::any::Any, _: &str, _: i32) {}
+    ///
+    /// let (u, recording) = Unimock::spy_record(());
+    /// u.fetch("a");
+    /// u.store("b", 2);
+    /// u.fetch("cd");
+    ///
+    /// let fetches = recording.calls_of::<FooMock::fetch>();
+    /// assert_eq!(2, fetches.len());
+    /// assert_eq!(&[Some("\"cd\"".to_string())], fetches[1].inputs());
+    /// ```
+    pub fn calls_of<F: MockFn>(&self) -> Vec<RecordedCall> {
+        let type_id = TypeId::of::<F>();
+        self.call_log.locked(|calls| {
+            calls
+                .iter()
+                .filter(|call| call.type_id == type_id)
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+/// A single call recorded by a [RecordingHandle].
+///
+/// Displays like in unimock's error messages, e.g. `Trait::method(1, "a")`.
+#[derive(Clone)]
+pub struct RecordedCall {
+    type_id: TypeId,
+    rendered: String,
+    inputs: Box<[Option<String>]>,
+}
+
+impl RecordedCall {
+    pub(crate) fn new(fn_call: FnActualCall) -> Self {
+        let rendered = fn_call.to_string();
+        let inputs = match fn_call.inputs_debug {
+            InputsDebug::Each(inputs_debug) => inputs_debug,
+            InputsDebug::Formatted(debug) => Box::new([Some(debug)]),
+        };
+
+        Self {
+            type_id: fn_call.info.type_id,
+            rendered,
+            inputs,
+        }
+    }
+
+    /// The [Debug](core::fmt::Debug) rendering of each input of the call.
+    ///
+    /// Inputs that don't implement [Debug](core::fmt::Debug) are `None`.
+    /// When the inputs are rendered by a [with_input_format](crate::MockFn::with_input_format) formatter,
+    /// there is a single element containing the formatted inputs.
+    pub fn inputs(&self) -> &[Option<String>] {
+        &self.inputs
+    }
+}
+
+impl core::fmt::Display for RecordedCall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.rendered)
+    }
+}
+
+impl core::fmt::Debug for RecordedCall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.rendered)
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::alloc::{format, String, Vec};
+use crate::debug::{FnActualCall, InputsDebug};
+use crate::error::MockFailure;
+use crate::private::MutexIsh;
+use crate::MockFnInfo;
+
+/// The serialized calls of one [MockFn](crate::MockFn), as read by [Unimock::new_from_file](crate::Unimock::new_from_file).
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedClause {
+    /// The path of the MockFn, as rendered in unimock's messages, e.g. `my_crate::Trait::method`.
+    pub name: String,
+    /// The calls, which are replayed in order.
+    pub calls: Vec<SerializedCall>,
+}
+
+/// One serialized call of a [SerializedClause].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedCall {
+    /// The debug representation of each input, or `null` for inputs without one.
+    pub inputs: Vec<Option<String>>,
+    /// The output returned by the call.
+    pub output: serde_json::Value,
+}
+
+/// The calls that remain to be replayed, per MockFn path.
+pub(crate) struct Replay {
+    calls: MutexIsh<BTreeMap<String, VecDeque<SerializedCall>>>,
+}
+
+impl Replay {
+    pub fn new(clauses: Vec<SerializedClause>) -> Self {
+        let mut calls: BTreeMap<String, VecDeque<SerializedCall>> = BTreeMap::new();
+        for clause in clauses {
+            calls.entry(clause.name).or_default().extend(clause.calls);
+        }
+
+        Self {
+            calls: MutexIsh::new(calls),
+        }
+    }
+
+    /// Take the next call to replay for the given function, if the fixture has any calls of that function.
+    pub fn next_call(
+        &self,
+        info: &MockFnInfo,
+        fn_call: impl FnOnce() -> FnActualCall,
+    ) -> Option<Result<serde_json::Value, MockFailure>> {
+        let path = format!("{}", info.path);
+        self.calls.locked(|calls| {
+            let remaining = calls.get_mut(&path)?;
+            let fn_call = fn_call();
+
+            let Some(call) = remaining.pop_front() else {
+                return Some(Err(MockFailure::ReplayExhausted { fn_call }));
+            };
+
+            let matches = match &fn_call.inputs_debug {
+                InputsDebug::Each(inputs) => inputs.as_ref() == call.inputs.as_slice(),
+                InputsDebug::Formatted(debug) => {
+                    call.inputs.len() == 1 && call.inputs[0].as_deref() == Some(debug.as_str())
+                }
+            };
+
+            Some(if matches {
+                Ok(call.output)
+            } else {
+                Err(MockFailure::ReplayMismatch {
+                    expected: crate::alloc::Box::new(FnActualCall {
+                        info: *info,
+                        inputs_debug: InputsDebug::Each(call.inputs.into()),
+                    }),
+                    fn_call,
+                })
+            })
+        })
+    }
+
+    /// Report the calls that were never made.
+    pub fn verify(&self, errors: &mut Vec<MockFailure>) {
+        self.calls.locked(|calls| {
+            for (path, remaining) in calls.iter() {
+                if !remaining.is_empty() {
+                    errors.push(MockFailure::FailedVerification(format!(
+                        "{path}: {} replayed call(s) were never made.",
+                        remaining.len()
+                    )));
+                }
+            }
+        });
+    }
+}
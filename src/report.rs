@@ -0,0 +1,96 @@
+use crate::alloc::{String, ToString, Vec};
+use crate::call_pattern::PatIndex;
+use crate::fn_mocker::{FnMocker, PatternMatchMode};
+use crate::state::SharedState;
+
+/// A structured snapshot of how well a [Unimock](crate::Unimock) instance's expectations are met,
+/// created by [Unimock::verification_report](crate::Unimock::verification_report).
+///
+/// It contains the same information as the panic message of a failed verification,
+/// in a form meant for tools that collect test results. With the `serde` feature, it implements `Serialize`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VerificationReport {
+    /// Whether every mocked function is satisfied.
+    pub satisfied: bool,
+    /// One entry for each mocked function, in no particular order.
+    pub mock_fns: Vec<MockFnReport>,
+    /// The calls recorded so far, if the instance was created with [Unimock::spy_record](crate::Unimock::spy_record).
+    pub calls: Option<Vec<String>>,
+}
+
+/// The part of a [VerificationReport] about one mocked function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MockFnReport {
+    /// The path of the mocked function, as shown in error messages.
+    pub path: String,
+    /// Whether the call patterns were set up with [MockFn::next_call](crate::MockFn::next_call).
+    pub in_order: bool,
+    /// Whether all call patterns are satisfied, and the function was called if it had to be.
+    pub satisfied: bool,
+    /// The call patterns of the function, in the order they are tried.
+    pub call_patterns: Vec<CallPatternReport>,
+}
+
+/// The part of a [VerificationReport] about one call pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CallPatternReport {
+    /// The pattern and where it was defined, as shown in error messages.
+    pub pattern: String,
+    /// The [label](crate::build::Quantify::named) of the pattern, if any.
+    pub label: Option<String>,
+    /// The least number of calls the pattern has to match.
+    pub expected_calls: usize,
+    /// Whether the pattern has to match exactly `expected_calls` calls, instead of at least that many.
+    pub exact: bool,
+    /// The number of calls the pattern matched so far.
+    pub actual_calls: usize,
+    /// Whether the number of calls meets the expectation.
+    pub satisfied: bool,
+}
+
+pub(crate) fn verification_report(shared_state: &SharedState) -> VerificationReport {
+    let mock_fns: Vec<_> = shared_state.fn_mockers.iter().map(mock_fn_report).collect();
+
+    VerificationReport {
+        satisfied: mock_fns.iter().all(|mock_fn| mock_fn.satisfied),
+        mock_fns,
+        calls: shared_state.call_log.as_ref().map(|call_log| {
+            call_log.locked(|calls| calls.iter().map(ToString::to_string).collect())
+        }),
+    }
+}
+
+fn mock_fn_report(fn_mocker: &FnMocker) -> MockFnReport {
+    let call_patterns: Vec<_> = fn_mocker
+        .call_patterns
+        .iter()
+        .enumerate()
+        .map(|(pat_index, pattern)| {
+            let counter = &pattern.call_counter;
+            CallPatternReport {
+                pattern: fn_mocker.debug_pattern(PatIndex(pat_index)).to_string(),
+                label: pattern.label.map(ToString::to_string),
+                expected_calls: counter.expectation().lower_bound().0,
+                exact: counter.expectation().exact_calls().is_some(),
+                actual_calls: counter.count(),
+                satisfied: counter.is_satisfied(),
+            }
+        })
+        .collect();
+
+    let total_calls: usize = call_patterns
+        .iter()
+        .map(|pattern| pattern.actual_calls)
+        .sum();
+
+    MockFnReport {
+        path: fn_mocker.info.path.to_string(),
+        in_order: fn_mocker.pattern_match_mode == PatternMatchMode::InOrder,
+        satisfied: call_patterns.iter().all(|pattern| pattern.satisfied)
+            && (total_calls > 0 || fn_mocker.optional),
+        call_patterns,
+    }
+}
@@ -2,8 +2,11 @@
 //!
 //! They may use the [crate::respond] infrastructure.
 
+use core::any::Any;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::{
-    alloc::Box,
+    alloc::{vec, Arc, Box, Vec},
     call_pattern::{downcast_box, PatternResult},
     output::{GetOutput, Kind},
     private::AnswerClosure,
@@ -16,7 +19,59 @@ pub(crate) enum DynResponder {
     Answer(DynAnswerResponder),
     ApplyDefaultImpl,
     Unmock,
+    /// Unmock, then pass the output through an [crate::private::OutputMapper]
+    UnmockMap(Arc<dyn Any + Send + Sync>),
     Panic(Box<str>),
+    /// Responders tried in order, moving on whenever one is unable to respond.
+    FallbackChain(Vec<DynResponder>),
+    /// A responder that is only active while its predicate holds.
+    Conditional(ResponseCondition, Box<DynResponder>),
+    /// Every n-th response is produced by the failure responder, the others by the success responder.
+    Intermittent(IntermittentFailure),
+}
+
+/// State of a [DynResponder::Intermittent].
+pub(crate) struct IntermittentFailure {
+    pub every: usize,
+    pub calls: AtomicUsize,
+    pub failure: Box<DynResponder>,
+    pub success: Box<DynResponder>,
+}
+
+impl IntermittentFailure {
+    /// Count a response, returning the responder that should produce it.
+    pub(crate) fn next_responder(&self) -> &DynResponder {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if call % self.every == 0 {
+            &self.failure
+        } else {
+            &self.success
+        }
+    }
+}
+
+/// Predicate deciding whether a [DynResponder::Conditional] is active.
+pub(crate) struct ResponseCondition(pub Box<dyn Fn() -> bool + Send + Sync + 'static>);
+
+impl DynResponder {
+    /// Append a fallback, turning this responder into a [DynResponder::FallbackChain] if it isn't one already.
+    pub(crate) fn push_fallback(&mut self, fallback: DynResponder) {
+        match self {
+            Self::FallbackChain(chain) => chain.push(fallback),
+            _ => {
+                let primary = core::mem::replace(self, Self::FallbackChain(vec![]));
+                *self = Self::FallbackChain(vec![primary, fallback]);
+            }
+        }
+    }
+
+    /// Whether this responder is currently able to take part in pattern matching.
+    pub(crate) fn is_active(&self) -> bool {
+        match self {
+            Self::Conditional(ResponseCondition(predicate), _) => predicate(),
+            _ => true,
+        }
+    }
 }
 
 /// A responder that returns some value converted into the function's output.
@@ -1,44 +1,297 @@
 use core::any::TypeId;
-use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::{AtomicBool, AtomicUsize};
 
-use crate::alloc::{vec, BTreeMap, Vec};
+use crate::alloc::{vec, BTreeMap, Box, ToString, Vec};
+use crate::assemble::Assembly;
+use crate::call_pattern::PatIndex;
 use crate::debug;
 use crate::error;
-use crate::fn_mocker::{FnMocker, PatternMatchMode};
+use crate::fn_mocker::{Fallback, FallbackMode, FnMocker, PatternMatchMode};
 use crate::private::MutexIsh;
-use crate::FallbackMode;
+use crate::{AnyBox, MockFn, MockFnInfo};
 
 pub(crate) struct SharedState {
     pub fallback_mode: FallbackMode,
-    pub fn_mockers: BTreeMap<TypeId, FnMocker>,
+    pub fallback_modes: BTreeMap<TypeId, Fallback>,
+    /// The maximum length of one input's debug representation, and its per-MockFn overrides
+    max_debug_len: usize,
+    max_debug_lens: BTreeMap<TypeId, usize>,
+    /// Per-MockFn [debug::InputFormat]s
+    input_formats: BTreeMap<TypeId, AnyBox>,
+    /// Sorted by `TypeId`, only mutated through [Unimock::replace](crate::Unimock::replace).
+    pub fn_mockers: Vec<FnMocker>,
+    call_counters: BTreeMap<TypeId, AtomicUsize>,
+    values: BTreeMap<TypeId, AnyBox>,
 
     #[cfg(feature = "std")]
     pub original_thread: std::thread::ThreadId,
 
     next_ordered_call_index: AtomicUsize,
-    pub panic_reasons: MutexIsh<Vec<error::MockError>>,
+    pub panic_reasons: MutexIsh<Vec<error::MockFailure>>,
+
+    /// When set, all calls get recorded here
+    pub call_log: Option<crate::recording::CallLog>,
+
+    /// When set, calls without call patterns are replayed from here
+    #[cfg(all(feature = "serde", feature = "std"))]
+    pub replay: Option<crate::replay::Replay>,
+
+    /// When set, no instance sharing this state verifies anything in `Drop`
+    disarmed: AtomicBool,
+
+    pub unmock_recursion_limit: usize,
+    /// Current number of nested unmocked and forwarded calls per MockFn, when they can't be counted per thread
+    #[cfg(not(feature = "std"))]
+    nesting_depths: MutexIsh<BTreeMap<(TypeId, crate::private::Nesting), usize>>,
 }
 
 impl SharedState {
-    pub fn new(fn_mockers: BTreeMap<TypeId, FnMocker>, fallback_mode: FallbackMode) -> Self {
+    pub fn new(
+        assembly: Assembly,
+        fallback_mode: FallbackMode,
+        counted_fns: impl IntoIterator<Item = TypeId>,
+        unmock_recursion_limit: usize,
+        max_debug_len: usize,
+    ) -> Self {
         Self {
             fallback_mode,
-            fn_mockers,
+            fallback_modes: assembly.fallback_modes,
+            max_debug_len,
+            max_debug_lens: assembly.max_debug_lens,
+            input_formats: assembly.input_formats,
+            fn_mockers: assembly.fn_mockers.into_values().collect(),
+            call_counters: counted_fns
+                .into_iter()
+                .map(|type_id| (type_id, AtomicUsize::new(0)))
+                .collect(),
+            values: BTreeMap::new(),
 
             #[cfg(feature = "std")]
             original_thread: std::thread::current().id(),
 
             next_ordered_call_index: AtomicUsize::new(0),
             panic_reasons: MutexIsh::new(vec![]),
+
+            call_log: None,
+            #[cfg(all(feature = "serde", feature = "std"))]
+            replay: None,
+            disarmed: AtomicBool::new(false),
+
+            unmock_recursion_limit,
+            #[cfg(not(feature = "std"))]
+            nesting_depths: MutexIsh::new(BTreeMap::new()),
         }
     }
 
+    pub fn fn_mocker(&self, type_id: TypeId) -> Option<&FnMocker> {
+        self.fn_mockers
+            .binary_search_by_key(&type_id, |fn_mocker| fn_mocker.info.type_id)
+            .ok()
+            .map(|index| &self.fn_mockers[index])
+    }
+
+    /// Verify the call counts of one MockFn, ignoring all others.
+    pub fn verify_call_counts(&self, type_id: TypeId) -> Vec<error::MockFailure> {
+        let mut errors = Vec::new();
+        if let Some(fn_mocker) = self.fn_mocker(type_id) {
+            fn_mocker.verify_call_counts(&mut errors);
+        }
+        errors
+    }
+
+    /// Forbid further calls to all call patterns whose expectations are already satisfied.
+    pub fn close_satisfied_call_patterns(&self) {
+        for fn_mocker in &self.fn_mockers {
+            fn_mocker.close_satisfied_call_patterns();
+        }
+    }
+
+    /// Resolve the fallback mode of a specific MockFn.
+    ///
+    /// A mode registered explicitly through a clause takes precedence over the instance-wide mode.
+    pub fn fallback_mode_for(&self, info: &MockFnInfo) -> Fallback {
+        match self.fallback_modes.get(&info.type_id) {
+            Some(fallback) => *fallback,
+            None => Fallback::Mode(self.fallback_mode),
+        }
+    }
+
+    /// Resolve the fallback mode of a specific MockFn that has no call patterns.
+    ///
+    /// Unlike [Self::fallback_mode_for], a MockFn that is partial by default is unmocked instead of using the instance-wide mode.
+    pub fn unpatterned_fallback_mode_for(&self, info: &MockFnInfo) -> Fallback {
+        match self.fallback_modes.get(&info.type_id) {
+            Some(fallback) => *fallback,
+            None if info.partial_by_default => Fallback::Mode(FallbackMode::Unmock),
+            None => Fallback::Mode(self.fallback_mode),
+        }
+    }
+
+    /// The maximum length of the debug representation of one input of a specific MockFn.
+    pub fn max_debug_len_for(&self, info: &MockFnInfo) -> usize {
+        self.max_debug_lens
+            .get(&info.type_id)
+            .copied()
+            .unwrap_or(self.max_debug_len)
+    }
+
+    /// Whether a formatter is registered for the inputs of a specific MockFn.
+    pub fn has_input_format(&self, info: &MockFnInfo) -> bool {
+        self.input_formats.contains_key(&info.type_id)
+    }
+
+    /// The formatter registered for the inputs of `F`, if any.
+    pub fn input_format<F: MockFn>(&self) -> Option<&debug::InputFormat<F>> {
+        self.input_formats
+            .get(&TypeId::of::<F>())
+            .and_then(|input_format| input_format.downcast_ref())
+    }
+
+    /// Append the call patterns of the [FnMocker] of `type_id` found in `assembly` to the existing one, if any.
+    pub fn extend_fn_mocker(
+        &mut self,
+        type_id: TypeId,
+        mut assembly: Assembly,
+    ) -> Result<(), error::AssembleError> {
+        let Some(new_fn_mocker) = assembly.fn_mockers.remove(&type_id) else {
+            return Ok(());
+        };
+
+        match self
+            .fn_mockers
+            .binary_search_by_key(&type_id, |fn_mocker| fn_mocker.info.type_id)
+        {
+            Ok(index) => {
+                let fn_mocker = &mut self.fn_mockers[index];
+                if fn_mocker.pattern_match_mode != new_fn_mocker.pattern_match_mode {
+                    return Err(error::AssembleError::IncompatiblePatternMatchMode {
+                        path: fn_mocker.info.path.to_string(),
+                        old_mode: fn_mocker.pattern_match_mode,
+                        new_mode: new_fn_mocker.pattern_match_mode,
+                    });
+                }
+                fn_mocker.optional &= new_fn_mocker.optional;
+                fn_mocker.call_patterns.extend(new_fn_mocker.call_patterns);
+                fn_mocker.update_priority_order();
+            }
+            Err(index) => {
+                self.fn_mockers.insert(index, new_fn_mocker);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replace the [FnMocker], fallback mode and debug settings of one MockFn with the ones found in `assembly`.
+    ///
+    /// Returns the replaced [FnMocker], if there was one.
+    pub fn replace_fn_mocker(
+        &mut self,
+        type_id: TypeId,
+        mut assembly: Assembly,
+    ) -> Option<FnMocker> {
+        self.fallback_modes.remove(&type_id);
+        if let Some(fallback_mode) = assembly.fallback_modes.remove(&type_id) {
+            self.fallback_modes.insert(type_id, fallback_mode);
+        }
+        self.max_debug_lens.remove(&type_id);
+        if let Some(max_debug_len) = assembly.max_debug_lens.remove(&type_id) {
+            self.max_debug_lens.insert(type_id, max_debug_len);
+        }
+        self.input_formats.remove(&type_id);
+        if let Some(input_format) = assembly.input_formats.remove(&type_id) {
+            self.input_formats.insert(type_id, input_format);
+        }
+
+        let new_fn_mocker = assembly.fn_mockers.remove(&type_id);
+
+        match self
+            .fn_mockers
+            .binary_search_by_key(&type_id, |fn_mocker| fn_mocker.info.type_id)
+        {
+            Ok(index) => match new_fn_mocker {
+                Some(fn_mocker) => Some(core::mem::replace(&mut self.fn_mockers[index], fn_mocker)),
+                None => Some(self.fn_mockers.remove(index)),
+            },
+            Err(index) => {
+                if let Some(fn_mocker) = new_fn_mocker {
+                    self.fn_mockers.insert(index, fn_mocker);
+                }
+                None
+            }
+        }
+    }
+
+    pub fn disarm(&self) {
+        self.disarmed
+            .store(true, core::sync::atomic::Ordering::SeqCst);
+    }
+
+    pub fn is_disarmed(&self) -> bool {
+        self.disarmed.load(core::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Register a call to a MockFn, if calls to it are being counted.
+    pub fn count_call(&self, info: &MockFnInfo) {
+        if let Some(counter) = self.call_counters.get(&info.type_id) {
+            counter.fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    /// Record a call, if calls are being recorded.
+    pub fn log_call(&self, fn_call: impl FnOnce() -> debug::FnActualCall) {
+        if let Some(call_log) = &self.call_log {
+            let recorded_call = crate::recording::RecordedCall::new(fn_call());
+            call_log.locked(|calls| calls.push(recorded_call));
+        }
+    }
+
+    pub fn call_count(&self, type_id: TypeId) -> Option<usize> {
+        self.call_counters
+            .get(&type_id)
+            .map(|counter| counter.load(core::sync::atomic::Ordering::SeqCst))
+    }
+
+    pub fn insert_value<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    pub fn get_value<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    pub fn ordered_call_index(&self) -> usize {
+        self.next_ordered_call_index
+            .load(core::sync::atomic::Ordering::SeqCst)
+    }
+
     pub fn bump_ordered_call_index(&self) -> usize {
         self.next_ordered_call_index
             .fetch_add(1, core::sync::atomic::Ordering::SeqCst)
     }
 
-    pub fn clone_panic_reasons(&self) -> Vec<error::MockError> {
+    /// Enter a nested call to a MockFn, returning the new nesting depth.
+    #[cfg(not(feature = "std"))]
+    pub fn enter_nesting(&self, key: (TypeId, crate::private::Nesting)) -> usize {
+        self.nesting_depths.locked(|depths| {
+            let depth = depths.entry(key).or_insert(0);
+            *depth += 1;
+            *depth
+        })
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn exit_nesting(&self, key: (TypeId, crate::private::Nesting)) {
+        self.nesting_depths.locked(|depths| {
+            if let Some(depth) = depths.get_mut(&key) {
+                *depth = depth.saturating_sub(1);
+            }
+        })
+    }
+
+    pub fn clone_panic_reasons(&self) -> Vec<error::MockFailure> {
         self.panic_reasons.locked(|reasons| reasons.clone())
     }
 
@@ -46,7 +299,7 @@ impl SharedState {
         &self,
         ordered_call_index: usize,
     ) -> Option<debug::CallPatternDebug> {
-        self.fn_mockers.values().find_map(|fn_mocker| {
+        self.fn_mockers.iter().find_map(|fn_mocker| {
             if fn_mocker.pattern_match_mode != PatternMatchMode::InOrder {
                 return None;
             }
@@ -56,4 +309,34 @@ impl SharedState {
             Some(fn_mocker.debug_pattern(pat_index))
         })
     }
+
+    /// The ordered call patterns that come before the given call index, in the order they were matched.
+    pub fn find_ordered_matched_call_patterns_debug(
+        &self,
+        ordered_call_index: usize,
+    ) -> Box<[debug::CallPatternDebug]> {
+        let mut matched: Vec<_> = self
+            .fn_mockers
+            .iter()
+            .filter(|fn_mocker| fn_mocker.pattern_match_mode == PatternMatchMode::InOrder)
+            .flat_map(|fn_mocker| {
+                fn_mocker
+                    .call_patterns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, pattern)| {
+                        pattern.ordered_call_index_range.end <= ordered_call_index
+                    })
+                    .map(|(pat_index, pattern)| {
+                        (
+                            pattern.ordered_call_index_range.start,
+                            fn_mocker.debug_pattern(PatIndex(pat_index)),
+                        )
+                    })
+            })
+            .collect();
+        matched.sort_by_key(|(start, _)| *start);
+
+        matched.into_iter().map(|(_, debug)| debug).collect()
+    }
 }
@@ -0,0 +1,145 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::alloc::{format, Arc, BTreeMap};
+use crate::clause::{DefaultStub, DefinedAt};
+use crate::error::MockFailure;
+use crate::private::MutexIsh;
+use crate::{error, Clause, MockFn, Unimock};
+
+/// A clause making the getter `get` return what the setter `set` stored for the same key.
+///
+/// `set` must take a key and a value, and `get` must take a key and return something convertible from a value,
+/// e.g. the value itself or an [Option] of it.
+/// Keys and values must be owned.
+/// Every call to either function is answered through a map shared by all clones of the [Unimock] instance.
+/// Like a default stub, other clauses for the two functions take precedence, and neither function has to be called.
+///
+/// By default, getting a key that was never set is a mock failure. See [StateMap] for the alternatives.
+///
+/// # Example
+/// ```rust
+/// # use unimock::*;
+/// #[unimock(api=ConfigMock)]
+/// trait Config {
+///     fn set(&self, key: String, value: i32);
+///     fn get(&self, key: String) -> Option<i32>;
+/// }
+///
+/// let u = Unimock::new(state_map(ConfigMock::set, ConfigMock::get).default_value(None));
+///
+/// assert_eq!(None, u.get("answer".to_string()));
+/// u.set("answer".to_string(), 42);
+/// assert_eq!(Some(42), u.get("answer".to_string()));
+/// ```
+#[track_caller]
+pub fn state_map<S, G, K, V, O>(set: S, get: G) -> StateMap<S, G, K, V, O>
+where
+    S: MockFn<AnswerFn = dyn Fn(&Unimock, K, V) + Send + Sync>,
+    G: MockFn<AnswerFn = dyn Fn(&Unimock, K) -> O + Send + Sync>,
+    K: Ord + Debug + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    O: From<V> + Clone + Send + Sync + 'static,
+{
+    StateMap {
+        set,
+        get,
+        default_value: None,
+        record_unknown_keys: false,
+        defined_at: core::panic::Location::caller(),
+        types: PhantomData,
+    }
+}
+
+/// A setter and a getter backed by a shared map, created by [state_map].
+pub struct StateMap<S, G, K, V, O> {
+    set: S,
+    get: G,
+    default_value: Option<O>,
+    record_unknown_keys: bool,
+    defined_at: &'static core::panic::Location<'static>,
+    types: PhantomData<fn(K, V)>,
+}
+
+impl<S, G, K, V, O> StateMap<S, G, K, V, O>
+where
+    S: MockFn<AnswerFn = dyn Fn(&Unimock, K, V) + Send + Sync>,
+    G: MockFn<AnswerFn = dyn Fn(&Unimock, K) -> O + Send + Sync>,
+    K: Ord + Debug + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    O: From<V> + Clone + Send + Sync + 'static,
+{
+    /// Make the getter return `value` for keys that were never set, instead of failing.
+    pub fn default_value(mut self, value: O) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Report getting a key that was never set when the [Unimock] instance is verified, instead of failing the call.
+    ///
+    /// The call itself returns the [default_value](Self::default_value).
+    /// Without a default value, such a call fails either way.
+    pub fn record_unknown_keys(mut self) -> Self {
+        self.record_unknown_keys = true;
+        self
+    }
+}
+
+impl<S, G, K, V, O> Clause for StateMap<S, G, K, V, O>
+where
+    S: MockFn<AnswerFn = dyn Fn(&Unimock, K, V) + Send + Sync>,
+    G: MockFn<AnswerFn = dyn Fn(&Unimock, K) -> O + Send + Sync>,
+    K: Ord + Debug + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    O: From<V> + Clone + Send + Sync + 'static,
+{
+    fn deconstruct(
+        self,
+        sink: &mut dyn crate::clause::term::Sink,
+    ) -> Result<(), error::AssembleError> {
+        let map: Arc<MutexIsh<BTreeMap<K, V>>> = Arc::new(MutexIsh::new(BTreeMap::new()));
+
+        let set_map = map.clone();
+        let set_answer: Arc<S::AnswerFn> = Arc::new(move |_, key, value| {
+            set_map.locked(|map| {
+                map.insert(key, value);
+            });
+        });
+
+        let default_value = self.default_value;
+        let record_unknown_keys = self.record_unknown_keys;
+        let get_answer: Arc<G::AnswerFn> = Arc::new(move |unimock, key| {
+            if let Some(value) = map.locked(|map| map.get(&key).cloned()) {
+                return O::from(value);
+            }
+
+            let failure = MockFailure::UnknownStateKey {
+                info: G::info(),
+                key: format!("{key:?}"),
+            };
+            match &default_value {
+                Some(default_value) => {
+                    if record_unknown_keys {
+                        unimock.record_failure(failure);
+                    }
+                    default_value.clone()
+                }
+                None => unimock.induce_panic(failure),
+            }
+        });
+
+        // Not using `matching!`, which would point error messages at this function instead of the caller
+        DefaultStub(DefinedAt {
+            clause: (
+                self.set
+                    .each_call(&|matching| matching.func(|_, _| true))
+                    .answers_arc(set_answer),
+                self.get
+                    .each_call(&|matching| matching.func(|_, _| true))
+                    .answers_arc(get_answer),
+            ),
+            defined_at: self.defined_at,
+        })
+        .deconstruct(sink)
+    }
+}
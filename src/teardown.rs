@@ -1,12 +1,13 @@
 use crate::alloc::{Arc, ToString, Vec};
-use crate::{error::MockError, Unimock};
+use crate::state::SharedState;
+use crate::{error::MockFailure, Unimock};
 
 #[track_caller]
 pub(crate) fn teardown_panic(unimock: &mut Unimock) {
     if let Err(errors) = teardown(unimock) {
         let error_strings = errors
             .iter()
-            .map(<MockError as ToString>::to_string)
+            .map(<MockFailure as ToString>::to_string)
             .collect::<Vec<_>>();
         panic!("{}", error_strings.join("\n"));
     }
@@ -26,7 +27,7 @@ pub(crate) fn teardown_report(unimock: &mut Unimock) -> std::process::ExitCode {
 }
 
 #[track_caller]
-pub(crate) fn teardown(unimock: &mut Unimock) -> Result<(), Vec<MockError>> {
+pub(crate) fn teardown(unimock: &mut Unimock) -> Result<(), Vec<MockFailure>> {
     unimock.torn_down = true;
 
     // first potentially drop the directly owned "helper" Unimock instance
@@ -64,20 +65,30 @@ pub(crate) fn teardown(unimock: &mut Unimock) -> Result<(), Vec<MockError>> {
         panic!("Original Unimock instance destroyed on a different thread than the one it was created on. To solve this, clone the object before sending it to the other thread.");
     }
 
+    verify_state(&unimock.shared_state)
+}
+
+/// Verify the calls recorded in the shared state, independent of any instance.
+pub(crate) fn verify_state(shared_state: &SharedState) -> Result<(), Vec<MockFailure>> {
     {
         // if already in error state, it must be from another thread. Forward those errors to the original thread.
         // (if original is even still in the original thread.. But report as close to the test "root" as possible)
-        let panic_reasons = unimock.shared_state.clone_panic_reasons();
+        let panic_reasons = shared_state.clone_panic_reasons();
         if !panic_reasons.is_empty() {
             return Err(panic_reasons);
         }
     }
 
     let mut mock_errors = Vec::new();
-    for (_, fn_mocker) in unimock.shared_state.fn_mockers.iter() {
+    for fn_mocker in shared_state.fn_mockers.iter() {
         fn_mocker.verify(&mut mock_errors);
     }
 
+    #[cfg(all(feature = "serde", feature = "std"))]
+    if let Some(replay) = &shared_state.replay {
+        replay.verify(&mut mock_errors);
+    }
+
     if mock_errors.is_empty() {
         Ok(())
     } else {
@@ -0,0 +1,91 @@
+use core::future::Future;
+use core::task::{Poll, Waker};
+use std::sync::{Condvar, Mutex};
+
+use crate::alloc::{Arc, Vec};
+use crate::state::SharedState;
+use crate::{teardown, MockError};
+
+/// A handle for verifying a [Unimock](crate::Unimock) after all of its instances have been dropped.
+///
+/// Created by [Unimock::new_with_verify_handle](crate::Unimock::new_with_verify_handle).
+///
+/// The instance returned alongside the handle does not verify anything when dropped.
+/// Instead, the handle waits until that instance and all of its clones are gone, and then verifies the calls made through them.
+/// This removes the race between mocked calls made in spawned tasks or threads and the end of the test.
+pub struct VerifyHandle {
+    shared_state: Arc<SharedState>,
+    notifier: Arc<DropNotifier>,
+}
+
+impl VerifyHandle {
+    pub(crate) fn new(shared_state: Arc<SharedState>, notifier: Arc<DropNotifier>) -> Self {
+        Self {
+            shared_state,
+            notifier,
+        }
+    }
+
+    /// Wait asynchronously for all instances to be dropped, then verify.
+    ///
+    /// The returned future does not depend on any particular async runtime.
+    pub fn join(self) -> impl Future<Output = Result<(), Vec<MockError>>> + Send {
+        core::future::poll_fn(move |cx| {
+            let mut wakers = self.notifier.wakers.lock().unwrap();
+            if self.all_dropped() {
+                Poll::Ready(self.verify())
+            } else {
+                wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    /// Block the current thread until all instances have been dropped, then verify.
+    pub fn join_blocking(self) -> Result<(), Vec<MockError>> {
+        let mut wakers = self.notifier.wakers.lock().unwrap();
+        while !self.all_dropped() {
+            wakers = self.notifier.condvar.wait(wakers).unwrap();
+        }
+        drop(wakers);
+
+        self.verify()
+    }
+
+    fn all_dropped(&self) -> bool {
+        // Only the handle itself is left.
+        Arc::strong_count(&self.shared_state) == 1
+    }
+
+    fn verify(&self) -> Result<(), Vec<MockError>> {
+        teardown::verify_state(&self.shared_state)
+            .map_err(|failures| failures.into_iter().map(MockError::new).collect())
+    }
+}
+
+/// Signals [VerifyHandle]s every time an instance gets dropped.
+#[derive(Default)]
+pub(crate) struct DropNotifier {
+    wakers: Mutex<Vec<Waker>>,
+    condvar: Condvar,
+}
+
+/// Owned by each instance, and declared after its shared state,
+/// so that the notification happens _after_ its reference count has been decremented.
+pub(crate) struct NotifyOnDrop(pub Arc<DropNotifier>);
+
+impl Clone for NotifyOnDrop {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for NotifyOnDrop {
+    fn drop(&mut self) {
+        let wakers = core::mem::take(&mut *self.0.wakers.lock().unwrap());
+        self.0.condvar.notify_all();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
@@ -8,8 +8,9 @@ cargo hack --feature-powerset \
     --at-least-one-of std,critical-section \
     --mutually-exclusive-features std,critical-section \
     --mutually-exclusive-features std,spin-lock \
-    --group-features mock-std,mock-tokio-1,mock-futures-io-0-3 \
+    --group-features mock-std,mock-tokio-1,mock-futures-core-0-3,mock-futures-io-0-3 \
     --group-features critical-section,spin-lock \
     --exclude-features nightly-tests,unstable-doc-cfg \
     test
+cargo check --no-default-features --features critical-section
 cargo test --doc --features mock-core,mock-std
@@ -58,3 +58,292 @@ mod rpit_future {
         .test()
     }
 }
+
+#[cfg(feature = "mock-tokio-1")]
+#[rustversion::since(1.75)]
+mod delayed_response {
+    use std::time::{Duration, Instant};
+
+    use unimock::*;
+
+    use crate::AsyncTest;
+
+    #[unimock(api = DelayMock)]
+    trait Delay {
+        async fn fetch(&self, arg: i32) -> i32;
+        fn fetch_sync(&self) -> i32;
+    }
+
+    #[test]
+    fn delayed_response_is_awaited() {
+        async {
+            let u = Unimock::new(
+                DelayMock::fetch
+                    .each_call(matching!(_))
+                    .returns(42)
+                    .with_delay(Duration::from_millis(20)),
+            );
+
+            let start = Instant::now();
+            assert_eq!(42, u.fetch(1).await);
+            assert!(start.elapsed() >= Duration::from_millis(20));
+        }
+        .test()
+    }
+
+    #[test]
+    fn delayed_response_can_time_out() {
+        async {
+            let u = Unimock::new(
+                DelayMock::fetch
+                    .next_call(matching!(_))
+                    .returns(42)
+                    .once()
+                    .with_delay(Duration::from_secs(10))
+                    .then_returns(1337),
+            );
+
+            assert!(
+                tokio_1::time::timeout(Duration::from_millis(10), u.fetch(1))
+                    .await
+                    .is_err()
+            );
+            assert_eq!(1337, u.fetch(2).await);
+        }
+        .test()
+    }
+
+    #[test]
+    fn delay_follows_paused_time() {
+        let u = Unimock::new(
+            DelayMock::fetch
+                .each_call(matching!(_))
+                .returns(42)
+                .with_delay(Duration::from_secs(3600)),
+        );
+
+        let start = Instant::now();
+        tokio_1::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap()
+            .block_on(async {
+                let paused_start = tokio_1::time::Instant::now();
+                assert_eq!(42, u.fetch(1).await);
+                assert!(paused_start.elapsed() >= Duration::from_secs(3600));
+            });
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::async_fn::delayed_response::Delay::fetch_sync has a delayed response, but a delay can only be awaited by async methods without a &mut self or Pin receiver."
+    )]
+    fn delay_is_refused_in_sync_methods() {
+        let u = Unimock::new(
+            DelayMock::fetch_sync
+                .each_call(matching!())
+                .returns(42)
+                .with_delay(Duration::from_millis(20)),
+        );
+
+        u.fetch_sync();
+    }
+
+    #[test]
+    fn sleep_then_value_only_delays_slow_calls() {
+        async {
+            let u = Unimock::new((
+                DelayMock::fetch
+                    .next_call(matching!(1))
+                    .answers_with_sleep_then_value(Duration::from_secs(10), 42),
+                DelayMock::fetch.next_call(matching!(2)).returns(1337),
+            ));
+
+            assert!(
+                tokio_1::time::timeout(Duration::from_millis(10), u.fetch(1))
+                    .await
+                    .is_err()
+            );
+            assert_eq!(
+                Ok(1337),
+                tokio_1::time::timeout(Duration::from_millis(10), u.fetch(2)).await
+            );
+        }
+        .test()
+    }
+
+    #[test]
+    fn sleep_then_value_for_each_call() {
+        async {
+            let u = Unimock::new(
+                DelayMock::fetch
+                    .each_call(matching!(_))
+                    .answers_with_sleep_then_value(Duration::from_millis(20), 42),
+            );
+
+            let start = Instant::now();
+            assert_eq!(42, u.fetch(1).await);
+            assert_eq!(42, u.fetch(2).await);
+            assert!(start.elapsed() >= Duration::from_millis(40));
+        }
+        .test()
+    }
+}
+
+#[rustversion::since(1.75)]
+mod unmock_native_async {
+    use unimock::*;
+
+    use crate::AsyncTest;
+
+    #[unimock(api = ShopMock, unmock_with = [
+        real_total,
+        _,
+        |_, code| async move { code.len() as u32 }
+    ])]
+    trait Shop {
+        async fn total(&self, items: u32) -> u32;
+        async fn price(&self) -> u32;
+        fn discount(&self, code: &str) -> impl core::future::Future<Output = u32>;
+    }
+
+    async fn real_total(shop: &impl Shop, items: u32) -> u32 {
+        shop.price().await * items - shop.discount("ab").await
+    }
+
+    #[test]
+    fn unmocked_async_fn_calls_back_into_mocked_methods() {
+        async {
+            let u = Unimock::new_partial(ShopMock::price.next_call(matching!()).returns(10_u32));
+
+            assert_eq!(28, u.total(3).await);
+        }
+        .test()
+    }
+}
+
+#[rustversion::since(1.75)]
+mod pending_times {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    use unimock::*;
+
+    use crate::AsyncTest;
+
+    #[unimock(api = FooMock)]
+    trait Foo {
+        async fn fetch(&self, id: u32) -> u32;
+    }
+
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn pending_polls_wake_the_task_before_resolving() {
+        let u = Unimock::new(
+            FooMock::fetch
+                .next_call(matching!(1))
+                .pending_times(2)
+                .then_resolves(42_u32)
+                .once(),
+        );
+        let counting_waker = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counting_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = pin!(u.fetch(1));
+        assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        assert_eq!(1, counting_waker.0.load(Ordering::SeqCst));
+        assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        assert_eq!(2, counting_waker.0.load(Ordering::SeqCst));
+        assert_eq!(Poll::Ready(42), future.as_mut().poll(&mut cx));
+        assert_eq!(2, counting_waker.0.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn pending_response_resolves_when_awaited() {
+        async {
+            let u = Unimock::new(
+                FooMock::fetch
+                    .each_call(matching!(_))
+                    .pending_times(3)
+                    .then_resolves(7_u32)
+                    .n_times(2),
+            );
+
+            assert_eq!(7, u.fetch(1).await);
+            assert_eq!(7, u.fetch(2).await);
+        }
+        .test()
+    }
+
+    #[test]
+    fn dropping_an_unresolved_future_is_allowed_by_default() {
+        let u = Unimock::new(
+            FooMock::fetch
+                .next_call(matching!(1))
+                .pending_times(1)
+                .then_resolves(42_u32)
+                .once(),
+        );
+        let mut cx = Context::from_waker(futures_util_0_3::task::noop_waker_ref());
+
+        let mut future = Box::pin(u.fetch(1));
+        assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        drop(future);
+    }
+
+    #[test]
+    fn dropping_an_unresolved_future_that_must_resolve_is_reported() {
+        let mut u = Unimock::new(
+            FooMock::fetch
+                .next_call(matching!(1))
+                .pending_times(1)
+                .must_resolve()
+                .then_resolves(42_u32)
+                .once(),
+        );
+        let mut cx = Context::from_waker(futures_util_0_3::task::noop_waker_ref());
+
+        let mut future = Box::pin(u.fetch(1));
+        assert_eq!(Poll::Pending, future.as_mut().poll(&mut cx));
+        drop(future);
+
+        let errors = u.drain_errors();
+        assert_eq!(1, errors.len());
+        assert_eq!(
+            "The future for it::async_fn::pending_times::Foo::fetch was dropped unresolved.",
+            errors[0].to_string()
+        );
+    }
+
+    #[test]
+    fn resolved_future_that_must_resolve_is_not_reported() {
+        async {
+            let u = Unimock::new(
+                FooMock::fetch
+                    .next_call(matching!(1))
+                    .pending_times(1)
+                    .must_resolve()
+                    .then_resolves(42_u32)
+                    .once(),
+            );
+
+            assert_eq!(42, u.fetch(1).await);
+        }
+        .test()
+    }
+}
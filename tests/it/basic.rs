@@ -415,7 +415,7 @@ mod custom_api_module {
     }
 
     #[test]
-    #[should_panic = "Single::func: Expected Single::func(_) at tests/it/basic.rs:422 to match exactly 1 call, but it actually matched no calls.\nMock for Single::func was never called. Dead mocks should be removed."]
+    #[should_panic = "it::basic::custom_api_module::Single::func: Expected it::basic::custom_api_module::Single::func(_) at tests/it/basic.rs:422 to match exactly 1 call, but it actually matched no calls.\nMock for it::basic::custom_api_module::Single::func was never called. Dead mocks should be removed."]
     fn test_without_module() {
         Unimock::new(
             FakeSingle::func
@@ -672,7 +672,7 @@ mod responders_in_series {
 
     #[test]
     #[should_panic(
-        expected = "Series::series: Expected Series::series() at tests/it/basic.rs:648 to match at least 4 calls, but it actually matched 2 calls."
+        expected = "it::basic::responders_in_series::Series::series: Expected it::basic::responders_in_series::Series::series() at tests/it/basic.rs:648 to match at least 4 calls, but it actually matched 2 calls."
     )]
     fn series_not_fully_generated_should_panic() {
         let b = Unimock::new(clause());
@@ -682,6 +682,23 @@ mod responders_in_series {
 
         // Exact repetition was defined to be 4 (the last responder is not exactly quantified), but it contained a `.then` call so minimum 1.
     }
+
+    #[test]
+    fn then_returns_shorthand() {
+        let u = Unimock::new(
+            SeriesMock::series
+                .each_call(matching!())
+                .returns(1)
+                .once()
+                .then_returns(2)
+                .then_returns_n_times(3, 2),
+        );
+
+        assert_eq!(1, u.series());
+        assert_eq!(2, u.series());
+        assert_eq!(3, u.series());
+        assert_eq!(3, u.series());
+    }
 }
 
 #[unimock(api=BorrowStaticMock)]
@@ -904,7 +921,7 @@ mod fn_cfg_attrs {
     trait Trait {
         fn a(&self) -> i32;
 
-        #[cfg(feature = "always-disabled")]
+        #[cfg(any())]
         fn b(&self) -> NonExistentType;
     }
 
@@ -1192,3 +1209,1554 @@ mod mut_output {
         }
     }
 }
+
+mod large_outputs_are_stored_once {
+    use unimock::alloc::{vec, Arc, Vec};
+    use unimock::*;
+
+    #[unimock(api=BlobMock)]
+    trait Blob {
+        fn shared(&self) -> Arc<Vec<u8>>;
+        fn lent(&self) -> &[u8];
+    }
+
+    #[test]
+    fn arc_output_is_converted_once() {
+        let u = Unimock::new(
+            BlobMock::shared
+                .each_call(matching!())
+                .returns(vec![0_u8; 1024]),
+        );
+        assert!(Arc::ptr_eq(&u.shared(), &u.shared()));
+    }
+
+    #[test]
+    fn lent_output_is_not_cloned() {
+        let u = Unimock::new(
+            BlobMock::lent
+                .each_call(matching!())
+                .returns(vec![0_u8; 1024]),
+        );
+        assert_eq!(u.lent().as_ptr(), u.lent().as_ptr());
+    }
+}
+
+mod each_from_fn {
+    use unimock::*;
+
+    #[unimock(api=FooMock)]
+    trait Foo {
+        fn foo(&self, arg: i32) -> i32;
+    }
+
+    #[test]
+    fn from_fn_is_equivalent_to_stub() {
+        let u = Unimock::new(build::Each::<FooMock::foo>::from_fn(|each| {
+            each.call(matching!(1)).returns(2);
+            each.call(matching!(_)).returns(0);
+        }));
+
+        assert_eq!(2, u.foo(1));
+        assert_eq!(0, u.foo(42));
+    }
+}
+
+#[cfg(feature = "std")]
+mod catch_unwind {
+    use std::panic::catch_unwind;
+
+    use unimock::*;
+
+    #[unimock(api=FooMock)]
+    trait Foo {
+        fn foo(&self, arg: i32) -> i32;
+    }
+
+    fn clause() -> impl Clause {
+        FooMock::foo.stub(|each| {
+            each.call(matching!(0)).panics("zero").once();
+            each.call(matching!(_)).returns(1).once();
+        })
+    }
+
+    #[test]
+    fn instance_is_usable_after_caught_explicit_panic() {
+        let u = Unimock::new(clause());
+
+        assert!(catch_unwind(|| u.foo(0)).is_err());
+        assert_eq!(1, u.foo(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::basic::catch_unwind::Foo::foo: Expected it::basic::catch_unwind::Foo::foo(_) at tests/it/basic.rs:1278 to match exactly 1 call, but it actually matched no calls."
+    )]
+    fn verification_runs_after_caught_explicit_panic() {
+        let u = Unimock::new(clause());
+
+        assert!(catch_unwind(|| u.foo(0)).is_err());
+    }
+
+    #[test]
+    fn instance_keeps_working_after_caught_responder_panic() {
+        let u = Unimock::new(FooMock::foo.stub(|each| {
+            each.call(matching!(0))
+                .answers(&|_, _| panic!("responder panicked"))
+                .once();
+            each.call(matching!(_)).returns(1).once();
+        }));
+
+        let result = catch_unwind(|| u.foo(0));
+        assert_eq!(
+            Some(&"responder panicked"),
+            result.unwrap_err().downcast_ref::<&str>()
+        );
+
+        assert_eq!(1, u.foo(1));
+        u.verify();
+    }
+}
+
+mod diverging {
+    use unimock::alloc::{String, ToString};
+    use unimock::*;
+
+    #[unimock(api=AbortMock)]
+    trait Abort {
+        fn abort(&self, code: i32) -> !;
+        fn abort_mut(&mut self, msg: &mut String) -> !;
+    }
+
+    #[test]
+    #[should_panic(expected = "out of fuel")]
+    fn panics_with_custom_message() {
+        Unimock::new(
+            AbortMock::abort
+                .next_call(matching!(1))
+                .panics("out of fuel"),
+        )
+        .abort(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "aborted with 42")]
+    fn answers_can_diverge() {
+        Unimock::new(
+            AbortMock::abort
+                .next_call(matching!(_))
+                .answers(&|_, code| panic!("aborted with {code}")),
+        )
+        .abort(42);
+    }
+
+    #[test]
+    #[should_panic(expected = "aborted: fatal")]
+    fn mut_self_answers_can_diverge() {
+        Unimock::new(
+            AbortMock::abort_mut
+                .next_call(matching!(_))
+                .answers(&|_, msg| panic!("aborted: {msg}")),
+        )
+        .abort_mut(&mut "fatal".to_string());
+    }
+}
+
+mod fallback_chain {
+    use super::*;
+
+    #[unimock(api=CacheMock)]
+    trait Cache {
+        fn get(&self, key: &str) -> String;
+    }
+
+    #[test]
+    fn falls_back_after_return_once_value_is_taken() {
+        let u = Unimock::new(
+            CacheMock::get
+                .some_call(matching!(_))
+                .returns("cached")
+                .with_fallback()
+                .answers(&|_, key| format!("fresh {key}"))
+                .n_times(3),
+        );
+
+        assert_eq!("cached", u.get("a"));
+        assert_eq!("fresh b", u.get("b"));
+        assert_eq!("fresh c", u.get("c"));
+    }
+
+    #[test]
+    fn skips_panicking_responders() {
+        let u = Unimock::new(
+            CacheMock::get
+                .each_call(matching!(_))
+                .panics("unavailable")
+                .with_fallback()
+                .returns("value"),
+        );
+
+        assert_eq!("value", u.get("a"));
+        assert_eq!("value", u.get("b"));
+    }
+
+    #[test]
+    fn chain_is_a_single_step_in_an_output_sequence() {
+        let u = Unimock::new(
+            CacheMock::get
+                .next_call(matching!(_))
+                .returns("first")
+                .with_fallback()
+                .returns("second")
+                .once()
+                .then()
+                .returns("third"),
+        );
+
+        assert_eq!("first", u.get("a"));
+        assert_eq!("third", u.get("b"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::basic::fallback_chain::Cache::get(\"b\"): Explicit panic from it::basic::fallback_chain::Cache::get(_)"
+    )]
+    fn last_responder_reports_failure() {
+        let u = Unimock::new(
+            CacheMock::get
+                .some_call(matching!(_))
+                .returns("cached")
+                .with_fallback()
+                .panics("unavailable")
+                .at_least_times(1),
+        );
+
+        assert_eq!("cached", u.get("a"));
+        u.get("b");
+    }
+}
+
+mod smart_pointer_receivers {
+    use super::*;
+    use unimock::alloc::Arc;
+
+    #[unimock(api=SmartMock, unmock_with=[unmocked_boxed, _])]
+    trait Smart {
+        fn boxed(self: Box<Self>, input: i32) -> i32;
+        fn arced(self: Arc<Self>, input: i32) -> i32;
+    }
+
+    fn unmocked_boxed(_: Box<impl Smart>, input: i32) -> i32 {
+        input * 10
+    }
+
+    #[test]
+    fn box_and_arc_self() {
+        let u = Unimock::new((
+            SmartMock::boxed.next_call(matching!(1)).returns(2),
+            SmartMock::boxed.next_call(matching!(2)).applies_unmocked(),
+            SmartMock::arced
+                .next_call(matching!(3))
+                .answers(&|_, input| input + 1),
+        ));
+
+        assert_eq!(2, Box::new(u.clone()).boxed(1));
+        assert_eq!(20, Box::new(u.clone()).boxed(2));
+        assert_eq!(4, Arc::new(u).arced(3));
+    }
+}
+
+mod disarm {
+    use unimock::*;
+
+    #[unimock(api = TraitMock)]
+    trait Trait {
+        fn foo(&self);
+    }
+
+    fn mock() -> Unimock {
+        Unimock::new(TraitMock::foo.next_call(matching!()).returns(()))
+    }
+
+    #[test]
+    fn disarmed_original_does_not_verify_in_drop() {
+        mock().disarm();
+    }
+
+    #[test]
+    fn disarm_through_clone_is_shared() {
+        let u = mock();
+        let clone = u.clone();
+        clone.disarm();
+
+        // the clone outlives the original, which would normally panic too
+        drop(u);
+        drop(clone);
+    }
+
+    #[test]
+    #[should_panic = "actually matched no calls"]
+    fn explicit_verify_still_works() {
+        let u = mock();
+        u.disarm();
+        u.verify();
+    }
+}
+
+mod track_inputs {
+    use unimock::alloc::{vec, String, ToString};
+    use unimock::*;
+
+    #[unimock(api = TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32, b: String) -> bool;
+    }
+
+    #[test]
+    fn records_inputs_of_all_patterns() {
+        let mut each = TraitMock::foo.stub(|each| {
+            each.call(matching!(1, _)).returns(true);
+        });
+        let tracker = each.track_inputs();
+        each.call(matching!(_, _)).returns(false);
+
+        let u = Unimock::new(each);
+        assert!(u.foo(1, "a".to_string()));
+        assert!(!u.clone().foo(2, "b".to_string()));
+
+        tracker.assert_called_with(&(2, "b".to_string()));
+        assert_eq!(
+            vec![(1, "a".to_string()), (2, "b".to_string())],
+            tracker.all_calls()
+        );
+    }
+
+    #[test]
+    #[should_panic = "Trait::foo: Expected a call with inputs (3, \"c\"), but the recorded calls were [(1, \"a\")]."]
+    fn assert_called_with_fails() {
+        let mut each = TraitMock::foo.stub(|each| {
+            each.call(matching!(_, _)).returns(true);
+        });
+        let tracker = each.track_inputs();
+
+        let u = Unimock::new(each);
+        u.foo(1, "a".to_string());
+
+        tracker.assert_called_with(&(3, "c".to_string()));
+    }
+}
+
+mod replace {
+    use super::*;
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32) -> i32;
+        fn bar(&self) -> i32;
+    }
+
+    #[test]
+    fn replaces_between_phases() {
+        let mut u = Unimock::new((
+            TraitMock::foo.each_call(matching!(_)).returns(1),
+            TraitMock::bar.each_call(matching!()).returns(10),
+        ));
+        assert_eq!(1, u.foo(0));
+        assert_eq!(10, u.bar());
+
+        u.replace::<TraitMock::foo>(TraitMock::foo.each_call(matching!(_)).returns(2))
+            .unwrap();
+        assert_eq!(2, u.foo(0));
+        assert_eq!(10, u.bar());
+    }
+
+    #[test]
+    fn ordered_patterns_continue_the_call_order() {
+        let mut u = Unimock::new((
+            TraitMock::foo.next_call(matching!(1)).returns(1),
+            TraitMock::bar.next_call(matching!()).returns(10),
+        ));
+        assert_eq!(1, u.foo(1));
+        assert_eq!(10, u.bar());
+
+        u.replace::<TraitMock::foo>(TraitMock::foo.next_call(matching!(2)).returns(2))
+            .unwrap();
+        assert_eq!(2, u.foo(2));
+    }
+
+    #[test]
+    #[should_panic = "Trait::foo(0): No mock implementation found."]
+    fn empty_clause_removes_setup() {
+        let mut u = Unimock::new(TraitMock::foo.each_call(matching!(_)).returns(1));
+        assert_eq!(1, u.foo(0));
+
+        u.replace::<TraitMock::foo>(()).unwrap();
+        u.foo(0);
+    }
+
+    #[test]
+    #[should_panic = "Mock for it::basic::replace::Trait::foo was never called. Dead mocks should be removed."]
+    fn verifies_replaced_setup() {
+        let mut u = Unimock::new(TraitMock::foo.each_call(matching!(_)).returns(1));
+        u.replace::<TraitMock::foo>(TraitMock::foo.each_call(matching!(_)).returns(2))
+            .unwrap();
+        assert_eq!(2, u.foo(0));
+    }
+
+    #[test]
+    fn replace_unchecked_skips_verification() {
+        let mut u = Unimock::new(TraitMock::foo.each_call(matching!(_)).returns(1));
+        u.replace_unchecked::<TraitMock::foo>(TraitMock::foo.each_call(matching!(_)).returns(2))
+            .unwrap();
+        assert_eq!(2, u.foo(0));
+    }
+
+    #[test]
+    fn other_mock_fn_in_clause_is_an_error() {
+        let mut u = Unimock::new(TraitMock::foo.each_call(matching!(_)).returns(1));
+        let error = u
+            .replace_unchecked::<TraitMock::foo>(TraitMock::bar.each_call(matching!()).returns(2))
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(
+            AssembleError::ReplaceWithOtherMockFn {
+                path: "it::basic::replace::Trait::foo".to_string()
+            },
+            error
+        );
+
+        // the setup is left unchanged
+        assert_eq!(1, u.foo(0));
+    }
+
+    #[test]
+    fn shared_instance_is_an_error() {
+        let mut u = Unimock::new(TraitMock::foo.each_call(matching!(_)).returns(1));
+        let clone = u.clone();
+        let error = u
+            .replace_unchecked::<TraitMock::foo>(TraitMock::foo.each_call(matching!(_)).returns(2))
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(
+            "Called replace() on a shared instance. Drop all clones before replacing it::basic::replace::Trait::foo.",
+            error.to_string()
+        );
+
+        assert_eq!(1, clone.foo(0));
+        drop(clone);
+        u.replace_unchecked::<TraitMock::foo>(TraitMock::foo.each_call(matching!(_)).returns(2))
+            .unwrap();
+        assert_eq!(2, u.foo(0));
+    }
+}
+
+mod also {
+    use super::*;
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32) -> i32;
+        fn bar(&self) -> i32;
+    }
+
+    #[test]
+    fn adds_setup_after_construction() {
+        let u = Unimock::new(TraitMock::foo.each_call(matching!(1)).returns(1))
+            .also::<TraitMock::foo>(|each| {
+                each.call(matching!(1)).returns(100);
+                each.call(matching!(_)).returns(2);
+            })
+            .also::<TraitMock::bar>(|each| {
+                each.call(matching!()).returns(10);
+            });
+
+        // existing patterns take precedence
+        assert_eq!(1, u.foo(1));
+        assert_eq!(2, u.foo(2));
+        assert_eq!(10, u.bar());
+    }
+
+    #[test]
+    #[should_panic = "A clause for it::basic::also::Trait::foo has already been registered as InOrder, but got re-registered as InAnyOrder."]
+    fn ordered_setup_panics() {
+        let _ = Unimock::new(TraitMock::foo.next_call(matching!(1)).returns(1))
+            .also::<TraitMock::foo>(|each| {
+                each.call(matching!(_)).returns(2);
+            });
+    }
+
+    #[test]
+    #[should_panic = "Called also() on a shared instance. Drop all clones before adding setup for it::basic::also::Trait::foo."]
+    fn shared_instance_panics() {
+        let u = Unimock::new(TraitMock::bar.each_call(matching!()).returns(1));
+        let _clone = u.clone();
+        let _ = u.also::<TraitMock::foo>(|each| {
+            each.call(matching!(_)).returns(2);
+        });
+    }
+}
+
+mod renamed_mock_fns {
+    mod flat {
+        use unimock::*;
+
+        #[unimock(rename_fn = "{Trait}{Method}Mock")]
+        trait Service {
+            fn get_item(&self, id: i32) -> i32;
+        }
+
+        #[test]
+        fn test_flat() {
+            let u = Unimock::new(ServiceGetItemMock.each_call(matching!(1)).returns(2));
+            assert_eq!(2, u.get_item(1));
+        }
+    }
+
+    mod in_module {
+        use unimock::*;
+
+        #[unimock(api=ServiceMock, rename_fn = "{trait}_{method}")]
+        trait Service {
+            fn get_item(&self, id: i32) -> i32;
+        }
+
+        #[test]
+        fn test_in_module() {
+            let u = Unimock::new(
+                ServiceMock::service_get_item
+                    .each_call(matching!(1))
+                    .returns(2),
+            );
+            assert_eq!(2, u.get_item(1));
+        }
+    }
+
+    mod generics {
+        use unimock::*;
+
+        #[unimock(rename_fn = "{Method}Mock")]
+        trait Generic<T> {
+            fn foo(&self, input: T) -> T;
+        }
+
+        #[test]
+        fn test_generics() {
+            let u = Unimock::new(
+                FooMock
+                    .with_types::<i32>()
+                    .each_call(matching!(1))
+                    .returns(2),
+            );
+            assert_eq!(2, <Unimock as Generic<i32>>::foo(&u, 1));
+        }
+    }
+}
+
+mod static_stub {
+    use unimock::*;
+
+    #[unimock(api=LookupMock)]
+    trait Lookup {
+        fn name(&self, id: u32) -> &str;
+        fn static_name(&self) -> &'static str;
+        fn primes(&self) -> &[u32];
+    }
+
+    const PRIMES: [u32; 3] = [2, 3, 5];
+
+    #[test]
+    fn responds_to_every_call() {
+        let u = Unimock::new((
+            LookupMock::name.static_stub("Bob"),
+            LookupMock::static_name.static_stub("Alice"),
+            LookupMock::primes.static_stub(&PRIMES),
+        ));
+
+        assert_eq!("Bob", u.name(1));
+        assert_eq!("Bob", u.name(2));
+        assert_eq!("Alice", u.static_name());
+        assert_eq!(&[2, 3, 5], u.primes());
+    }
+}
+
+mod clause_bitor {
+    use unimock::build::Clauses;
+    use unimock::*;
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32) -> i32;
+        fn bar(&self) -> i32;
+    }
+
+    fn base_clauses() -> Clauses {
+        TraitMock::foo.each_call(matching!(1)).returns(1)
+            | TraitMock::bar.next_call(matching!()).returns(2)
+    }
+
+    #[test]
+    fn combines_clauses() {
+        let mut clauses = base_clauses();
+        clauses |= TraitMock::foo.each_call(matching!(_)).returns(3);
+        clauses |= TraitMock::bar.next_call(matching!()).returns(4).once();
+
+        let u = Unimock::new(clauses);
+        assert_eq!(1, u.foo(1));
+        assert_eq!(3, u.foo(2));
+        assert_eq!(2, u.bar());
+        assert_eq!(4, u.bar());
+    }
+
+    #[test]
+    #[should_panic = "Mock for it::basic::clause_bitor::Trait::bar was never called."]
+    fn combined_clauses_are_verified() {
+        let _ = Unimock::new(Clauses::new() | base_clauses());
+    }
+}
+
+mod derive_debug {
+    use unimock::alloc::{format, String};
+    use unimock::*;
+
+    #[unimock(api=ServiceMock, derive_debug)]
+    trait Service {
+        fn get_item(&self, id: i32) -> i32;
+    }
+
+    #[unimock(api=[GenericFoo], derive_debug)]
+    trait Generic<T> {
+        fn foo(&self, input: T) -> T;
+    }
+
+    #[test]
+    fn formats_as_struct_name() {
+        assert_eq!("get_item", format!("{:?}", ServiceMock::get_item));
+        assert_eq!("GenericFoo", format!("{:?}", GenericFoo));
+    }
+}
+
+mod each_ext {
+    use unimock::alloc::{format, String};
+    use unimock::*;
+
+    #[unimock(api=FooMock)]
+    trait Foo {
+        fn foo(&self, arg: i32) -> String;
+    }
+
+    #[test]
+    fn shorthands_define_quantified_call_patterns() {
+        let u = Unimock::new(FooMock::foo.stub(|each| {
+            each.once_returns(matching!(1), "one");
+            each.once_answers(matching!(2), &|_, arg| format!("answer {arg}"));
+            each.n_times_returns(2, matching!(3), "three");
+            each.always_returns(matching!(_), "other");
+        }));
+
+        assert_eq!("one", u.foo(1));
+        assert_eq!("answer 2", u.foo(2));
+        assert_eq!("three", u.foo(3));
+        assert_eq!("three", u.foo(3));
+        assert_eq!("other", u.foo(4));
+        assert_eq!("other", u.foo(5));
+    }
+
+    #[test]
+    fn shorthands_can_be_chained() {
+        let u = Unimock::new(FooMock::foo.stub(|each| {
+            each.once_returns(matching!(_), "first")
+                .then_returns("second");
+        }));
+
+        assert_eq!("first", u.foo(1));
+        assert_eq!("second", u.foo(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "to match exactly 2 calls, but it actually matched 1 call.")]
+    fn n_times_is_verified() {
+        let u = Unimock::new(FooMock::foo.stub(|each| {
+            each.n_times_returns(2, matching!(_), "x");
+        }));
+
+        u.foo(1);
+    }
+}
+
+mod with_override {
+    use unimock::*;
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32) -> i32;
+    }
+
+    fn base() -> impl Clause {
+        (
+            TraitMock::foo.each_call(matching!(_)).returns(0),
+            TraitMock::foo.each_call(matching!(1)).returns(1),
+        )
+    }
+
+    #[test]
+    fn override_patterns_are_tried_first() {
+        let u = Unimock::new(
+            base().with_override(TraitMock::foo.each_call(matching!(1 | 2)).returns(10)),
+        );
+
+        assert_eq!(10, u.foo(1));
+        assert_eq!(10, u.foo(2));
+        assert_eq!(0, u.foo(3));
+    }
+
+    #[test]
+    fn later_overrides_win() {
+        let u = Unimock::new(
+            base()
+                .with_override(TraitMock::foo.each_call(matching!(_)).returns(10))
+                .with_override(TraitMock::foo.each_call(matching!(2)).returns(20)),
+        );
+
+        assert_eq!(10, u.foo(1));
+        assert_eq!(20, u.foo(2));
+    }
+
+    #[test]
+    fn nested_overrides_keep_their_priority() {
+        let u = Unimock::new(
+            base().with_override(
+                TraitMock::foo
+                    .each_call(matching!(_))
+                    .returns(10)
+                    .with_override(TraitMock::foo.each_call(matching!(2)).returns(20)),
+            ),
+        );
+
+        assert_eq!(10, u.foo(1));
+        assert_eq!(20, u.foo(2));
+    }
+}
+
+mod assert_satisfied {
+    use unimock::*;
+
+    #[unimock(api=FooMock)]
+    trait Foo {
+        fn fetch(&self) -> i32;
+        fn store(&self, value: i32);
+    }
+
+    fn clauses() -> impl Clause {
+        (
+            FooMock::fetch
+                .each_call(matching!())
+                .returns(1)
+                .at_least_times(2),
+            FooMock::store.each_call(matching!(_)).returns(()).once(),
+        )
+    }
+
+    #[test]
+    fn passes_when_the_mock_fn_is_satisfied() {
+        let u = Unimock::new(clauses());
+
+        u.fetch();
+        u.fetch();
+        assert_satisfied!(u, FooMock::fetch);
+
+        u.store(1);
+        assert_satisfied!(u.clone(), FooMock::store);
+    }
+
+    #[test]
+    #[should_panic = "Foo::fetch() at tests/it/basic.rs:1951 to match at least 2 calls, but it actually matched 1 call."]
+    fn panics_when_the_mock_fn_is_not_satisfied() {
+        let u = Unimock::new(clauses());
+
+        u.fetch();
+        u.store(1);
+        assert_satisfied!(u, FooMock::fetch);
+    }
+}
+
+mod only_if {
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use unimock::*;
+
+    #[unimock(api=DoorMock)]
+    trait Door {
+        fn open(&self, code: i32) -> bool;
+    }
+
+    #[test]
+    fn inactive_response_falls_through_to_next_pattern() {
+        static UNLOCKED: AtomicBool = AtomicBool::new(false);
+
+        let u = Unimock::new((
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(true)
+                .only_if(|| UNLOCKED.load(Ordering::SeqCst))
+                .at_least_times(1),
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(false)
+                .at_least_times(1),
+        ));
+
+        assert!(!u.open(1));
+        UNLOCKED.store(true, Ordering::SeqCst);
+        assert!(u.open(1));
+        UNLOCKED.store(false, Ordering::SeqCst);
+        assert!(!u.open(1));
+    }
+
+    #[test]
+    fn condition_on_quantified_answer() {
+        static ENABLED: AtomicBool = AtomicBool::new(true);
+
+        let u = Unimock::new((
+            DoorMock::open
+                .each_call(matching!(_))
+                .answers(&|_, code| {
+                    ENABLED.store(false, Ordering::SeqCst);
+                    code == 42
+                })
+                .only_if(|| ENABLED.load(Ordering::SeqCst))
+                .once(),
+            DoorMock::open.each_call(matching!(_)).returns(false).once(),
+        ));
+
+        assert!(u.open(42));
+        assert!(!u.open(42));
+    }
+
+    #[test]
+    #[should_panic = "No matching call patterns"]
+    fn no_active_patterns_is_a_mismatch() {
+        let u = Unimock::new(
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(true)
+                .only_if(|| false)
+                .at_least_times(0),
+        );
+
+        u.open(1);
+    }
+
+    #[test]
+    fn condition_after_n_times() {
+        static UNLOCKED: AtomicBool = AtomicBool::new(false);
+
+        let u = Unimock::new((
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(true)
+                .n_times(1)
+                .only_if(|| UNLOCKED.load(Ordering::SeqCst)),
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(false)
+                .at_least_times(1),
+        ));
+
+        assert!(!u.open(1));
+        UNLOCKED.store(true, Ordering::SeqCst);
+        assert!(u.open(1));
+    }
+
+    #[test]
+    fn condition_after_at_least_times() {
+        let u = Unimock::new((
+            DoorMock::open
+                .each_call(matching!(_))
+                .returns(true)
+                .at_least_times(0)
+                .only_if(|| false),
+            DoorMock::open.each_call(matching!(_)).returns(false).once(),
+        ));
+
+        assert!(!u.open(1));
+    }
+
+    #[test]
+    fn condition_on_unquantified_return_value() {
+        let u = Unimock::new(DoorMock::open.stub(|each| {
+            each.call(matching!(_)).returns(true).only_if(|| false);
+            each.call(matching!(_)).returns(false);
+        }));
+
+        assert!(!u.open(1));
+    }
+
+    #[cfg(any(feature = "std", feature = "spin-lock"))]
+    #[test]
+    fn inactive_pattern_does_not_record_inputs() {
+        let mut each = DoorMock::open.stub(|each| {
+            each.call(matching!(1)).returns(true).only_if(|| false);
+            each.call(matching!(2)).returns(false);
+        });
+        let tracker = each.track_inputs();
+
+        let u = Unimock::new(each);
+        assert_eq!(
+            MockErrorKind::NoMatchingCallPatterns,
+            u.try_eval::<DoorMock::open>(1).err().unwrap().kind()
+        );
+        assert!(!u.open(2));
+
+        assert_eq!(unimock::alloc::vec![2], tracker.all_calls());
+    }
+}
+
+mod mock_fn_ext {
+    use unimock::alloc::String;
+    use unimock::*;
+
+    #[unimock(api=StoreMock)]
+    trait Store {
+        fn get(&self, key: &str) -> String;
+        fn put(&self, key: &str, value: i32);
+    }
+
+    #[test]
+    fn quick_mock_responds_to_every_call() {
+        let u = Unimock::new(StoreMock::get.quick_mock("value"));
+
+        assert_eq!("value", u.get("a"));
+        assert_eq!("value", u.get("b"));
+    }
+
+    #[test]
+    fn ignore_accepts_every_call() {
+        let u = Unimock::new(StoreMock::put.ignore());
+
+        u.put("a", 1);
+        u.put("b", 2);
+    }
+}
+
+mod derive_default_stub {
+    use unimock::alloc::String;
+    use unimock::*;
+
+    #[derive(Clone)]
+    pub struct NoDefault;
+
+    #[unimock(api=RepoMock, derive_default_stub)]
+    trait Repo {
+        fn count(&self) -> usize;
+        fn name(&self, id: u32) -> String;
+        fn store(&self, id: u32);
+        fn special(&self) -> NoDefault;
+        fn generic<T: 'static>(&self, value: T) -> i32;
+    }
+
+    #[test]
+    fn stubs_every_defaultable_method() {
+        let u = Unimock::new(RepoMock::default_stub());
+
+        assert_eq!(0, u.count());
+        assert_eq!("", u.name(1));
+        u.store(1);
+    }
+
+    #[test]
+    fn other_clauses_override_the_default_stub() {
+        let u = Unimock::new((
+            RepoMock::default_stub(),
+            RepoMock::name.each_call(matching!(2)).returns("two"),
+            RepoMock::special.each_call(matching!()).returns(NoDefault),
+        ));
+
+        assert_eq!("", u.name(1));
+        assert_eq!("two", u.name(2));
+        let NoDefault = u.special();
+    }
+
+    #[test]
+    #[should_panic = "Repo::special(): No mock implementation found."]
+    fn methods_without_default_output_are_not_stubbed() {
+        let u = Unimock::new(RepoMock::default_stub());
+
+        u.special();
+    }
+}
+
+#[cfg(feature = "std")]
+mod delegates_to {
+    use super::*;
+
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    #[unimock(api=RepoMock)]
+    trait Repo {
+        fn get(&self, id: u32) -> Option<String>;
+        fn insert(&self, id: u32, name: String) -> bool;
+        fn name(&self) -> &str;
+    }
+
+    #[derive(Default)]
+    struct InMemoryRepo(Mutex<BTreeMap<u32, String>>);
+
+    impl Repo for InMemoryRepo {
+        fn get(&self, id: u32) -> Option<String> {
+            self.0.lock().unwrap().get(&id).cloned()
+        }
+
+        fn insert(&self, id: u32, name: String) -> bool {
+            self.0.lock().unwrap().insert(id, name).is_none()
+        }
+
+        fn name(&self) -> &str {
+            "in-memory"
+        }
+    }
+
+    #[test]
+    fn forwards_to_the_fake() {
+        let u = Unimock::new(
+            RepoMock::get
+                .each_call(matching!(_))
+                .delegates_to(InMemoryRepo(Mutex::new(BTreeMap::from([(
+                    1,
+                    "one".to_string(),
+                )])))),
+        );
+
+        assert_eq!(Some("one".to_string()), u.get(1));
+        assert_eq!(None, u.get(2));
+    }
+
+    #[test]
+    fn shared_fake_and_explicit_responses() {
+        let fake = Arc::new(InMemoryRepo::default());
+        let u = Unimock::new((
+            RepoMock::insert
+                .each_call(matching!(_, _))
+                .delegates_to_arc(fake.clone()),
+            RepoMock::get
+                .next_call(matching!(1))
+                .delegates_to_arc(fake.clone())
+                .once()
+                .then()
+                .returns(None),
+            RepoMock::name.each_call(matching!()).returns("mocked"),
+        ));
+
+        assert!(u.insert(1, "one".to_string()));
+        assert!(!u.insert(1, "uno".to_string()));
+        assert_eq!(Some("uno".to_string()), u.get(1));
+        assert_eq!(None, u.get(1));
+        assert_eq!("mocked", u.name());
+        assert_eq!(Some("uno".to_string()), fake.get(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "to match exactly 2 calls, but it actually matched 1 call.")]
+    fn keeps_verification() {
+        let u = Unimock::new(
+            RepoMock::get
+                .each_call(matching!(_))
+                .delegates_to(InMemoryRepo::default())
+                .n_times(2),
+        );
+
+        u.get(1);
+    }
+}
+
+mod clauses_merge_into {
+    use unimock::build::Clauses;
+    use unimock::*;
+
+    #[unimock(api=TraitMock)]
+    trait Trait {
+        fn foo(&self, a: i32) -> i32;
+    }
+
+    #[test]
+    fn merged_patterns_keep_their_order() {
+        let mut target = Clauses::new();
+        target.push(TraitMock::foo.next_call(matching!(1)).returns(1));
+
+        let mut other = Clauses::new();
+        other.push(TraitMock::foo.next_call(matching!(2)).returns(2));
+        other.push(TraitMock::foo.next_call(matching!(3)).returns(3));
+        other.merge_into(&mut target);
+
+        let u = Unimock::new(target);
+        assert_eq!(1, u.foo(1));
+        assert_eq!(2, u.foo(2));
+        assert_eq!(3, u.foo(3));
+    }
+
+    #[test]
+    #[should_panic = "Mock for it::basic::clauses_merge_into::Trait::foo was never called."]
+    fn merged_patterns_are_verified() {
+        let mut target = Clauses::new();
+        Clauses::new().merge_into(&mut target);
+        (Clauses::new() | TraitMock::foo.each_call(matching!(_)).returns(0))
+            .merge_into(&mut target);
+
+        let _ = Unimock::new(target);
+    }
+}
+
+#[cfg(feature = "std")]
+mod forwards_to {
+    use std::sync::{Arc, Mutex, OnceLock};
+
+    use unimock::*;
+
+    #[unimock(api=GreeterMock)]
+    trait Greeter {
+        fn greet(&self, name: String) -> String;
+    }
+
+    fn base() -> Unimock {
+        Unimock::new(
+            GreeterMock::greet
+                .each_call(matching!(_))
+                .answers(&|_, name| format!("Hello, {name}!")),
+        )
+    }
+
+    #[test]
+    fn overrides_fall_through_to_the_other_instance() {
+        let base = base();
+        let u = Unimock::new((
+            GreeterMock::greet
+                .each_call(matching!("Bob"))
+                .returns("Hi Bob"),
+            GreeterMock::greet
+                .each_call(matching!(_))
+                .forwards_to(base.clone()),
+        ));
+
+        assert_eq!("Hi Bob", u.greet("Bob".to_string()));
+        assert_eq!("Hello, Alice!", u.greet("Alice".to_string()));
+        assert_eq!("Hello, Bob!", base.greet("Bob".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "to match exactly 2 calls, but it actually matched 1 call.")]
+    fn forwarded_calls_count_towards_verification_of_the_other_instance() {
+        let base = Unimock::new(
+            GreeterMock::greet
+                .each_call(matching!(_))
+                .returns("hello")
+                .n_times(2),
+        );
+        let u = Unimock::new(
+            GreeterMock::greet
+                .each_call(matching!(_))
+                .forwards_to(base.clone()),
+        );
+
+        u.greet("Alice".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::basic::forwards_to::Greeter::greet was forwarded between Unimock instances more than 8 times. Do two instances forward to each other?"
+    )]
+    fn mutual_forwarding_is_cut_off() {
+        let back: Arc<OnceLock<Unimock>> = Arc::new(OnceLock::new());
+        let back_clone = back.clone();
+        let b = Unimock::builder()
+            .clause(
+                GreeterMock::greet
+                    .each_call(matching!(_))
+                    .answers_arc(Arc::new(move |_, name| {
+                        back_clone.get().unwrap().greet(name)
+                    })),
+            )
+            .unmock_recursion_limit(8)
+            .build();
+        let a = Unimock::new(
+            GreeterMock::greet
+                .each_call(matching!(_))
+                .forwards_to(b.clone()),
+        );
+        back.set(a.clone()).ok().unwrap();
+
+        a.greet("Alice".to_string());
+    }
+
+    #[test]
+    fn forwarding_depth_is_counted_per_thread() {
+        let other: Arc<Mutex<Option<Unimock>>> = Arc::new(Mutex::new(None));
+        let other_clone = other.clone();
+        let b = Unimock::builder()
+            .clause((
+                GreeterMock::greet.each_call(matching!("x")).returns("done"),
+                GreeterMock::greet
+                    .each_call(matching!(_))
+                    .answers_arc(Arc::new(move |_, _| {
+                        let other = other_clone.lock().unwrap();
+                        let other = other.as_ref().unwrap();
+                        // Forwarded into the same instance again, but on another thread
+                        std::thread::scope(|s| {
+                            s.spawn(|| other.greet("x".to_string())).join().unwrap()
+                        })
+                    })),
+            ))
+            .unmock_recursion_limit(1)
+            .build();
+        let forward_to_b = || {
+            Unimock::new(
+                GreeterMock::greet
+                    .each_call(matching!(_))
+                    .forwards_to(b.clone()),
+            )
+        };
+        let a = forward_to_b();
+        *other.lock().unwrap() = Some(forward_to_b());
+
+        assert_eq!("done", a.greet("Alice".to_string()));
+
+        // Break the cycle through the answer function, so `b` can verify
+        drop(other.lock().unwrap().take());
+        drop(a);
+    }
+}
+
+mod generic_associated_type {
+    use unimock::alloc::{vec, String, Vec};
+    use unimock::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Word<'a>(&'a str);
+
+    #[unimock(api = LenderMock, type Item<'a> = Word<'a>; type Items<T> = Vec<T>;)]
+    pub trait Lender {
+        type Item<'a>
+        where
+            Self: 'a;
+        type Items<T>;
+
+        fn len(&self, item: Self::Item<'_>) -> usize;
+        fn first(&self) -> Self::Item<'_>;
+        fn all(&self) -> Self::Items<i32>;
+    }
+
+    #[test]
+    fn lifetime_gat_as_input_borrows_from_caller() {
+        let u = Unimock::new(
+            LenderMock::len
+                .each_call(matching!(Word("abc")))
+                .returns(3_usize),
+        );
+
+        let word = String::from("abc");
+        assert_eq!(3, u.len(Word(&word)));
+    }
+
+    #[test]
+    fn gats_as_outputs() {
+        let u = Unimock::new((
+            LenderMock::first
+                .each_call(matching!())
+                .returns(Word("first")),
+            LenderMock::all.each_call(matching!()).returns(vec![1, 2]),
+        ));
+
+        assert_eq!(Word("first"), u.first());
+        assert_eq!(vec![1, 2], u.all());
+    }
+}
+
+mod expect_no_more_calls {
+    use unimock::*;
+
+    #[unimock(api=FooMock)]
+    trait Foo {
+        fn fetch(&self) -> i32;
+        fn store(&self, value: i32);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic = "No more calls expected to it::basic::expect_no_more_calls::Foo::fetch() at tests/it/basic.rs:"]
+    fn extra_call_to_satisfied_pattern_panics_immediately() {
+        let u = Unimock::new(
+            FooMock::fetch
+                .each_call(matching!())
+                .returns(1)
+                .at_least_times(1),
+        );
+
+        u.fetch();
+        u.expect_no_more_calls();
+        u.clone().fetch();
+    }
+
+    #[test]
+    fn unsatisfied_pattern_stays_callable() {
+        let u = Unimock::new((
+            FooMock::fetch.each_call(matching!()).returns(1).once(),
+            FooMock::store
+                .each_call(matching!(_))
+                .returns(())
+                .n_times(2),
+        ));
+
+        u.fetch();
+        u.store(1);
+        u.expect_no_more_calls();
+        u.store(2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn extra_call_is_reported_by_drain_errors() {
+        let mut u = Unimock::new(FooMock::store.each_call(matching!(_)).returns(()).once());
+
+        u.store(1);
+        u.expect_no_more_calls();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| u.store(2)));
+        assert!(result.is_err());
+
+        let errors = u.drain_errors();
+        assert_eq!(1, errors.len());
+        assert_eq!(MockErrorKind::NoMoreCallsExpected, errors[0].kind());
+    }
+}
+
+#[cfg(feature = "std")]
+mod returns_boxed {
+    use std::error::Error;
+    use std::fmt::Display;
+
+    use unimock::*;
+
+    pub trait Handler {
+        fn handle(&mut self) -> i32;
+    }
+
+    impl_box_from!(Handler + Send);
+
+    #[derive(Clone)]
+    pub struct Counter(i32);
+
+    impl Handler for Counter {
+        fn handle(&mut self) -> i32 {
+            self.0 += 1;
+            self.0
+        }
+    }
+
+    struct NotClone;
+
+    impl Handler for NotClone {
+        fn handle(&mut self) -> i32 {
+            0
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct Timeout;
+
+    impl Display for Timeout {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "timeout")
+        }
+    }
+
+    impl Error for Timeout {}
+
+    #[unimock(api = FactoryMock)]
+    trait Factory {
+        fn make(&self, name: &str) -> Box<dyn Handler + Send>;
+        fn error(&self) -> Box<dyn Error + Send + Sync>;
+        fn counter(&self) -> Box<Counter>;
+    }
+
+    #[test]
+    fn every_call_gets_a_fresh_box() {
+        let u = Unimock::new(
+            FactoryMock::make
+                .each_call(matching!("counter"))
+                .returns_boxed(Counter(0))
+                .n_times(2),
+        );
+
+        let mut first = u.make("counter");
+        assert_eq!(1, first.handle());
+        assert_eq!(2, first.handle());
+        assert_eq!(1, u.make("counter").handle());
+    }
+
+    #[test]
+    fn returns_boxed_once_accepts_values_without_clone() {
+        let u = Unimock::new((
+            FactoryMock::make
+                .next_call(matching!("a"))
+                .returns_boxed_once(NotClone),
+            FactoryMock::make
+                .next_call(matching!("b"))
+                .returns_boxed(Counter(10))
+                .once(),
+        ));
+
+        assert_eq!(0, u.make("a").handle());
+        assert_eq!(11, u.make("b").handle());
+    }
+
+    #[test]
+    fn boxed_error() {
+        let u = Unimock::new(
+            FactoryMock::error
+                .each_call(matching!())
+                .returns_boxed(Timeout),
+        );
+
+        assert_eq!("timeout", u.error().to_string());
+        assert_eq!("timeout", u.error().to_string());
+    }
+
+    #[test]
+    fn boxed_sized_value() {
+        let u = Unimock::new(
+            FactoryMock::counter
+                .each_call(matching!())
+                .returns_boxed(Counter(5)),
+        );
+
+        assert_eq!(6, u.counter().handle());
+    }
+}
+
+mod fails_every {
+    use super::*;
+    use unimock::alloc::{vec, Vec};
+
+    #[unimock(api=FetchMock)]
+    trait Fetch {
+        fn fetch(&self, id: u32) -> Result<String, String>;
+    }
+
+    #[test]
+    fn every_nth_call_fails() {
+        let u = Unimock::new(
+            FetchMock::fetch
+                .each_call(matching!(_))
+                .fails_every(3, "unavailable".to_string())
+                .then_returns("ok"),
+        );
+
+        let results: Vec<_> = (0..6).map(|id| u.fetch(id).is_ok()).collect();
+        assert_eq!(vec![true, true, false, true, true, false], results);
+    }
+
+    #[test]
+    fn failing_calls_count_towards_the_pattern() {
+        let u = Unimock::new(
+            FetchMock::fetch
+                .each_call(matching!(_))
+                .fails_every(2, "unavailable".to_string())
+                .then_returns("ok")
+                .n_times(4),
+        );
+
+        for id in 0..4 {
+            let _ = u.fetch(id);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "to match exactly 4 calls, but it actually matched 3 calls.")]
+    fn missing_calls_are_reported() {
+        let u = Unimock::new(
+            FetchMock::fetch
+                .each_call(matching!(_))
+                .fails_every(2, "unavailable".to_string())
+                .then_returns("ok")
+                .n_times(4),
+        );
+
+        for id in 0..3 {
+            let _ = u.fetch(id);
+        }
+    }
+
+    #[test]
+    fn in_a_stub() {
+        let u = Unimock::new(FetchMock::fetch.stub(|each| {
+            each.call(matching!(0))
+                .returns(Err("not found".to_string()));
+            each.call(matching!(_))
+                .fails_every(1, "unavailable".to_string())
+                .then_returns("ok");
+        }));
+
+        assert_eq!(Err("not found".to_string()), u.fetch(0));
+        assert_eq!(Err("unavailable".to_string()), u.fetch(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "fails_every requires a non-zero period")]
+    fn zero_period_should_panic() {
+        let _ = FetchMock::fetch
+            .each_call(matching!(_))
+            .fails_every(0, "unavailable".to_string());
+    }
+}
+
+mod and_then_default {
+    use super::*;
+
+    #[unimock(api=CounterMock)]
+    trait Counter {
+        fn next(&self) -> Option<u32>;
+    }
+
+    #[test]
+    fn returns_default_after_the_sequence() {
+        let u = Unimock::new(
+            CounterMock::next
+                .each_call(matching!())
+                .returns(Some(1))
+                .n_times(2)
+                .then_returns(Some(2))
+                .and_then_default(),
+        );
+
+        assert_eq!(Some(1), u.next());
+        assert_eq!(Some(1), u.next());
+        assert_eq!(Some(2), u.next());
+        assert_eq!(None, u.next());
+        assert_eq!(None, u.next());
+    }
+
+    #[test]
+    fn default_does_not_need_to_be_reached() {
+        let u = Unimock::new(
+            CounterMock::next
+                .some_call(matching!())
+                .returns(Some(1))
+                .once()
+                .and_then_default(),
+        );
+
+        assert_eq!(Some(1), u.next());
+    }
+
+    #[test]
+    #[should_panic(expected = "to match at least 2 calls, but it actually matched 1 call.")]
+    fn sequence_must_be_used_up() {
+        let u = Unimock::new(
+            CounterMock::next
+                .each_call(matching!())
+                .returns(Some(1))
+                .once()
+                .then_returns(Some(2))
+                .and_then_default(),
+        );
+
+        assert_eq!(Some(1), u.next());
+    }
+}
@@ -264,3 +264,44 @@ mod default_impl_pin {
         );
     }
 }
+
+mod default_impl_box {
+    use unimock::alloc::Box;
+    use unimock::*;
+
+    #[unimock(api = DefaultBodyMock)]
+    trait DefaultBody: Sized {
+        fn core(self: Box<Self>, arg: i32) -> i32;
+
+        fn default_body(self: Box<Self>, arg: i32) -> i32 {
+            self.core(arg * 2)
+        }
+    }
+
+    #[test]
+    fn mock_default_body() {
+        assert_eq!(
+            777,
+            Box::new(Unimock::new(
+                DefaultBodyMock::default_body
+                    .next_call(matching!(21))
+                    .answers(&|_, _| 777)
+            ))
+            .default_body(21)
+        );
+    }
+
+    #[test]
+    fn delegate_to_default_body() {
+        assert_eq!(
+            42,
+            Box::new(Unimock::new((
+                DefaultBodyMock::default_body
+                    .next_call(matching!(21))
+                    .applies_default_impl(),
+                DefaultBodyMock::core.next_call(matching!(42)).returns(42)
+            )))
+            .default_body(21)
+        );
+    }
+}
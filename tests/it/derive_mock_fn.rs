@@ -0,0 +1,84 @@
+use unimock::*;
+
+// Stands in for a trait from another crate
+trait Store {
+    fn get(&self, key: &str) -> Option<i32>;
+    fn name(&self) -> &str;
+    fn clear(&self);
+}
+
+#[derive(MockFn)]
+#[mock_fn(name = "Store::get", inputs = "(&str,)", output = "Option<i32>")]
+struct StoreGet;
+
+#[derive(MockFn)]
+#[mock_fn(name = "storage::Store::name", output = "&str")]
+struct StoreName;
+
+#[derive(MockFn)]
+#[mock_fn(name = "Store::clear")]
+struct StoreClear;
+
+struct MockStore(Unimock);
+
+impl Store for MockStore {
+    fn get(&self, key: &str) -> Option<i32> {
+        StoreGet::call(&self.0, key)
+    }
+
+    fn name(&self) -> &str {
+        StoreName::call(&self.0)
+    }
+
+    fn clear(&self) {
+        StoreClear::call(&self.0)
+    }
+}
+
+#[test]
+fn derived_mock_fns_respond() {
+    let store = MockStore(Unimock::new((
+        StoreGet.each_call(matching!("a")).returns(Some(1)),
+        StoreGet.each_call(matching!(_)).returns(None),
+        StoreName
+            .each_call(matching!())
+            .returns("mocked".to_string()),
+        StoreClear.next_call(matching!()).returns(()),
+    )));
+
+    assert_eq!(Some(1), store.get("a"));
+    assert_eq!(None, store.get("b"));
+    assert_eq!("mocked", store.name());
+    store.clear();
+}
+
+#[test]
+fn answers_receive_the_inputs() {
+    let store = MockStore(Unimock::new(
+        StoreGet
+            .next_call(matching!(_))
+            .answers(&|_, key| Some(key.len() as i32)),
+    ));
+
+    assert_eq!(Some(3), store.get("abc"));
+}
+
+#[test]
+#[should_panic = "it::derive_mock_fn::Store::get(\"b\"): No matching call patterns."]
+fn errors_name_the_module_of_the_struct() {
+    let store = MockStore(Unimock::new(
+        StoreGet.each_call(matching!("a")).returns(Some(1)),
+    ));
+
+    store.get("b");
+}
+
+#[test]
+#[should_panic = "Mock for storage::Store::name was never called."]
+fn errors_name_the_given_module() {
+    Unimock::new(
+        StoreName
+            .each_call(matching!())
+            .returns("unused".to_string()),
+    );
+}
@@ -1,4 +1,4 @@
-use unimock::alloc::String;
+use unimock::alloc::{format, vec, String, ToString, Vec};
 use unimock::*;
 
 #[unimock(api=SingleArgMock)]
@@ -15,7 +15,7 @@ fn should_panic_for_nonexisting_mock() {
 
 #[test]
 #[should_panic(
-    expected = "Mock for SingleArg::method1 was never called. Dead mocks should be removed."
+    expected = "Mock for it::errors::SingleArg::method1 was never called. Dead mocks should be removed."
 )]
 fn should_panic_for_unused_stub() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -26,7 +26,7 @@ fn should_panic_for_unused_stub() {
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 #[test]
 #[should_panic(
-    expected = "A clause for SingleArg::method1 has already been registered as InAnyOrder, but got re-registered as InOrder. They cannot be mixed for the same MockFn."
+    expected = "A clause for it::errors::SingleArg::method1 has already been registered as InAnyOrder, but got re-registered as InOrder. They cannot be mixed for the same MockFn."
 )]
 fn should_complain_about_mismatched_modes() {
     Unimock::new((
@@ -46,7 +46,7 @@ fn should_panic_for_empty_stub_closure() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1(\"whatever\"): No output available for after matching SingleArg::method1(_) at tests/it/errors.rs:53."
+    expected = "it::errors::SingleArg::method1(\"whatever\"): No output available for after matching it::errors::SingleArg::method1(_) at tests/it/errors.rs:53."
 )]
 fn call_pattern_without_output_factory_should_crash() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -66,7 +66,7 @@ fn should_panic_if_no_call_patterns_in_stub_are_matched() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1: Expected SingleArg::method1(\"a\") at tests/it/errors.rs:73 to match exactly 1 call, but it actually matched no calls."
+    expected = "it::errors::SingleArg::method1: Expected it::errors::SingleArg::method1(\"a\") at tests/it/errors.rs:73 to match exactly 1 call, but it actually matched no calls."
 )]
 fn call_pattern_with_count_expectation_should_panic_if_not_met() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -78,7 +78,7 @@ fn call_pattern_with_count_expectation_should_panic_if_not_met() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1(\"b\"): Explicit panic from SingleArg::method1(_) at tests/it/errors.rs:85: foobar!"
+    expected = "it::errors::SingleArg::method1(\"b\"): Explicit panic from it::errors::SingleArg::method1(_) at tests/it/errors.rs:85: foobar!"
 )]
 fn should_panic_with_explicit_message() {
     Unimock::new(SingleArgMock::method1.stub(|each| {
@@ -127,7 +127,7 @@ mod should_complain_when_returning_unquantified_value_more_then_once {
 
     #[test]
     #[should_panic(
-        expected = "Foo::foo(2): Cannot return value more than once from Foo::foo(_) at tests/it/errors.rs:133, because of missing Clone bound. Try using `.each_call()` or explicitly quantifying the response."
+        expected = "it::errors::should_complain_when_returning_unquantified_value_more_then_once::Foo::foo(2): Cannot return value more than once from it::errors::should_complain_when_returning_unquantified_value_more_then_once::Foo::foo(_) at tests/it/errors.rs:133, because of missing Clone bound. Try using `.each_call()` or explicitly quantifying the response."
     )]
     fn test() {
         let unimock = Unimock::new(FooMock::foo.some_call(matching!(_)).returns(42));
@@ -148,7 +148,7 @@ mod should_require_both_calls_2_some_call {
 
     #[test]
     #[should_panic(
-        expected = "Foo::foo: Expected Foo::foo(2) at tests/it/errors.rs:156 to match exactly 1 call, but it actually matched no calls."
+        expected = "it::errors::should_require_both_calls_2_some_call::Foo::foo: Expected it::errors::should_require_both_calls_2_some_call::Foo::foo(2) at tests/it/errors.rs:156 to match exactly 1 call, but it actually matched no calls."
     )]
     fn test() {
         let unimock = Unimock::new((
@@ -174,7 +174,7 @@ fn should_crash_when_sending_original_unimock_to_another_thread() {
 
 #[test]
 #[should_panic(
-    expected = "SingleArg::method1(\"\"): No function supplied for matching inputs for call pattern SingleArg::method1[#0]."
+    expected = "it::errors::SingleArg::method1(\"\"): No function supplied for matching inputs for call pattern it::errors::SingleArg::method1[#0] defined at tests/it/errors.rs:180."
 )]
 fn no_matcher_function() {
     let u = Unimock::new(SingleArgMock::method1.next_call(&|_| ()).returns(""));
@@ -198,3 +198,471 @@ fn no_default_impl() {
 fn should_panic_without_mutex_api_for_owned_once_responder() {
     Unimock::new(SingleArgMock::owned.next_call(matching!(_)).returns(666));
 }
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+#[test]
+fn try_build_returns_assemble_error() {
+    let error = Unimock::builder()
+        .clause(SingleArgMock::method1.each_call(matching!(_)).returns("a"))
+        .clause(
+            SingleArgMock::method1
+                .next_call(matching!(_))
+                .returns("b")
+                .once(),
+        )
+        .try_build()
+        .unwrap_err();
+
+    assert_eq!(
+        AssembleError::IncompatiblePatternMatchMode {
+            path: "it::errors::SingleArg::method1".into(),
+            old_mode: PatternMatchMode::InAnyOrder,
+            new_mode: PatternMatchMode::InOrder,
+        },
+        error
+    );
+}
+
+#[test]
+fn try_build_reports_conflicting_fallback_modes() {
+    let error = Unimock::builder()
+        .clause(SingleArgMock::owned.strict())
+        .clause(SingleArgMock::owned.spy_fallback())
+        .try_build()
+        .unwrap_err();
+
+    assert_eq!(
+        AssembleError::IncompatibleFallbackMode {
+            path: "it::errors::SingleArg::owned".into(),
+            old_mode: FallbackMode::Error,
+            new_mode: FallbackMode::Unmock,
+        },
+        error
+    );
+    assert_eq!(
+        "A fallback mode for it::errors::SingleArg::owned has already been registered as Error, but got re-registered as Unmock.",
+        error.to_string()
+    );
+}
+
+#[test]
+fn try_eval_reports_no_matching_call_patterns_without_panicking() {
+    let u = Unimock::new(
+        SingleArgMock::method1
+            .each_call(matching!("a"))
+            .returns("b"),
+    );
+
+    let error = u
+        .try_eval::<SingleArgMock::method1>("c")
+        .err()
+        .expect("should not match");
+    assert_eq!(MockErrorKind::NoMatchingCallPatterns, error.kind());
+    assert!(error
+        .to_string()
+        .starts_with("it::errors::SingleArg::method1(\"c\"): No matching call patterns."));
+
+    // the error is not recorded, so the instance still verifies after a successful call
+    assert_eq!("b", u.method1("a"));
+}
+
+#[test]
+fn try_eval_reports_missing_mock_implementation() {
+    let u = Unimock::new(());
+
+    let error = u
+        .try_eval::<SingleArgMock::owned>(())
+        .err()
+        .expect("should not be mocked");
+    assert_eq!(MockErrorKind::NoMockImplementation, error.kind());
+}
+
+#[test]
+#[should_panic(
+    expected = "it::errors::SingleArg::owned: Expected call pattern it::errors::SingleArg::owned[#0] defined at tests/it/errors.rs:287 to match exactly 1 call, but it actually matched no calls."
+)]
+fn verification_error_points_at_clause_definition() {
+    Unimock::new(
+        SingleArgMock::owned
+            .each_call(&|m| m.func(|_, _| true))
+            .returns(1)
+            .once(),
+    );
+}
+
+#[test]
+fn try_eval_error_includes_call_site() {
+    let u = Unimock::new(());
+
+    let error = u
+        .try_eval::<SingleArgMock::owned>(())
+        .err()
+        .expect("should not be mocked");
+    let location = error.location().expect("should have a call site");
+    assert_eq!("tests/it/errors.rs", location.file());
+    assert_eq!(298, location.line());
+    assert!(error
+        .to_string()
+        .ends_with("\nCalled at tests/it/errors.rs:298:10."));
+}
+
+mod closest_match {
+    use super::*;
+
+    #[unimock(api=RouterMock)]
+    trait Router {
+        fn route(&self, method: &str, path: &str) -> i32;
+    }
+
+    #[test]
+    fn no_matching_call_patterns_points_at_closest_pattern() {
+        let u = Unimock::new(RouterMock::route.stub(|each| {
+            each.call(matching!("POST", "/posts")).returns(1);
+            each.call(matching!("GET", "/users")).returns(2);
+            each.call(matching!((_, _) if false)).returns(3);
+        }));
+
+        let error = u
+            .try_eval::<RouterMock::route>(("GET", "/user"))
+            .err()
+            .expect("should not match");
+        assert!(error.to_string().contains(
+            "Closest call pattern #1 is it::errors::closest_match::Router::route(\"GET\", \"/users\") at tests/it/errors.rs:321, where input #1 was \"/user\"."
+        ));
+
+        assert_eq!(2, u.route("GET", "/users"));
+    }
+
+    #[test]
+    fn single_mismatching_pattern_has_no_closest_pattern() {
+        let u = Unimock::new(RouterMock::route.stub(|each| {
+            each.call(matching!("GET", "/users")).returns(2);
+        }));
+
+        let error = u
+            .try_eval::<RouterMock::route>(("GET", "/user"))
+            .err()
+            .expect("should not match");
+        assert!(!error.to_string().contains("Closest call pattern"));
+
+        assert_eq!(2, u.route("GET", "/users"));
+    }
+}
+
+#[test]
+fn drain_errors_returns_verification_errors_instead_of_panicking() {
+    let mut u = Unimock::new((
+        SingleArgMock::method1
+            .each_call(matching!("a"))
+            .returns("b"),
+        SingleArgMock::owned.each_call(matching!()).returns(1),
+    ));
+
+    let errors = u.drain_errors();
+    assert_eq!(
+        vec![
+            MockErrorKind::MockNeverCalled,
+            MockErrorKind::MockNeverCalled
+        ],
+        errors.iter().map(MockError::kind).collect::<Vec<_>>()
+    );
+    assert!(errors.iter().any(|error| {
+        error.to_string()
+        == "Mock for it::errors::SingleArg::method1 was never called. Dead mocks should be removed."
+    }));
+
+    // already verified, so dropping does not panic
+    drop(u);
+}
+
+#[test]
+fn drain_errors_is_empty_for_a_satisfied_instance() {
+    let mut u = Unimock::new(SingleArgMock::owned.next_call(matching!()).returns(1));
+    assert_eq!(1, u.owned());
+
+    assert!(u.drain_errors().is_empty());
+}
+
+mod debug_len {
+    use super::*;
+    use unimock::alloc::{vec, ToString, Vec};
+
+    #[unimock(api=SinkMock)]
+    trait Sink {
+        fn text(&self, text: String) -> i32;
+        fn bytes(&self, bytes: &[u8]) -> i32;
+        fn owned_bytes(&self, bytes: Vec<u8>) -> i32;
+    }
+
+    #[test]
+    fn long_inputs_are_elided_by_default() {
+        let u = Unimock::new(SinkMock::text.each_call(matching!("short")).returns(1));
+
+        let error = u
+            .try_eval::<SinkMock::text>("x".repeat(5000))
+            .err()
+            .unwrap();
+        let expected_prefix = format!(
+            "it::errors::debug_len::Sink::text(\"{}... (906 bytes elided)): ",
+            "x".repeat(4095)
+        );
+        assert!(error.to_string().starts_with(&expected_prefix));
+
+        assert_eq!(1, u.text("short".to_string()));
+    }
+
+    #[test]
+    fn builder_sets_max_debug_len() {
+        let u = Unimock::builder()
+            .clause(SinkMock::text.each_call(matching!("short")).returns(1))
+            .max_debug_len(4)
+            .build();
+
+        let error = u
+            .try_eval::<SinkMock::text>("long".repeat(2))
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("it::errors::debug_len::Sink::text(\"lon... (6 bytes elided)): No matching call patterns."));
+
+        assert_eq!(1, u.text("short".to_string()));
+    }
+
+    #[test]
+    fn mock_fn_overrides_max_debug_len() {
+        let u = Unimock::builder()
+            .clause((
+                SinkMock::text.each_call(matching!("short")).returns(1),
+                SinkMock::text.max_debug_len(100),
+            ))
+            .max_debug_len(4)
+            .build();
+
+        let error = u
+            .try_eval::<SinkMock::text>("long".repeat(2))
+            .err()
+            .unwrap();
+        assert!(error.to_string().starts_with(
+            "it::errors::debug_len::Sink::text(\"longlong\"): No matching call patterns."
+        ));
+
+        assert_eq!(1, u.text("short".to_string()));
+    }
+
+    #[test]
+    fn byte_buffers_are_summarized() {
+        let u = Unimock::new((
+            SinkMock::bytes.each_call(matching!([])).returns(1),
+            SinkMock::owned_bytes.each_call(matching!(_)).returns(2),
+        ));
+        let bytes: Vec<u8> = (0..=255).cycle().take(1024 * 1024).collect();
+
+        let error = u
+            .try_eval::<SinkMock::bytes>(&bytes)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.starts_with(
+            "it::errors::debug_len::Sink::bytes(<1048576 bytes: 000102030405060708090a0b0c0d0e0f..>): No matching call patterns."
+        ));
+        // the pattern mismatch is elided too
+        assert!(error.len() < 10_000);
+
+        assert_eq!(1, u.bytes(&[]));
+        assert_eq!(2, u.owned_bytes(vec![1, 2, 3]));
+    }
+}
+
+mod input_format {
+    use super::*;
+    use unimock::alloc::{format, vec, ToString};
+
+    #[unimock(api=AuthMock, unmock_with=[real_login])]
+    trait Auth {
+        fn login(&self, user: &str, password: &str) -> bool;
+    }
+
+    fn real_login(_: &impl core::any::Any, user: &str, _password: &str) -> bool {
+        user == "alice"
+    }
+
+    fn redacted() -> impl Clause {
+        AuthMock::login.with_input_format(|(user, _)| format!("({user:?}, <redacted>)"))
+    }
+
+    #[test]
+    fn applies_to_unmatched_calls() {
+        let u = Unimock::new((
+            redacted(),
+            AuthMock::login
+                .each_call(matching!("alice", "secret"))
+                .returns(true),
+        ));
+
+        let error = u
+            .try_eval::<AuthMock::login>(("alice", "hunter2"))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.starts_with("it::errors::input_format::Auth::login(\"alice\", <redacted>): No matching call patterns."));
+        assert!(!error.contains("hunter2"));
+
+        assert!(u.login("alice", "secret"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Auth::login(\"bob\", <redacted>): Method invoked in the correct order (1)"
+    )]
+    fn applies_to_call_order_errors() {
+        let u = Unimock::new((
+            redacted(),
+            AuthMock::login
+                .next_call(matching!("alice", _))
+                .returns(true),
+        ));
+
+        u.login("bob", "hunter2");
+    }
+
+    #[test]
+    fn applies_to_recorded_calls() {
+        let (u, recording) = Unimock::spy_record(redacted());
+
+        assert!(u.login("alice", "hunter2"));
+
+        assert_eq!(
+            vec!["it::errors::input_format::Auth::login(\"alice\", <redacted>)"],
+            recording.calls()
+        );
+    }
+}
+
+#[test]
+fn no_matching_call_patterns_error_includes_signature() {
+    let u = Unimock::new(
+        SingleArgMock::method1
+            .each_call(matching!("a"))
+            .returns("b"),
+    );
+
+    let error = u.try_eval::<SingleArgMock::method1>("c").err().unwrap();
+    assert!(error
+        .to_string()
+        .contains("The signature is `fn method1<'s>(&'s self, a: &'s str) -> &'s str`."));
+
+    assert_eq!("b", u.method1("a"));
+}
+
+mod labels {
+    use super::*;
+
+    #[test]
+    #[should_panic(
+        expected = "SingleArg::method1(_) \"catch-all\" at tests/it/errors.rs:572 to match at least 1 call"
+    )]
+    fn count_mismatch_names_the_label() {
+        let _u = Unimock::new((
+            SingleArgMock::method1
+                .each_call(matching!("a"))
+                .returns("b")
+                .named("first"),
+            SingleArgMock::method1
+                .each_call(matching!(_))
+                .answers(&|_, _| "c")
+                .named("catch-all")
+                .at_least_times(1),
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Pattern mismatch for call pattern \"only x\", input #0")]
+    fn no_match_listing_names_the_label() {
+        let u = Unimock::new(
+            SingleArgMock::method1
+                .each_call(matching!("x"))
+                .named("only x")
+                .returns("b"),
+        );
+
+        u.method1("a");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "inputs didn't match it::errors::SingleArg::method1(\"a\") \"step one\" at tests/it/errors.rs:599."
+    )]
+    fn call_order_error_names_the_label() {
+        let u = Unimock::new((
+            SingleArgMock::method1
+                .next_call(matching!("a"))
+                .returns("b")
+                .named("step one"),
+            SingleArgMock::method1
+                .next_call(matching!("b"))
+                .returns("c")
+                .named("step two"),
+        ));
+
+        u.method1("b");
+    }
+
+    #[test]
+    fn assemble_error_names_the_label() {
+        assert_eq!(
+            "it::errors::SingleArg::method1 \"step one\": Ordered call patterns must expect an exact number of calls.",
+            AssembleError::MockHasNoExactExpectation {
+                path: "it::errors::SingleArg::method1".into(),
+                label: Some("step one".into()),
+            }
+            .to_string()
+        );
+    }
+}
+
+mod debug_hints {
+    use super::*;
+
+    #[test]
+    fn no_match_lists_the_debug_hints() {
+        let u = Unimock::new((
+            SingleArgMock::method1
+                .each_call(matching_debug_hint!("the letter a", "a"))
+                .returns("b"),
+            SingleArgMock::method1
+                .each_call(matching!("b"))
+                .returns("c"),
+            SingleArgMock::method1
+                .each_call(matching_debug_hint!("long words", (s) if s.len() > 5))
+                .returns("d"),
+        ));
+
+        let error = u
+            .try_eval::<SingleArgMock::method1>("c")
+            .err()
+            .expect("should not match");
+        let error = error.to_string();
+
+        assert!(
+            error.starts_with("it::errors::SingleArg::method1(\"c\"): No matching call patterns.")
+        );
+        assert!(error.contains(
+            "Tried 'the letter a' for args (\"c\").\nTried 'long words' for args (\"c\").\n"
+        ));
+        assert_eq!(2, error.matches("Tried").count());
+
+        assert_eq!("b", u.method1("a"));
+    }
+
+    #[test]
+    fn debug_hint_matches_like_matching() {
+        let u = Unimock::new(
+            SingleArgMock::method1
+                .each_call(matching_debug_hint!("the letter a", "a"))
+                .returns("b"),
+        );
+
+        assert_eq!("b", u.method1("a"));
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::OnceLock;
+
+use unimock::build::Fixture;
+use unimock::*;
+
+#[unimock(api=ServiceMock, unmock_with=[_, real_metric])]
+trait Service {
+    fn now(&self) -> u64;
+    fn metric(&self, name: &str);
+}
+
+fn real_metric(_: &impl core::any::Any, _: &str) {}
+
+fn fixture() -> &'static Fixture {
+    static FIXTURE: OnceLock<Fixture> = OnceLock::new();
+    FIXTURE.get_or_init(|| {
+        Fixture::new()
+            .with_fn(|| ServiceMock::now.each_call(matching!()).returns(0_u64))
+            .with(ServiceMock::metric.spy_fallback())
+    })
+}
+
+#[test]
+fn unused_fixture_clauses_pass_verification() {
+    let u = fixture().unimock();
+    u.metric("ok");
+}
+
+#[test]
+fn instances_are_isolated() {
+    let fixture = Fixture::new().with_fn(|| ServiceMock::now.next_call(matching!()).returns(1_u64));
+
+    let a = fixture.unimock();
+    let b = fixture.unimock();
+
+    assert_eq!(1, a.now());
+    assert_eq!(1, b.now());
+}
+
+#[test]
+#[should_panic(
+    expected = "it::fixture::Service::now: Expected it::fixture::Service::now() at tests/it/fixture.rs:45 to match exactly 1 call, but it actually matched no calls."
+)]
+fn exact_expectations_still_apply() {
+    let fixture = Fixture::new().with_fn(|| ServiceMock::now.next_call(matching!()).returns(1_u64));
+
+    let a = fixture.unimock();
+    let b = fixture.unimock();
+
+    assert_eq!(1, a.now());
+    drop(a);
+    drop(b);
+}
+
+#[test]
+#[should_panic(
+    expected = "Mock for it::fixture::Service::now was never called. Dead mocks should be removed."
+)]
+fn test_clauses_stay_required() {
+    fixture()
+        .builder()
+        .clause(ServiceMock::now.each_call(matching!()).returns(1_u64))
+        .build();
+}
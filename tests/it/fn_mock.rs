@@ -0,0 +1,36 @@
+use unimock::alloc::{format, String};
+use unimock::*;
+
+fn apply_twice(input: i32, f: impl Fn(i32) -> i32) -> i32 {
+    f(f(input))
+}
+
+#[test]
+fn single_input() {
+    let u = Unimock::new(FnMock::<i32, i32>::new().stub(|each| {
+        each.call(matching!(1)).returns(2);
+        each.call(matching!(2)).returns(4);
+    }));
+
+    assert_eq!(4, apply_twice(1, |n| FnMock::call(&u, n)));
+}
+
+#[test]
+fn tuple_inputs_with_answer() {
+    let u = Unimock::new(
+        FnMock::<(i32, String), String>::new()
+            .each_call(matching!(_, "x"))
+            .answers(&|_, (n, s)| format!("{s}{n}")),
+    );
+
+    assert_eq!(
+        "x42",
+        FnMock::<(i32, String), String>::call(&u, (42, "x".into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "FnMock::call(): No mock implementation found.")]
+fn unmocked_call_panics() {
+    FnMock::<i32, i32>::call(&Unimock::new(()), 1);
+}
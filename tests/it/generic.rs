@@ -1,4 +1,4 @@
-use unimock::alloc::{String, ToString};
+use unimock::alloc::{format, String, ToString};
 use unimock::*;
 
 use core::fmt::Debug;
@@ -352,3 +352,54 @@ mod generics_and_associated_type {
         fn func<U: 'static>(&self) -> Option<Self::Assoc>;
     }
 }
+
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod method_generics_with_unmock {
+    use super::*;
+
+    #[unimock(api=RegistryMock, unmock_with=[real_lookup])]
+    trait Registry {
+        fn lookup<T: 'static>(&self, key: &str) -> String;
+    }
+
+    fn real_lookup<T: 'static>(_: &impl core::any::Any, key: &str) -> String {
+        format!("{key}:{}", core::any::type_name::<T>())
+    }
+
+    #[unimock(api=ExplicitMock, unmock_with=[real_explicit::<T>])]
+    trait Explicit {
+        fn explicit<T: core::fmt::Display + 'static>(&self, value: T) -> String;
+    }
+
+    fn real_explicit<T: core::fmt::Display>(_: &impl core::any::Any, value: T) -> String {
+        format!("<{value}>")
+    }
+
+    #[test]
+    fn spy_forwards_method_generics() {
+        let u = Unimock::new_partial(());
+
+        assert_eq!("a:u8", u.lookup::<u8>("a"));
+        assert_eq!("b:i32", u.lookup::<i32>("b"));
+    }
+
+    #[test]
+    fn mocked_and_unmocked_types_coexist() {
+        let u = Unimock::new_partial(
+            RegistryMock::lookup
+                .with_types::<u8>()
+                .next_call(matching!("a"))
+                .returns("mocked".to_string()),
+        );
+
+        assert_eq!("mocked", u.lookup::<u8>("a"));
+        assert_eq!("a:u16", u.lookup::<u16>("a"));
+    }
+
+    #[test]
+    fn explicit_generic_args() {
+        let u = Unimock::new_partial(());
+
+        assert_eq!("<42>", u.explicit(42));
+    }
+}
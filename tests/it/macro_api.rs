@@ -0,0 +1,83 @@
+use core::future::Future;
+use core::marker::PhantomData;
+
+use unimock::alloc::{format, Box, String};
+use unimock::macro_api::{eval, eval_async, Continuation, Eval};
+use unimock::*;
+
+// The macro does not support type-generic methods yet, so this trait is mocked by hand.
+trait Identity {
+    fn identity<T: core::fmt::Debug + Send + Sync + 'static>(&self, value: T) -> T;
+}
+
+#[allow(non_snake_case)]
+mod IdentityMock {
+    use super::*;
+
+    #[allow(non_camel_case_types)]
+    pub struct identity<T>(pub PhantomData<T>);
+
+    impl<T: core::fmt::Debug + Send + Sync + 'static> MockFn for identity<T> {
+        type Inputs<'i> = T;
+        type OutputKind = output::Owning<T>;
+        type AnswerFn = dyn Fn(&Unimock, T) -> T + Send + Sync;
+
+        fn info() -> MockFnInfo {
+            MockFnInfo::new::<Self>().path(&["Identity", "identity"])
+        }
+
+        fn debug_inputs(value: &Self::Inputs<'_>) -> Box<[Option<String>]> {
+            Box::new([Some(format!("{value:?}"))])
+        }
+    }
+}
+
+impl Identity for Unimock {
+    fn identity<T: core::fmt::Debug + Send + Sync + 'static>(&self, value: T) -> T {
+        match eval::<IdentityMock::identity<T>>(self, value) {
+            Eval::Return(output) => output,
+            Eval::Continue(Continuation::Answer(answer_fn), value) => answer_fn(self, value),
+            Eval::Continue(continuation, _) => continuation.report(self),
+        }
+    }
+}
+
+#[test]
+fn hand_written_generic_mock() {
+    let u = Unimock::new((
+        IdentityMock::identity::<i32>(PhantomData)
+            .each_call(matching!(_))
+            .answers(&|_, value| value * 2),
+        IdentityMock::identity::<&'static str>(PhantomData)
+            .next_call(matching!("a"))
+            .returns("b"),
+    ));
+
+    assert_eq!(4, u.identity(2));
+    assert_eq!("b", u.identity("a"));
+}
+
+#[test]
+#[should_panic(expected = "Identity::identity(\"c\"): No matching call patterns.")]
+fn hand_written_mock_reports_mismatches() {
+    let u = Unimock::new(
+        IdentityMock::identity::<&'static str>(PhantomData)
+            .each_call(matching!("a"))
+            .returns("b"),
+    );
+
+    u.identity("c");
+}
+
+// Fails to compile if the signatures of the stable entry points change.
+#[allow(unused)]
+fn entry_point_signatures() {
+    type F = IdentityMock::identity<i32>;
+
+    let _: for<'u> fn(&'u Unimock, i32) -> Eval<'u, 'static, F> = eval::<F>;
+    let _: fn(Continuation<F>, &Unimock) -> ! = Continuation::<F>::report;
+
+    fn eval_async_output<'u>(u: &'u Unimock) -> impl Future<Output = Eval<'u, 'u, F>> + 'u {
+        eval_async::<F>(u, 0)
+    }
+}
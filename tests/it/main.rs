@@ -11,10 +11,23 @@ mod basic;
 mod arg_borrows;
 
 mod default_impl;
+#[cfg(feature = "std")]
+mod derive_mock_fn;
 mod errors;
+#[cfg(feature = "std")]
+mod fixture;
+mod fn_mock;
 mod generic;
+mod macro_api;
 mod matching_eq;
+#[cfg(feature = "mock-serde")]
+mod matching_json;
+#[cfg(all(feature = "serde", feature = "std"))]
+mod replay;
+#[cfg(feature = "std")]
+mod state_map;
 mod test_debug;
+mod test_helpers;
 
 #[cfg(any(feature = "std", feature = "spin-lock"))]
 mod matching_pat;
@@ -27,6 +40,9 @@ mod mock_order;
 
 mod prefix;
 
+#[cfg(any(feature = "std", feature = "spin-lock"))]
+mod report;
+
 #[cfg(all(feature = "pretty-print", any(feature = "std", feature = "spin-lock")))]
 mod pretty_mismatches;
 
@@ -44,9 +60,27 @@ mod unmock;
 #[cfg(all(feature = "mock-tokio-1", feature = "std"))]
 mod test_mock_tokio;
 
+#[cfg(all(feature = "mock-futures-core-0-3", feature = "std"))]
+mod test_mock_futures;
+
 #[cfg(feature = "mock-embedded-hal-1")]
 mod test_mock_embedded_hal;
 
+#[cfg(feature = "mock-rand-core-0-6")]
+mod test_mock_rand;
+
+#[cfg(feature = "mock-log-0-4")]
+mod test_mock_log;
+
+#[cfg(feature = "mock-tower-service-0-3")]
+mod test_mock_tower;
+
+#[cfg(feature = "clock")]
+mod test_clock;
+
+#[cfg(feature = "std")]
+mod verify_handle;
+
 fn main() {}
 
 trait AsyncTest {
@@ -0,0 +1,60 @@
+use unimock::alloc::{String, ToString, Vec};
+use unimock::*;
+
+#[unimock(api=PublisherMock)]
+trait Publisher {
+    fn publish_str(&self, payload: &str) -> i32;
+    fn publish_string(&self, payload: String) -> i32;
+    fn publish_vec(&self, payload: Vec<u8>) -> i32;
+    fn publish_bytes(&self, payload: bytes::Bytes) -> i32;
+}
+
+#[test]
+fn matches_json_structure() {
+    let u = Unimock::new((
+        PublisherMock::publish_str
+            .each_call(matching!(json!({ "key": "value", "n": [1, 2] })))
+            .returns(1),
+        PublisherMock::publish_str
+            .each_call(matching!(_))
+            .returns(0),
+    ));
+
+    assert_eq!(1, u.publish_str(r#"{"key":"value","n":[1,2]}"#));
+    assert_eq!(1, u.publish_str(r#"{ "n": [1, 2], "key": "value" }"#));
+    assert_eq!(0, u.publish_str(r#"{"key":"other","n":[1,2]}"#));
+    assert_eq!(0, u.publish_str("not json"));
+}
+
+#[test]
+fn matches_owned_and_binary_payloads() {
+    let u = Unimock::new((
+        PublisherMock::publish_string
+            .each_call(matching!(json!({ "id": 1 })))
+            .returns(1),
+        PublisherMock::publish_vec
+            .each_call(matching!(json!({ "id": 2 })))
+            .returns(2),
+        PublisherMock::publish_bytes
+            .each_call(matching!(json!({ "id": 3 })))
+            .returns(3),
+    ));
+
+    assert_eq!(1, u.publish_string(r#"{"id":1}"#.to_string()));
+    assert_eq!(2, u.publish_vec(br#"{"id":2}"#.to_vec()));
+    assert_eq!(
+        3,
+        u.publish_bytes(bytes::Bytes::from_static(br#"{"id":3}"#))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Publisher::publish_str(\"{}\"): No matching call patterns.")]
+fn unmatched_json_panics() {
+    Unimock::new(
+        PublisherMock::publish_str
+            .each_call(matching!(json!({ "key": "value" })))
+            .returns(1),
+    )
+    .publish_str("{}");
+}
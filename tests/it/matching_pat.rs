@@ -64,3 +64,170 @@ mod debug_matching_literal_autoref_debug {
         TestMock::f.next_call(matching!(42)).returns_default();
     }
 }
+
+mod matching_arg_index {
+    use super::*;
+
+    pub struct NoDebug;
+
+    #[unimock(api = TestMock)]
+    trait Test {
+        fn f(&self, a: i32, b: &str, c: String, d: NoDebug) -> i32;
+    }
+
+    #[test]
+    fn test() {
+        let u = Unimock::new(TestMock::f.stub(|each| {
+            each.call(matching!(arg[1] == "bar")).returns(1);
+            each.call(matching!(arg[0] == 2 && arg[2] != "c"))
+                .returns(2);
+            each.call(matching!(_)).returns(3);
+        }));
+
+        assert_eq!(1, u.f(0, "bar", "c".to_string(), NoDebug));
+        assert_eq!(2, u.f(2, "foo", "x".to_string(), NoDebug));
+        assert_eq!(3, u.f(2, "foo", "c".to_string(), NoDebug));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::matching_pat::matching_arg_index::Test::f(1, \"foo\", \"c\", ?): Method invoked in the correct order (1), but inputs didn't match it::matching_pat::matching_arg_index::Test::f(arg[1] == \"bar\") at tests/it/matching_pat.rs:97. \nEquality mismatch for input #1 (actual / expected):\n"
+    )]
+    fn mismatch() {
+        let u = Unimock::new(TestMock::f.next_call(matching!(arg[1] == "bar")).returns(1));
+        u.f(1, "foo", "c".to_string(), NoDebug);
+    }
+}
+
+mod matching_within {
+    use super::*;
+    use unimock::alloc::{vec, Vec};
+
+    #[unimock(api = TestMock)]
+    trait Test {
+        fn id(&self, id: u32) -> bool;
+        fn name(&self, id: u32, name: &str) -> bool;
+    }
+
+    #[test]
+    fn test() {
+        let valid_ids: Vec<u32> = (1..4).collect();
+        let names = vec!["a", "b"];
+
+        let u = Unimock::new((
+            TestMock::id.stub(|each| {
+                each.call(matching!(within!(valid_ids))).returns(true);
+                each.call(matching!(_)).returns(false);
+            }),
+            TestMock::name
+                .each_call(matching!(within!(valid_ids), within!(names)))
+                .returns(true),
+        ));
+
+        // the collections are cloned, and still usable
+        assert_eq!(3, valid_ids.len());
+
+        assert!(u.id(1));
+        assert!(u.id(3));
+        assert!(!u.id(4));
+        assert!(u.name(2, "b"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::matching_pat::matching_within::Test::id(4): Method invoked in the correct order (1), but inputs didn't match it::matching_pat::matching_within::Test::id(within!(..)) at tests/it/matching_pat.rs:143. \nPattern mismatch for input #0 (actual / expected):\n"
+    )]
+    fn mismatch() {
+        let u = Unimock::new(
+            TestMock::id
+                .next_call(matching!(within!([1, 2, 3])))
+                .returns(true),
+        );
+        u.id(4);
+    }
+}
+
+mod match_fn {
+    use super::*;
+
+    mod matchers {
+        pub fn is_even(value: &i32) -> bool {
+            value % 2 == 0
+        }
+
+        pub fn same_length(&(a, b): &(&str, &str)) -> bool {
+            a.len() == b.len()
+        }
+    }
+
+    #[unimock(api = TestMock)]
+    trait Test {
+        fn single(&self, a: i32) -> bool;
+        fn double<'a>(&self, a: &'a str, b: &'a str) -> bool;
+    }
+
+    #[test]
+    fn external_matcher_functions() {
+        let u = Unimock::new((
+            TestMock::single.stub(|each| {
+                each.call(matching!(match_fn!(matchers::is_even)))
+                    .returns(true);
+                each.call(matching!(_)).returns(false);
+            }),
+            TestMock::double.stub(|each| {
+                each.call(matching!(match_fn!(self::matchers::same_length)))
+                    .returns(true);
+                each.call(matching!(_, _)).returns(false);
+            }),
+        ));
+
+        assert!(u.single(2));
+        assert!(!u.single(3));
+        assert!(u.double("ab", "cd"));
+        assert!(!u.double("a", "cd"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::matching_pat::match_fn::Test::single(3): Method invoked in the correct order (1), but inputs didn't match it::matching_pat::match_fn::Test::single(match_fn!(matchers::is_even)) at tests/it/matching_pat.rs:197."
+    )]
+    fn mismatch() {
+        let u = Unimock::new(
+            TestMock::single
+                .next_call(matching!(match_fn!(matchers::is_even)))
+                .returns(true),
+        );
+        u.single(3);
+    }
+}
+
+mod arg_count {
+    use super::*;
+
+    #[unimock(api = TestMock)]
+    trait Test {
+        fn none(&self) -> i32;
+        fn pair(&self, pair: (i32, i32)) -> i32;
+        fn two(&self, a: i32, b: i32) -> i32;
+    }
+
+    #[test]
+    fn n_inputs_is_generated() {
+        assert_eq!(Some(0), <TestMock::none as MockFn>::N_INPUTS);
+        assert_eq!(Some(1), <TestMock::pair as MockFn>::N_INPUTS);
+        assert_eq!(Some(2), <TestMock::two as MockFn>::N_INPUTS);
+    }
+
+    #[test]
+    fn single_patterns_match_the_whole_inputs() {
+        let u = Unimock::new((
+            TestMock::none.each_call(matching!(_)).returns(0),
+            TestMock::pair.each_call(matching!((1, 2))).returns(1),
+            TestMock::two.each_call(matching!(1, 2)).returns(2),
+        ));
+
+        assert_eq!(0, u.none());
+        assert_eq!(1, u.pair((1, 2)));
+        assert_eq!(2, u.two(1, 2));
+    }
+}
@@ -5,7 +5,7 @@
 
 use core::task::Poll;
 
-use unimock::alloc::{vec, ToString, Vec};
+use unimock::alloc::{vec, String, ToString, Vec};
 use unimock::*;
 
 mod clone {
@@ -113,7 +113,7 @@ fn in_result_clone_acrobatics() {
 
 #[test]
 #[should_panic(
-    expected = "InResult::ok_no_clone: Expected InResult::ok_no_clone(_) at tests/it/mixed.rs:121 to match exactly 1 call, but it actually matched 2 calls."
+    expected = "it::mixed::InResult::ok_no_clone: Expected it::mixed::InResult::ok_no_clone(_) at tests/it/mixed.rs:121 to match exactly 1 call, but it actually matched 2 calls."
 )]
 fn in_result_may_multi_respond_on_ok_no_clone() {
     let u = Unimock::new(
@@ -257,3 +257,126 @@ mod shallow {
         assert_eq!(<Unimock as Trait>::f(&u), Ok(&42));
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigError;
+
+#[unimock(api = ConfigStoreMock)]
+trait ConfigStore {
+    fn config(&self, name: &str) -> Result<&Config, ConfigError>;
+    fn entry(&self) -> (&str, usize);
+}
+
+#[test]
+fn borrowed_result_returns_owned_ok_and_err() {
+    let u = Unimock::new((
+        ConfigStoreMock::config
+            .each_call(matching!("app"))
+            .returns(Ok::<_, ConfigError>(Config {
+                name: "app".to_string(),
+            })),
+        ConfigStoreMock::config
+            .each_call(matching!(_))
+            .returns(Err::<Config, _>(ConfigError)),
+    ));
+
+    for _ in 0..2 {
+        assert_eq!(
+            Ok(&Config {
+                name: "app".to_string()
+            }),
+            u.config("app")
+        );
+        assert_eq!(Err(ConfigError), u.config("other"));
+    }
+}
+
+#[test]
+fn tuple_with_str_returns_owned_string() {
+    let u = Unimock::new(
+        ConfigStoreMock::entry
+            .each_call(matching!())
+            .returns(("name".to_string(), 3_usize)),
+    );
+
+    assert_eq!(("name", 3), u.entry());
+    assert_eq!(("name", 3), u.entry());
+}
+
+mod cow {
+    use unimock::alloc::{vec, Cow, String, ToString};
+    use unimock::*;
+
+    #[unimock(api = UserMock)]
+    trait User {
+        fn name(&self) -> Cow<'_, str>;
+        fn bytes<'s>(&'s self) -> Cow<'s, [u8]>;
+        fn greeting(&self) -> Cow<'static, str>;
+    }
+
+    #[test]
+    fn returns_lends_out_the_stored_value() {
+        let u = Unimock::new((
+            UserMock::name
+                .each_call(matching!())
+                .returns("alice".to_string()),
+            UserMock::bytes.next_call(matching!()).returns(vec![1, 2]),
+        ));
+
+        assert!(matches!(u.name(), Cow::Borrowed("alice")));
+        assert!(matches!(u.name(), Cow::Borrowed("alice")));
+        assert!(matches!(u.bytes(), Cow::Borrowed([1, 2])));
+    }
+
+    #[test]
+    fn returns_owned_returns_a_copy_every_time() {
+        let u = Unimock::new(
+            UserMock::name
+                .each_call(matching!())
+                .returns_owned("bob")
+                .n_times(2),
+        );
+
+        for _ in 0..2 {
+            match u.name() {
+                Cow::Owned(name) => assert_eq!("bob", name),
+                Cow::Borrowed(_) => panic!("expected an owned name"),
+            }
+        }
+    }
+
+    #[test]
+    fn answers_may_return_either_variant() {
+        let u = Unimock::new((
+            UserMock::name
+                .next_call(matching!())
+                .answers(&|_| Cow::Borrowed("carol")),
+            UserMock::name
+                .next_call(matching!())
+                .answers(&|_| Cow::Owned(String::from("dave"))),
+        ));
+
+        assert_eq!("carol", u.name());
+        assert_eq!("dave", u.name());
+    }
+
+    #[test]
+    fn static_cow_is_owned_output() {
+        let u = Unimock::new((
+            UserMock::greeting
+                .next_call(matching!())
+                .returns(Cow::Borrowed("hello")),
+            UserMock::greeting
+                .next_call(matching!())
+                .returns("hi".to_string()),
+        ));
+
+        assert!(matches!(u.greeting(), Cow::Borrowed("hello")));
+        assert!(matches!(u.greeting(), Cow::Owned(greeting) if greeting == "hi"));
+    }
+}
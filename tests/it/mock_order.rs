@@ -13,7 +13,7 @@ trait T2 {
 
 #[test]
 #[should_panic(
-    expected = "T2::c(2): Method matched in wrong order. Expected a call matching T1::a(2) at tests/it/mock_order.rs:25."
+    expected = "it::mock_order::T2::c(2): Method matched in wrong order. Expected a call matching it::mock_order::T1::a(2) at tests/it/mock_order.rs:25."
 )]
 fn two_fns_in_incorrect_order_should_fail_and_presence_of_a_stub_should_not_influence_order() {
     let m = Unimock::new((
@@ -50,7 +50,7 @@ fn should_give_sensible_panic_message_when_calling_beyond_mocked_order() {
 
 #[test]
 #[should_panic(
-    expected = "T1::a(0): Method invoked in the correct order (3), but inputs didn't match T1::a(1) at tests/it/mock_order.rs:58."
+    expected = "it::mock_order::T1::a(0): Method invoked in the correct order (3), but inputs didn't match it::mock_order::T1::a(1) at tests/it/mock_order.rs:58."
 )]
 fn calling_expired_pattern_should_fail() {
     let m = Unimock::new((
@@ -65,7 +65,7 @@ fn calling_expired_pattern_should_fail() {
 
 #[test]
 #[should_panic(
-    expected = "T1::a(0): Method invoked in the correct order (1), but inputs didn't match T1::a(1 | 2) | (3 | 4) at tests/it/mock_order.rs:71."
+    expected = "it::mock_order::T1::a(0): Method invoked in the correct order (1), but inputs didn't match it::mock_order::T1::a(1 | 2) | (3 | 4) at tests/it/mock_order.rs:71."
 )]
 fn call_order_error_with_complex_pattern() {
     let m = Unimock::new(T1Mock::a.next_call(matching!((1 | 2) | (3 | 4))).returns(0));
@@ -74,9 +74,140 @@ fn call_order_error_with_complex_pattern() {
 
 #[test]
 #[should_panic(
-    expected = "T1::a(0): Method invoked in the correct order (1), but inputs didn't match T1::a(x) if {guard} at tests/it/mock_order.rs:80."
+    expected = "it::mock_order::T1::a(0): Method invoked in the correct order (1), but inputs didn't match it::mock_order::T1::a(x) if {guard} at tests/it/mock_order.rs:80."
 )]
 fn call_order_error_with_guard_pattern() {
     let m = Unimock::new(T1Mock::a.next_call(matching!((x) if x * 2 == 7)).returns(0));
     m.a(0);
 }
+
+#[test]
+#[should_panic(
+    expected = "it::mock_order::T2::c(3): Method matched in wrong order. Expected a call matching it::mock_order::T1::b(2) at tests/it/mock_order.rs:91. This was ordered call (2). Matched in order so far: it::mock_order::T1::a(1) at tests/it/mock_order.rs:90."
+)]
+fn skipped_ordered_call_is_named() {
+    let m = Unimock::new((
+        T1Mock::a.next_call(matching!(1)).returns(1),
+        T1Mock::b.next_call(matching!(2)).returns(2),
+        T2Mock::c.next_call(matching!(3)).returns(3),
+    ));
+
+    assert_eq!(1, m.a(1));
+    m.c(3);
+}
+
+#[test]
+#[should_panic(
+    expected = "Ordered call (3) out of range: There were no more ordered call patterns in line for selection. Matched in order so far: it::mock_order::T1::a(0) at tests/it/mock_order.rs:105, it::mock_order::T1::b(1) at tests/it/mock_order.rs:106."
+)]
+fn out_of_range_call_lists_matched_calls() {
+    let m = Unimock::new((
+        T1Mock::a.next_call(matching!(0)).returns(0),
+        T1Mock::b.next_call(matching!(1)).returns(1),
+    ));
+
+    assert_eq!(0, m.a(0));
+    assert_eq!(1, m.b(1));
+    m.a(2);
+}
+
+mod strict_order {
+    use super::*;
+
+    #[test]
+    fn patterns_are_matched_in_definition_order() {
+        let m = Unimock::new(T1Mock::a.stub(|each| {
+            each.strict_order();
+            each.call(matching!(1)).returns(10).n_times(2);
+            each.call(matching!(_)).returns(20).once();
+        }));
+
+        assert_eq!(10, m.a(1));
+        assert_eq!(10, m.a(1));
+        assert_eq!(20, m.a(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "No matching call patterns")]
+    fn calling_a_later_pattern_first_should_fail() {
+        let m = Unimock::new(T1Mock::a.stub(|each| {
+            each.strict_order();
+            each.call(matching!(1)).returns(10).once();
+            each.call(matching!(2)).returns(20).once();
+        }));
+
+        m.a(2);
+    }
+
+    #[test]
+    fn orders_together_with_next_call() {
+        let m = Unimock::new((
+            T1Mock::a.stub(|each| {
+                each.strict_order();
+                each.call(matching!(1)).returns(10).once();
+                each.call(matching!(2)).returns(20).once();
+            }),
+            T2Mock::c.next_call(matching!(3)).returns(30),
+        ));
+
+        assert_eq!(10, m.a(1));
+        assert_eq!(20, m.a(2));
+        assert_eq!(30, m.c(3));
+    }
+
+    #[test]
+    fn unrelated_calls_may_come_in_between() {
+        let m = Unimock::new((
+            T1Mock::a.stub(|each| {
+                each.strict_order();
+                each.call(matching!(1)).returns(10).once();
+                each.call(matching!(2)).returns(20).once();
+            }),
+            T1Mock::b.each_call(matching!(_)).returns(0),
+            T2Mock::c.next_call(matching!(3)).returns(30),
+        ));
+
+        assert_eq!(10, m.a(1));
+        assert_eq!(0, m.b(0));
+        assert_eq!(30, m.c(3));
+        assert_eq!(20, m.a(2));
+    }
+
+    #[test]
+    fn other_clauses_for_the_same_mock_fn_stay_unordered() {
+        let m = Unimock::new((
+            T1Mock::a.stub(|each| {
+                each.strict_order();
+                each.call(matching!(1)).returns(10).once();
+                each.call(matching!(2)).returns(20).once();
+            }),
+            T1Mock::a.each_call(matching!(9)).returns(90).n_times(2),
+        ));
+
+        assert_eq!(90, m.a(9));
+        assert_eq!(10, m.a(1));
+        assert_eq!(90, m.a(9));
+        assert_eq!(20, m.a(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "No matching call patterns")]
+    fn calls_beyond_the_last_pattern_should_fail() {
+        let m = Unimock::new(T1Mock::a.stub(|each| {
+            each.strict_order();
+            each.call(matching!(1)).returns(10).once();
+        }));
+
+        m.a(1);
+        m.a(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ordered call patterns must expect an exact number of calls.")]
+    fn unquantified_pattern_should_fail() {
+        Unimock::new(T1Mock::a.stub(|each| {
+            each.strict_order();
+            each.call(matching!(1)).returns(10);
+        }));
+    }
+}
@@ -11,7 +11,7 @@ mod should_print_pattern_mismatch_on_call_order_failure {
 
     #[test]
     #[should_panic(
-        expected = "Trait::foo([\"a\"]): Method invoked in the correct order (1), but inputs didn't match Trait::foo([]) at tests/it/pretty_mismatches.rs:17. \nPattern mismatch for input #0 (actual / expected):\n\u{1b}[1mDiff\u{1b}[0m \u{1b}[31m< left\u{1b}[0m / \u{1b}[32mright >\u{1b}[0m :\n\u{1b}[31m<[\u{1b}[0m\u{1b}[1;48;5;52;31m\"a\"\u{1b}[0m\u{1b}[31m]\u{1b}[0m\n\u{1b}[32m>[]\u{1b}[0m\n"
+        expected = "it::pretty_mismatches::should_print_pattern_mismatch_on_call_order_failure::Trait::foo([\"a\"]): Method invoked in the correct order (1), but inputs didn't match it::pretty_mismatches::should_print_pattern_mismatch_on_call_order_failure::Trait::foo([]) at tests/it/pretty_mismatches.rs:17. \nPattern mismatch for input #0 (actual / expected):\n\u{1b}[1mDiff\u{1b}[0m \u{1b}[31m< left\u{1b}[0m / \u{1b}[32mright >\u{1b}[0m :\n\u{1b}[31m<[\u{1b}[0m\u{1b}[1;48;5;52;31m\"a\"\u{1b}[0m\u{1b}[31m]\u{1b}[0m\n\u{1b}[32m>[]\u{1b}[0m\n"
     )]
     fn test() {
         let u = Unimock::new(TraitMock::foo.next_call(matching!([])).returns(()));
@@ -34,7 +34,7 @@ mod should_print_eq_mismatch_on_call_order_failure {
 
     #[test]
     #[should_panic(
-        expected = "Trait::foo(S { value: \"b\" }): Method invoked in the correct order (1), but inputs didn't match Trait::foo(eq!(..)) at tests/it/pretty_mismatches.rs:42. \nEquality mismatch for input #0 (actual / expected):\n\u{1b}[1mDiff\u{1b}[0m \u{1b}[31m< left\u{1b}[0m / \u{1b}[32mright >\u{1b}[0m :\n\u{1b}[31m<S { value: \"\u{1b}[0m\u{1b}[1;48;5;52;31mb\u{1b}[0m\u{1b}[31m\" }\u{1b}[0m\n\u{1b}[32m>S { value: \"\u{1b}[0m\u{1b}[1;48;5;22;32ma\u{1b}[0m\u{1b}[32m\" }\u{1b}[0m\n"
+        expected = "it::pretty_mismatches::should_print_eq_mismatch_on_call_order_failure::Trait::foo(S { value: \"b\" }): Method invoked in the correct order (1), but inputs didn't match it::pretty_mismatches::should_print_eq_mismatch_on_call_order_failure::Trait::foo(eq!(..)) at tests/it/pretty_mismatches.rs:42. \nEquality mismatch for input #0 (actual / expected):\n\u{1b}[1mDiff\u{1b}[0m \u{1b}[31m< left\u{1b}[0m / \u{1b}[32mright >\u{1b}[0m :\n\u{1b}[31m<S { value: \"\u{1b}[0m\u{1b}[1;48;5;52;31mb\u{1b}[0m\u{1b}[31m\" }\u{1b}[0m\n\u{1b}[32m>S { value: \"\u{1b}[0m\u{1b}[1;48;5;22;32ma\u{1b}[0m\u{1b}[32m\" }\u{1b}[0m\n"
     )]
     fn test() {
         let u = Unimock::new(
@@ -66,7 +66,7 @@ mod should_print_message_about_missing_debug {
 
     #[test]
     #[should_panic(
-        expected = "Trait::foo(?): Method invoked in the correct order (1), but inputs didn't match Trait::foo(eq!(..)) at tests/it/pretty_mismatches.rs:74. \nEquality mismatch for input #0:\nActual value did not equal expected value, but can't display diagnostics because the type is likely missing #[derive(Debug)]."
+        expected = "it::pretty_mismatches::should_print_message_about_missing_debug::Trait::foo(?): Method invoked in the correct order (1), but inputs didn't match it::pretty_mismatches::should_print_message_about_missing_debug::Trait::foo(eq!(..)) at tests/it/pretty_mismatches.rs:74. \nEquality mismatch for input #0:\nActual value did not equal expected value, but can't display diagnostics because the type is likely missing #[derive(Debug)]."
     )]
     fn test() {
         let u = Unimock::new(
@@ -98,7 +98,7 @@ mod should_print_message_about_failed_inequality_check {
 
     #[test]
     #[should_panic(
-        expected = "Trait::foo(S { value: \"a\" }): Method invoked in the correct order (1), but inputs didn't match Trait::foo(ne!(..)) at tests/it/pretty_mismatches.rs:106. \nInequality mismatch for input #0:\nS { value: \"a\" }"
+        expected = "it::pretty_mismatches::should_print_message_about_failed_inequality_check::Trait::foo(S { value: \"a\" }): Method invoked in the correct order (1), but inputs didn't match it::pretty_mismatches::should_print_message_about_failed_inequality_check::Trait::foo(ne!(..)) at tests/it/pretty_mismatches.rs:106. \nInequality mismatch for input #0:\nS { value: \"a\" }"
     )]
     fn test() {
         let u = Unimock::new(
@@ -130,7 +130,7 @@ mod should_complain_about_missing_debug_representation_for_inequality_mismatch {
 
     #[test]
     #[should_panic(
-        expected = "Trait::foo(?): Method invoked in the correct order (1), but inputs didn't match Trait::foo(ne!(..)) at tests/it/pretty_mismatches.rs:138. \nInequality mismatch for input #0:\nActual value unexpectedly equalled expected value, but can't display diagnostics because the type is likely missing #[derive(Debug)]."
+        expected = "it::pretty_mismatches::should_complain_about_missing_debug_representation_for_inequality_mismatch::Trait::foo(?): Method invoked in the correct order (1), but inputs didn't match it::pretty_mismatches::should_complain_about_missing_debug_representation_for_inequality_mismatch::Trait::foo(ne!(..)) at tests/it/pretty_mismatches.rs:138. \nInequality mismatch for input #0:\nActual value unexpectedly equalled expected value, but can't display diagnostics because the type is likely missing #[derive(Debug)]."
     )]
     fn test() {
         let u = Unimock::new(
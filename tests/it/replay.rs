@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use unimock::*;
+
+#[unimock(api=InventoryMock)]
+trait Inventory {
+    fn stock(&self, item: &str) -> Option<u32>;
+    fn names(&self) -> Vec<String>;
+    fn name(&self) -> &str;
+}
+
+pub struct NotDeserializable;
+
+#[unimock(api=OpaqueMock)]
+trait Opaque {
+    fn opaque(&self) -> NotDeserializable;
+}
+
+fn write_fixture(name: &str, json: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("unimock-replay-{name}.json"));
+    std::fs::write(&path, json).unwrap();
+    path
+}
+
+#[test]
+fn replays_calls_in_order() {
+    let path = write_fixture(
+        "in-order",
+        r#"[
+            {
+                "name": "it::replay::Inventory::stock",
+                "calls": [
+                    { "inputs": ["\"apple\""], "output": 3 },
+                    { "inputs": ["\"apple\""], "output": null }
+                ]
+            },
+            {
+                "name": "it::replay::Inventory::names",
+                "calls": [{ "inputs": [], "output": ["apple", "pear"] }]
+            }
+        ]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    assert_eq!(Some(3), u.stock("apple"));
+    assert_eq!(vec!["apple".to_string(), "pear".to_string()], u.names());
+    assert_eq!(None, u.stock("apple"));
+}
+
+#[test]
+fn invalid_fixture_is_an_io_error() {
+    let path = write_fixture("invalid", r#"{ "name": "not a list" }"#);
+    let error = Unimock::new_from_file(&path).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn missing_fixture_is_an_io_error() {
+    let error = Unimock::new_from_file(&std::env::temp_dir().join("unimock-replay-missing.json"))
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::NotFound, error.kind());
+}
+
+#[test]
+#[should_panic(
+    expected = "it::replay::Inventory::stock(\"pear\"): The next replayed call is it::replay::Inventory::stock(\"apple\")."
+)]
+fn input_mismatch() {
+    let path = write_fixture(
+        "mismatch",
+        r#"[{ "name": "it::replay::Inventory::stock", "calls": [{ "inputs": ["\"apple\""], "output": 3 }] }]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    u.stock("pear");
+}
+
+#[test]
+#[should_panic(
+    expected = "it::replay::Inventory::stock(\"apple\"): All replayed calls to it::replay::Inventory::stock have already been made."
+)]
+fn exhausted() {
+    let path = write_fixture(
+        "exhausted",
+        r#"[{ "name": "it::replay::Inventory::stock", "calls": [{ "inputs": ["\"apple\""], "output": 3 }] }]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    u.stock("apple");
+    u.stock("apple");
+}
+
+#[test]
+#[should_panic(expected = "it::replay::Inventory::stock: 1 replayed call(s) were never made.")]
+fn calls_left_unreplayed() {
+    let path = write_fixture(
+        "unreplayed",
+        r#"[{ "name": "it::replay::Inventory::stock", "calls": [{ "inputs": ["\"apple\""], "output": 3 }] }]"#,
+    );
+    let _ = Unimock::new_from_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "it::replay::Inventory::stock: Cannot replay output: invalid type: string \"many\""
+)]
+fn output_of_wrong_type() {
+    let path = write_fixture(
+        "wrong-type",
+        r#"[{ "name": "it::replay::Inventory::stock", "calls": [{ "inputs": ["\"apple\""], "output": "many" }] }]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    u.stock("apple");
+}
+
+#[test]
+#[should_panic(
+    expected = "it::replay::Inventory::name: Cannot replay output: the output borrows its data"
+)]
+fn borrowed_output() {
+    let path = write_fixture(
+        "borrowed",
+        r#"[{ "name": "it::replay::Inventory::name", "calls": [{ "inputs": [], "output": "apple" }] }]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    u.name();
+}
+
+#[test]
+#[should_panic(
+    expected = "it::replay::Opaque::opaque: Cannot replay output: the output does not implement `serde::de::DeserializeOwned`"
+)]
+fn output_not_deserializable() {
+    let path = write_fixture(
+        "opaque",
+        r#"[{ "name": "it::replay::Opaque::opaque", "calls": [{ "inputs": [], "output": null }] }]"#,
+    );
+    let u = Unimock::new_from_file(&path).unwrap();
+
+    let _ = u.opaque();
+}
@@ -0,0 +1,126 @@
+use unimock::alloc::{vec, ToString};
+use unimock::*;
+
+#[unimock(api=ServiceMock, unmock_with=[real_get, _])]
+trait Service {
+    fn get(&self, key: &str) -> i32;
+    fn put(&self, key: &str, value: i32);
+}
+
+fn real_get(_: &impl core::any::Any, key: &str) -> i32 {
+    key.len() as i32
+}
+
+#[test]
+fn reports_expected_and_actual_calls() {
+    let mut u = Unimock::new((
+        ServiceMock::get
+            .each_call(matching!("a"))
+            .returns(1)
+            .n_times(2),
+        ServiceMock::put.next_call(matching!(_, _)).returns(()),
+    ));
+
+    assert_eq!(1, u.get("a"));
+
+    let report = u.verification_report();
+    assert!(!report.satisfied);
+    assert_eq!(None, report.calls);
+
+    let get = report
+        .mock_fns
+        .iter()
+        .find(|mock_fn| mock_fn.path.ends_with("Service::get"))
+        .unwrap();
+    assert!(!get.in_order);
+    assert!(!get.satisfied);
+    assert_eq!(
+        vec![CallPatternReport {
+            pattern: "it::report::Service::get(\"a\") at tests/it/report.rs:18".to_string(),
+            label: None,
+            expected_calls: 2,
+            exact: true,
+            actual_calls: 1,
+            satisfied: false,
+        }],
+        get.call_patterns
+    );
+
+    let put = report
+        .mock_fns
+        .iter()
+        .find(|mock_fn| mock_fn.path.ends_with("Service::put"))
+        .unwrap();
+    assert!(put.in_order);
+    assert!(!put.satisfied);
+    assert_eq!(0, put.call_patterns[0].actual_calls);
+
+    assert_eq!(1, u.get("a"));
+    u.put("a", 1);
+    assert!(u.verification_report().satisfied);
+    u.verify();
+}
+
+#[test]
+fn includes_recorded_calls() {
+    let (u, _recording) = Unimock::spy_record(());
+    assert_eq!(3, u.get("abc"));
+
+    assert_eq!(
+        Some(vec!["it::report::Service::get(\"abc\")".to_string()]),
+        u.verification_report().calls
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_to_json() {
+    let u = Unimock::new(ServiceMock::get.each_call(matching!(_)).returns(1).once());
+
+    assert_eq!(
+        r#"{
+  "satisfied": false,
+  "mock_fns": [
+    {
+      "path": "it::report::Service::get",
+      "in_order": false,
+      "satisfied": false,
+      "call_patterns": [
+        {
+          "pattern": "it::report::Service::get(_) at tests/it/report.rs:78",
+          "label": null,
+          "expected_calls": 1,
+          "exact": true,
+          "actual_calls": 0,
+          "satisfied": false
+        }
+      ]
+    }
+  ],
+  "calls": null
+}"#,
+        serde_json::to_string_pretty(&u.verification_report()).unwrap()
+    );
+
+    assert_eq!(1, u.get("x"));
+}
+
+#[test]
+fn reports_pattern_labels() {
+    let u = Unimock::new(
+        ServiceMock::get
+            .each_call(matching!(_))
+            .returns(1)
+            .named("any key"),
+    );
+
+    let report = u.verification_report();
+    let pattern = &report.mock_fns[0].call_patterns[0];
+    assert_eq!(Some("any key".to_string()), pattern.label);
+    assert_eq!(
+        "it::report::Service::get(_) \"any key\" at tests/it/report.rs:112",
+        pattern.pattern
+    );
+
+    assert_eq!(1, u.get("x"));
+}
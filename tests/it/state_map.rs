@@ -0,0 +1,87 @@
+use unimock::*;
+
+#[unimock(api=ConfigMock)]
+trait Config {
+    fn set(&self, key: String, value: i32);
+    fn get(&self, key: String) -> Option<i32>;
+    fn get_or_fail(&self, key: String) -> i32;
+}
+
+#[test]
+fn get_returns_what_was_set() {
+    let u = Unimock::new(state_map(ConfigMock::set, ConfigMock::get).default_value(None));
+
+    assert_eq!(None, u.get("a".to_string()));
+    u.set("a".to_string(), 1);
+    u.set("b".to_string(), 2);
+    u.set("a".to_string(), 3);
+    assert_eq!(Some(3), u.get("a".to_string()));
+    assert_eq!(Some(2), u.get("b".to_string()));
+}
+
+#[test]
+fn functions_may_go_uncalled() {
+    let _ = Unimock::new(state_map(ConfigMock::set, ConfigMock::get_or_fail));
+}
+
+#[test]
+#[should_panic = "it::state_map::Config::get_or_fail: No value was set for the key \"missing\"."]
+fn unknown_key_fails_without_default() {
+    let u = Unimock::new(state_map(ConfigMock::set, ConfigMock::get_or_fail));
+
+    u.set("a".to_string(), 1);
+    assert_eq!(1, u.get_or_fail("a".to_string()));
+    u.get_or_fail("missing".to_string());
+}
+
+#[test]
+fn unknown_keys_are_recorded() {
+    let mut u = Unimock::new(
+        state_map(ConfigMock::set, ConfigMock::get_or_fail)
+            .default_value(-1)
+            .record_unknown_keys(),
+    );
+
+    assert_eq!(-1, u.get_or_fail("missing".to_string()));
+
+    let errors = u.drain_errors();
+    assert_eq!(MockErrorKind::UnknownStateKey, errors[0].kind());
+    assert_eq!(
+        "it::state_map::Config::get_or_fail: No value was set for the key \"missing\".",
+        errors[0].to_string()
+    );
+}
+
+#[test]
+fn other_clauses_take_precedence() {
+    let u = Unimock::new((
+        state_map(ConfigMock::set, ConfigMock::get).default_value(None),
+        ConfigMock::get
+            .each_call(matching!("fixed"))
+            .returns(Some(0)),
+    ));
+
+    u.set("fixed".to_string(), 1);
+    assert_eq!(Some(0), u.get("fixed".to_string()));
+}
+
+#[test]
+fn concurrent_access_from_two_threads() {
+    let u = Unimock::new(state_map(ConfigMock::set, ConfigMock::get).default_value(None));
+
+    std::thread::scope(|scope| {
+        for thread in 0..2 {
+            let u = u.clone();
+            scope.spawn(move || {
+                for index in 0..100 {
+                    let key = format!("{thread}-{index}");
+                    u.set(key.clone(), index);
+                    assert_eq!(Some(index), u.get(key));
+                }
+            });
+        }
+    });
+
+    assert_eq!(Some(99), u.get("0-99".to_string()));
+    assert_eq!(Some(99), u.get("1-99".to_string()));
+}
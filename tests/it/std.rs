@@ -78,6 +78,37 @@ fn test_write() {
     write!(&mut unimock, "hello {}", "world".to_string()).unwrap();
 }
 
+#[test]
+fn test_fmt_write_captures_output() {
+    use std::sync::Mutex;
+    use unimock::mock::std::fmt::WriteMock as FmtWriteMock;
+
+    let mut unimock = Unimock::new(FmtWriteMock::write_str.each_call(matching!(_)).answers(
+        &|u, s| {
+            u.get_value::<Mutex<String>>()
+                .unwrap()
+                .lock()
+                .unwrap()
+                .push_str(s);
+            Ok(())
+        },
+    ))
+    .with_value(Mutex::new(String::new()));
+
+    std::fmt::Write::write_fmt(&mut unimock, format_args!("{}-{}", 1, "two")).unwrap();
+    std::fmt::Write::write_char(&mut unimock, '!').unwrap();
+
+    assert_eq!(
+        "1-two!",
+        unimock
+            .get_value::<Mutex<String>>()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .as_str()
+    );
+}
+
 #[test]
 #[should_panic = "Write::write_all([119, 111, 114, 108, 100]): Ordered call (2) out of range"]
 fn test_write_fail() {
@@ -180,4 +211,581 @@ mod termination {
         );
         assert_eq!("ExitCode(unix_exit_status(0))", format!("{:?}", u.report()));
     }
+
+    #[test]
+    fn unmatched_mocked_termination_is_an_error() {
+        let u = Unimock::new(
+            TerminationMock::report
+                .each_call(matching!())
+                .returns(ExitCode::SUCCESS)
+                .only_if(|| false),
+        );
+        assert_eq!(
+            MockErrorKind::NoMatchingCallPatterns,
+            u.try_eval::<TerminationMock::report>(())
+                .err()
+                .unwrap()
+                .kind()
+        );
+        u.disarm();
+    }
+}
+
+mod io_error_matching {
+    use std::io::{self, ErrorKind};
+
+    use super::*;
+
+    #[unimock(api=IoResultMock)]
+    trait IoResult {
+        fn handle(&self, result: io::Result<u32>) -> &'static str;
+    }
+
+    #[test]
+    fn matches_on_error_kind() {
+        let u = Unimock::new((
+            IoResultMock::handle
+                .each_call(matching!(io_error!(ErrorKind::NotFound)))
+                .returns("not found"),
+            IoResultMock::handle
+                .each_call(matching!(Ok(_)))
+                .returns("ok"),
+            IoResultMock::handle
+                .each_call(matching!(_))
+                .returns("other"),
+        ));
+
+        assert_eq!("not found", u.handle(Err(ErrorKind::NotFound.into())));
+        assert_eq!("ok", u.handle(Ok(42)));
+        assert_eq!("other", u.handle(Err(ErrorKind::InvalidData.into())));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "IoResult::handle(Err(Kind(InvalidData))): No matching call patterns."
+    )]
+    fn unmatched_error_kind() {
+        Unimock::new(
+            IoResultMock::handle
+                .each_call(matching!(io_error!(ErrorKind::NotFound)))
+                .returns("not found"),
+        )
+        .handle(Err(ErrorKind::InvalidData.into()));
+    }
+}
+
+mod convert {
+    use std::path::PathBuf;
+
+    use unimock::{mock::std::convert::IntoMock, *};
+
+    fn describe(name: impl Into<String>, path: impl Into<PathBuf>) -> String {
+        format!("{} at {}", name.into(), path.into().display())
+    }
+
+    #[test]
+    fn mocked_into_per_target_type() {
+        let u = Unimock::new((
+            IntoMock::into
+                .with_types::<String>()
+                .next_call(matching!())
+                .returns("config"),
+            IntoMock::into
+                .with_types::<PathBuf>()
+                .next_call(matching!())
+                .returns(PathBuf::from("/etc/app.toml")),
+        ));
+
+        assert_eq!("config at /etc/app.toml", describe(u.clone(), u.clone()));
+    }
+
+    #[test]
+    #[should_panic = "unimock::mock::std::convert::IntoTarget::into: Expected unimock::mock::std::convert::IntoTarget::into() at tests/it/std.rs:308 to match exactly 1 call, but it actually matched no calls."]
+    fn unused_conversion_is_verified() {
+        Unimock::new(
+            IntoMock::into
+                .with_types::<Vec<u8>>()
+                .next_call(matching!())
+                .returns(vec![1, 2, 3]),
+        );
+    }
+}
+
+mod io_seek_and_buf_read {
+    use std::io::{BufRead, Seek, SeekFrom};
+
+    use unimock::{
+        mock::std::io::{BufReadMock, SeekMock},
+        *,
+    };
+
+    #[test]
+    fn seek() {
+        let mut u = Unimock::new((
+            SeekMock::seek
+                .next_call(matching!(SeekFrom::End(-4)))
+                .returns(Ok(12)),
+            SeekMock::seek
+                .next_call(matching!(SeekFrom::Start(0)))
+                .returns(Ok(0)),
+        ));
+
+        assert_eq!(12, u.seek(SeekFrom::End(-4)).unwrap());
+        // `rewind` is not mocked, so it delegates to `seek` through its default implementation
+        u.rewind().unwrap();
+    }
+
+    #[test]
+    fn buf_read_default_impl_uses_fill_buf_and_consume() {
+        let mut u = Unimock::new((
+            BufReadMock::fill_buf
+                .next_call(matching!())
+                .returns(Ok::<_, std::io::Error>(b"line\nrest".to_vec())),
+            BufReadMock::consume.next_call(matching!(5)).returns(()),
+        ));
+
+        let mut line = String::new();
+        assert_eq!(5, u.read_line(&mut line).unwrap());
+        assert_eq!("line\n", line);
+    }
+}
+
+mod fmt_error {
+    use core::fmt::Write;
+
+    use unimock::{mock::core::fmt::DebugMock, *};
+
+    #[test]
+    fn debug_error_propagates_to_formatter() {
+        let u = Unimock::new(
+            DebugMock::fmt
+                .next_call(matching!())
+                .returns(Err(core::fmt::Error)),
+        );
+
+        let mut output = String::new();
+        assert_eq!(Err(core::fmt::Error), write!(&mut output, "{u:?}"));
+    }
+}
+
+mod iterator {
+    use unimock::{
+        mock::core::iter::{self, IteratorMock},
+        *,
+    };
+
+    #[test]
+    fn returns_items_then_none() {
+        let u = Unimock::new(iter::returns_items(["a", "b"]));
+        let mut items = u.as_iterator::<&str>();
+
+        assert_eq!((0, None), items.size_hint());
+        assert_eq!(Some("a"), items.next());
+        assert_eq!(Some("b"), items.next());
+        assert_eq!(None, items.next());
+        assert_eq!(None, items.next());
+    }
+
+    #[test]
+    fn item_types_are_independent() {
+        let u = Unimock::new((iter::returns_items([1, 2]), iter::returns_items(["x"])));
+
+        assert_eq!(vec![1, 2], u.as_iterator::<i32>().collect::<Vec<_>>());
+        assert_eq!(vec!["x"], u.as_iterator::<&str>().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic = "stop here"]
+    fn panics_midway() {
+        let u = Unimock::new(
+            IteratorMock::next
+                .with_types::<i32>()
+                .some_call(matching!())
+                .returns(Some(1))
+                .once()
+                .then()
+                .panics("stop here"),
+        );
+
+        for _ in u.as_iterator::<i32>() {}
+    }
+
+    #[test]
+    #[should_panic = "unimock::mock::core::iter::IteratorOf::next: Expected call pattern unimock::mock::core::iter::IteratorOf::next[#0] defined at tests/it/std.rs:416 to match at least 3 calls, but it actually matched 1 call."]
+    fn unconsumed_items_fail_verification() {
+        let u = Unimock::new(iter::returns_items([1, 2]));
+        u.as_iterator::<i32>().next();
+    }
+}
+
+mod error_source_chain {
+    use std::error::Error;
+
+    use unimock::{
+        mock::{
+            core::fmt::DisplayMock,
+            std::error::{ErrorMock, ErrorSource},
+        },
+        *,
+    };
+
+    fn report(error: &dyn Error) -> String {
+        let mut report = error.to_string();
+        let mut source = error.source();
+        while let Some(error) = source {
+            report.push_str(&format!(": {error}"));
+            source = error.source();
+        }
+        report
+    }
+
+    fn mock_error(msg: &'static str, source: Option<ErrorSource>) -> Unimock {
+        Unimock::new((
+            DisplayMock::fmt
+                .each_call(matching!(_))
+                .answers_arc(std::sync::Arc::new(move |_, f| write!(f, "{msg}"))),
+            ErrorMock::source.each_call(matching!()).returns(source),
+        ))
+    }
+
+    #[test]
+    fn three_levels() {
+        let bottom = mock_error("bottom", None);
+        let middle = mock_error("middle", Some(ErrorSource::new(bottom)));
+        let top = mock_error("top", Some(ErrorSource::new(middle)));
+
+        assert_eq!("top: middle: bottom", report(&top));
+    }
+
+    #[test]
+    fn std_error_as_source() {
+        let top = mock_error(
+            "failed to load",
+            Some(ErrorSource::new(std::io::Error::other("disk on fire"))),
+        );
+
+        assert_eq!("failed to load: disk on fire", report(&top));
+        assert!(top.source().unwrap().is::<std::io::Error>());
+    }
+}
+
+mod write_faults {
+    use std::io::{ErrorKind, Write};
+
+    use unimock::{mock::std::io::WriteMock, *};
+
+    #[test]
+    fn write_all_retries_short_writes() {
+        let mut u = Unimock::new((
+            WriteMock::write.short_writes(4),
+            WriteMock::flush.next_call(matching!()).returns(Ok(())),
+        ));
+
+        u.write_all(b"hello world").unwrap();
+        u.flush().unwrap();
+    }
+
+    #[test]
+    fn short_writes_accepts_partial_buffers() {
+        let mut u = Unimock::new(WriteMock::write.short_writes(4));
+
+        assert_eq!(4, u.write(b"hello world").unwrap());
+        assert_eq!(3, u.write(b"rld").unwrap());
+    }
+
+    #[test]
+    fn write_all_fails_after_two_writes() {
+        let mut u = Unimock::new(WriteMock::write.fails_after(2, ErrorKind::BrokenPipe));
+
+        assert_eq!(3, u.write(b"one").unwrap());
+        assert_eq!(3, u.write(b"two").unwrap());
+        assert_eq!(
+            ErrorKind::BrokenPipe,
+            u.write_all(b"three").unwrap_err().kind()
+        );
+        assert_eq!(ErrorKind::BrokenPipe, u.write(b"four").unwrap_err().kind());
+    }
+
+    #[test]
+    fn fails_after_zero_fails_immediately() {
+        let mut u = Unimock::new(WriteMock::write.fails_after(0, ErrorKind::Other));
+
+        assert_eq!(ErrorKind::Other, u.write(b"x").unwrap_err().kind());
+    }
+
+    #[test]
+    #[should_panic = "std::io::Write::write: Expected call pattern std::io::Write::write[#0] defined at tests/it/std.rs:519 to match at least 3 calls, but it actually matched 1 call."]
+    fn fails_after_must_reach_the_failure() {
+        let mut u = Unimock::new(WriteMock::write.fails_after(2, ErrorKind::BrokenPipe));
+
+        assert_eq!(3, u.write(b"one").unwrap());
+    }
+
+    #[test]
+    fn fmt_write_fails_after() {
+        use unimock::mock::std::fmt::WriteMock as FmtWriteMock;
+
+        let mut u = Unimock::new(FmtWriteMock::write_str.fails_after(2));
+
+        assert!(std::fmt::Write::write_str(&mut u, "a").is_ok());
+        assert!(std::fmt::Write::write_char(&mut u, 'b').is_ok());
+        assert!(std::fmt::Write::write_str(&mut u, "c").is_err());
+    }
+}
+
+mod btree_map {
+    use std::collections::BTreeMap;
+    use std::ops::Bound;
+
+    use unimock::{
+        mock::std::collections::{BTreeMapLike, BTreeMapMock},
+        *,
+    };
+
+    /// Caches a computed value, unless already present.
+    fn get_or_compute(cache: &mut impl BTreeMapLike<u32, String>, key: u32) -> String {
+        if let Some(value) = cache.get(&key) {
+            return value.clone();
+        }
+        let value = format!("value{key}");
+        cache.insert(key, value.clone());
+        value
+    }
+
+    fn evict_between(cache: &mut impl BTreeMapLike<u32, String>, from: u32, to: u32) -> usize {
+        let keys: Vec<u32> = cache
+            .range((Bound::Included(&from), Bound::Included(&to)))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            cache.remove(key);
+        }
+        keys.len()
+    }
+
+    #[test]
+    fn real_map() {
+        let mut map = BTreeMap::new();
+        assert_eq!("value1", get_or_compute(&mut map, 1));
+        assert_eq!("value5", get_or_compute(&mut map, 5));
+        assert!(BTreeMapLike::contains_key(&map, &5));
+
+        assert_eq!(1, evict_between(&mut map, 2, 9));
+        assert!(!BTreeMapLike::contains_key(&map, &5));
+    }
+
+    #[test]
+    fn cache_hit_and_miss() {
+        let mut u = Unimock::new((
+            BTreeMapMock::get
+                .with_types::<u32, String>()
+                .next_call(matching!(1))
+                .returns(Some("cached".to_string())),
+            BTreeMapMock::get
+                .with_types::<u32, String>()
+                .next_call(matching!(2))
+                .returns(None::<String>),
+            BTreeMapMock::insert
+                .with_types::<u32, String>()
+                .next_call(matching!((2, value) if value == "value2"))
+                .returns(None),
+        ));
+
+        assert_eq!("cached", get_or_compute(&mut u, 1));
+        assert_eq!("value2", get_or_compute(&mut u, 2));
+    }
+
+    #[test]
+    fn range_returns_vec_backed_iterator() {
+        let mut u = Unimock::new((
+            BTreeMapMock::range
+                .with_types::<u32, String>()
+                .next_call(matching!((Bound::Included(2), Bound::Included(9))))
+                .returns(vec![(3, "a".to_string()), (7, "b".to_string())].into_iter()),
+            BTreeMapMock::remove
+                .with_types::<u32, String>()
+                .next_call(matching!(3))
+                .returns(Some("a".to_string())),
+            BTreeMapMock::remove
+                .with_types::<u32, String>()
+                .next_call(matching!(7))
+                .returns(Some("b".to_string())),
+        ));
+
+        assert_eq!(2, evict_between(&mut u, 2, 9));
+    }
+}
+
+mod from_str {
+    use unimock::mock::std::str::{scoped, FromStrMock, ParseError};
+    use unimock::*;
+
+    fn parse_port<T: core::str::FromStr>(config: &str) -> Result<T, T::Err> {
+        config.trim_start_matches("port=").parse()
+    }
+
+    #[test]
+    fn parses_valid_and_invalid_input() {
+        let u = Unimock::new((
+            FromStrMock::from_str
+                .next_call(matching!("8080"))
+                .answers(&|u, _| Ok(u.clone())),
+            FromStrMock::from_str
+                .next_call(matching!("http"))
+                .returns(Err(ParseError::new("not a number"))),
+        ));
+
+        scoped(&u, || {
+            assert!(parse_port::<Unimock>("port=8080").is_ok());
+            assert_eq!(
+                ParseError::new("not a number"),
+                parse_port::<Unimock>("port=http").unwrap_err()
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic = "Parsing a Unimock requires an instance installed with `unimock::mock::std::str::scoped`."]
+    fn panics_outside_of_scope() {
+        let _ = "8080".parse::<Unimock>();
+    }
+
+    #[test]
+    #[should_panic = "std::str::FromStr::from_str(\"x\"): No mock implementation found."]
+    fn unmocked_input_is_an_error() {
+        let u = Unimock::new(());
+
+        scoped(&u, || {
+            let _ = "x".parse::<Unimock>();
+        });
+    }
+}
+
+mod rw_lock {
+    use std::sync::Arc;
+
+    use unimock::{
+        mock::std::sync::{LockedValue, RwLockLike, RwLockMock},
+        *,
+    };
+
+    fn append(list: &impl RwLockLike<Vec<u32>>, item: u32) -> usize {
+        list.write().push(item);
+        list.read().len()
+    }
+
+    fn mock_lock(value: &LockedValue<Vec<u32>>) -> Unimock {
+        Unimock::new((
+            RwLockMock::read
+                .with_types::<Vec<u32>>()
+                .each_call(matching!())
+                .answers_arc({
+                    let value = value.clone();
+                    Arc::new(move |_| value.read())
+                }),
+            RwLockMock::write
+                .with_types::<Vec<u32>>()
+                .each_call(matching!())
+                .answers_arc({
+                    let value = value.clone();
+                    Arc::new(move |_| value.write())
+                }),
+        ))
+    }
+
+    #[test]
+    fn in_memory_lock() {
+        let list = LockedValue::new(vec![1]);
+        assert_eq!(2, append(&list, 2));
+        assert_eq!(vec![1, 2], list.get());
+    }
+
+    #[test]
+    fn writes_are_visible_to_subsequent_reads() {
+        let value = LockedValue::new(vec![1, 2]);
+        let u = mock_lock(&value);
+
+        assert_eq!(3, append(&u, 3));
+        assert_eq!(4, append(&u, 4));
+        assert_eq!(vec![1, 2, 3, 4], value.get());
+    }
+
+    #[test]
+    fn read_guard_is_a_snapshot() {
+        let value = LockedValue::new(vec![1]);
+        let u = Unimock::new(
+            RwLockMock::read
+                .with_types::<Vec<u32>>()
+                .each_call(matching!())
+                .answers_arc({
+                    let value = value.clone();
+                    Arc::new(move |_| value.read())
+                }),
+        );
+
+        let guard = RwLockLike::<Vec<u32>>::read(&u);
+        value.set(vec![]);
+        assert_eq!(vec![1], *guard);
+        assert!(RwLockLike::<Vec<u32>>::read(&u).is_empty());
+    }
+}
+
+mod net {
+    use std::io::{ErrorKind, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    use unimock::mock::std::io::{ReadMock, WriteMock};
+    use unimock::mock::std::net::{TcpStreamLike, TcpStreamMock};
+    use unimock::*;
+
+    fn send_line(stream: &impl TcpStreamLike, line: &str) -> std::io::Result<String> {
+        let mut writer = stream.try_clone()?;
+        writer.write_all(line.as_bytes())?;
+
+        let mut reader = stream.try_clone()?;
+        let mut reply = String::new();
+        reader.read_to_string(&mut reply)?;
+        Ok(reply)
+    }
+
+    #[test]
+    fn try_clone_shares_state_with_the_original() {
+        let u = Unimock::new((
+            TcpStreamMock::try_clone.shares_state(),
+            WriteMock::write_all
+                .next_call(matching!(eq!(b"hello")))
+                .returns(Ok(())),
+            ReadMock::read_to_string
+                .next_call(matching!(_))
+                .answers(&|_, buf| {
+                    buf.push_str("world");
+                    Ok(5)
+                }),
+        ));
+
+        assert_eq!("world", send_line(&u, "hello").unwrap());
+    }
+
+    #[test]
+    fn try_clone_can_fail() {
+        let u = Unimock::new(
+            TcpStreamMock::try_clone
+                .next_call(matching!())
+                .answers(&|_| Err(ErrorKind::ConnectionReset.into())),
+        );
+
+        assert_eq!(
+            ErrorKind::ConnectionReset,
+            send_line(&u, "hello").unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn real_tcp_stream_implements_the_trait() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        let mut clone = TcpStreamLike::try_clone(&stream).unwrap();
+        clone.flush().unwrap();
+    }
 }
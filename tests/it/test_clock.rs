@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use unimock::clock::{Clock, ClockMock, MonotonicClock, MonotonicClockMock, SystemClock};
+use unimock::*;
+
+fn elapsed_secs(clock: &impl Clock, since: SystemTime) -> u64 {
+    clock.now().duration_since(since).unwrap().as_secs()
+}
+
+#[test]
+fn returns_epoch_plus() {
+    let u = Unimock::new(ClockMock::now.returns_epoch_plus(42));
+
+    assert_eq!(42, elapsed_secs(&u, UNIX_EPOCH));
+    assert_eq!(42, elapsed_secs(&u, UNIX_EPOCH));
+}
+
+#[test]
+fn returns_epoch_plus_once() {
+    let u = Unimock::new(
+        ClockMock::now
+            .returns_epoch_plus(1)
+            .once()
+            .then()
+            .returns(UNIX_EPOCH + Duration::from_secs(5)),
+    );
+
+    assert_eq!(1, elapsed_secs(&u, UNIX_EPOCH));
+    assert_eq!(5, elapsed_secs(&u, UNIX_EPOCH));
+    assert_eq!(5, elapsed_secs(&u, UNIX_EPOCH));
+}
+
+#[test]
+fn system_time_ticks() {
+    let start = UNIX_EPOCH + Duration::from_secs(1000);
+    let u = Unimock::new(ClockMock::now.ticks(start, Duration::from_secs(30)));
+
+    assert_eq!(0, elapsed_secs(&u, start));
+    assert_eq!(30, elapsed_secs(&u, start));
+    assert_eq!(60, elapsed_secs(&u, start));
+}
+
+#[test]
+fn instant_ticks() {
+    let start = Instant::now();
+    let u = Unimock::new(MonotonicClockMock::now.ticks(start, Duration::from_millis(500)));
+
+    assert_eq!(start, MonotonicClock::now(&u));
+    assert_eq!(start + Duration::from_millis(500), MonotonicClock::now(&u));
+    assert_eq!(start + Duration::from_secs(1), MonotonicClock::now(&u));
+}
+
+#[test]
+fn partial_mock_reads_real_time() {
+    let before = SystemTime::now();
+    let u = Unimock::new_partial(());
+
+    assert!(Clock::now(&u) >= before);
+    assert!(MonotonicClock::now(&u) <= Instant::now());
+}
+
+#[test]
+fn partial_mock_with_overridden_clock() {
+    let before = Instant::now();
+    let u = Unimock::new_partial(ClockMock::now.returns_epoch_plus(7));
+
+    assert_eq!(7, elapsed_secs(&u, UNIX_EPOCH));
+    assert!(MonotonicClock::now(&u) >= before);
+}
+
+#[test]
+fn system_clock_is_real() {
+    let before = SystemTime::now();
+    assert!(Clock::now(&SystemClock) >= before);
+
+    let before = Instant::now();
+    assert!(MonotonicClock::now(&SystemClock) >= before);
+}
+
+#[test]
+#[should_panic(
+    expected = "Clock::now[#0] defined at tests/it/test_clock.rs:87 to match exactly 1 call"
+)]
+fn unused_ticks_points_at_caller() {
+    Unimock::new(
+        ClockMock::now
+            .ticks(UNIX_EPOCH, Duration::from_secs(1))
+            .once(),
+    );
+}
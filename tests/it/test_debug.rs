@@ -67,3 +67,48 @@ mod slice {
         Unimock::new(()).dbg_slice(&[Dbg]);
     }
 }
+
+mod unimock_summary {
+    use unimock::alloc::{format, String, ToString};
+    use unimock::*;
+
+    #[unimock(api=SummaryMock)]
+    trait Summary {
+        fn a(&self, input: String) -> i32;
+        fn b(&self) -> i32;
+    }
+
+    #[test]
+    fn summary_lists_fns_without_inputs() {
+        let u = Unimock::new((
+            SummaryMock::a.stub(|each| {
+                each.call(matching!("secret")).returns(1);
+                each.call(matching!(_)).returns(2);
+            }),
+            SummaryMock::b.next_call(matching!()).returns(3),
+        ));
+
+        assert_eq!(1, u.a("secret".to_string()));
+        assert_eq!(2, u.a("other".to_string()));
+        assert_eq!(2, u.a("other".to_string()));
+        assert_eq!(3, u.b());
+
+        let summary = format!("{:?}", u.debug_summary());
+        assert!(!summary.contains("secret"));
+        assert!(summary.starts_with("Unimock { fallback_mode: Error, fns: ["));
+        assert!(summary
+            .contains("MockFn { path: it::test_debug::unimock_summary::Summary::a, patterns: 2, mode: InAnyOrder, calls: [1, 2] }"));
+        assert!(
+            summary.contains("MockFn { path: it::test_debug::unimock_summary::Summary::b, patterns: 1, mode: InOrder, calls: [1] }")
+        );
+    }
+
+    #[cfg(not(feature = "mock-core"))]
+    #[test]
+    fn debug_impl_prints_summary() {
+        let u = Unimock::new(SummaryMock::b.each_call(matching!()).returns(3));
+        assert_eq!(3, u.b());
+
+        assert_eq!(format!("{:?}", u.debug_summary()), format!("{u:?}"));
+    }
+}
@@ -0,0 +1,64 @@
+use unimock::alloc::{String, ToString};
+use unimock::*;
+
+#[unimock(api=KeyValueMock, test_helpers)]
+trait KeyValue {
+    fn get(&self, key: &str) -> Option<String>;
+    fn count(&self) -> usize;
+    fn name(&self) -> &str;
+    fn generic<T: 'static>(&self, t: T);
+}
+
+#[test]
+fn default_and_returns_factories() {
+    let u = Unimock::new((
+        test_helpers::stub_key_value_get_default(),
+        test_helpers::stub_key_value_count_returns(3_usize),
+        test_helpers::stub_key_value_name_returns("n"),
+    ));
+
+    assert_eq!(None, u.get("a"));
+    assert_eq!(None, u.get("b"));
+    assert_eq!(3, u.count());
+    assert_eq!("n", u.name());
+}
+
+#[test]
+fn factories_combine_with_regular_clauses() {
+    let u = Unimock::new((
+        test_helpers::stub_key_value_get_returns(Some("v".to_string())),
+        KeyValueMock::count.next_call(matching!()).returns(1_usize),
+    ));
+
+    assert_eq!(Some("v".to_string()), u.get("a"));
+    assert_eq!(1, u.count());
+}
+
+mod custom_module_name {
+    use unimock::*;
+
+    #[unimock(api=[PingMock], test_helpers=ping_helpers)]
+    trait Ping {
+        fn ping(&self) -> i32;
+    }
+
+    // No helpers are generated for outputs that can't be returned
+    #[unimock(api=PongMock, test_helpers=pong_helpers)]
+    pub trait Pong {
+        fn pong(&mut self) -> &mut i32;
+        fn pong_ref(&self) -> &i32;
+    }
+
+    #[test]
+    fn flattened_api() {
+        let u = Unimock::new(ping_helpers::stub_ping_ping_default());
+        assert_eq!(0, u.ping());
+        assert_eq!(0, u.ping());
+    }
+
+    #[test]
+    fn borrowed_output_only_has_returns_helper() {
+        let u = Unimock::new(pong_helpers::stub_pong_pong_ref_returns(7));
+        assert_eq!(&7, u.pong_ref());
+    }
+}
@@ -0,0 +1,90 @@
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::Wake;
+
+use futures_util_0_3::{Stream, StreamExt};
+use unimock::{
+    mock::futures_0_3::stream::{self, StreamMock},
+    *,
+};
+
+use crate::AsyncTest;
+
+#[derive(Default)]
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn stream_next_await() {
+    async {
+        let u = Unimock::new(
+            stream::yields(["a", "b"])
+                .then_pending()
+                .then_yields(["c"])
+                .then_none(),
+        );
+
+        let mut stream = u.as_stream::<&str>();
+        assert_eq!(Some("a"), stream.next().await);
+        assert_eq!(Some("b"), stream.next().await);
+        assert_eq!(Some("c"), stream.next().await);
+        assert_eq!(None, stream.next().await);
+    }
+    .test()
+}
+
+#[test]
+fn manual_poll_next_wakes_on_pending() {
+    let u = Unimock::new(stream::yields([1]).then_pending().then_none());
+    let counting_waker = Arc::new(CountingWaker::default());
+    let waker = Waker::from(counting_waker.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut stream = u.as_stream::<i32>();
+    assert_eq!(
+        Poll::Ready(Some(1)),
+        Pin::new(&mut stream).poll_next(&mut cx)
+    );
+    assert_eq!(0, counting_waker.0.load(Ordering::SeqCst));
+
+    assert_eq!(Poll::Pending, Pin::new(&mut stream).poll_next(&mut cx));
+    assert_eq!(1, counting_waker.0.load(Ordering::SeqCst));
+
+    assert_eq!(Poll::Ready(None), Pin::new(&mut stream).poll_next(&mut cx));
+}
+
+#[test]
+fn size_hint() {
+    async {
+        let u = Unimock::new((
+            stream::yields([1, 2]).then_none(),
+            StreamMock::size_hint
+                .with_types::<i32>()
+                .some_call(matching!())
+                .returns((2, Some(2))),
+        ));
+
+        let stream = u.as_stream::<i32>();
+        assert_eq!((2, Some(2)), stream.size_hint());
+        assert_eq!(vec![1, 2], stream.collect::<Vec<_>>().await);
+    }
+    .test()
+}
+
+#[test]
+#[should_panic = "StreamOf::poll_next: Expected call pattern unimock::mock::futures_0_3::stream::StreamOf::poll_next[#0] defined at tests/it/test_mock_futures.rs:85 to match exactly 3 calls, but it actually matched 1 call."]
+fn unfinished_script_is_verified() {
+    async {
+        let u = Unimock::new(stream::yields([1, 2]).then_none());
+
+        assert_eq!(Some(1), u.as_stream::<i32>().next().await);
+    }
+    .test()
+}
@@ -0,0 +1,86 @@
+use log_0_4::{Level, Log};
+use unimock::mock::log_0_4::{scoped, LogMock};
+use unimock::*;
+
+fn fetch_with_retry(attempts: u32) {
+    for attempt in 1..attempts {
+        log_0_4::warn!("attempt {attempt} failed, retrying");
+    }
+    log_0_4::info!("fetched");
+}
+
+#[test]
+fn exactly_one_warning_about_retrying() {
+    let u = Unimock::new((
+        LogMock::log
+            .next_call(matching!((rec) if rec.level() == Level::Warn))
+            .returns(()),
+        LogMock::log
+            .next_call(matching!(msg_contains!("fetched")))
+            .returns(()),
+    ));
+
+    scoped(&u, || fetch_with_retry(2));
+}
+
+#[test]
+fn msg_contains_matches_substring() {
+    let u = Unimock::new((
+        LogMock::log
+            .each_call(matching!(msg_contains!("retrying")))
+            .returns(())
+            .n_times(2),
+        LogMock::log.each_call(matching!(_)).returns(()).once(),
+    ));
+
+    scoped(&u, || fetch_with_retry(3));
+}
+
+#[test]
+#[should_panic(expected = "inputs didn't match log_0_4::Log::log(msg_contains!(..))")]
+fn msg_contains_mismatch() {
+    let u = Unimock::new(
+        LogMock::log
+            .next_call(matching!(msg_contains!("retrying")))
+            .returns(()),
+    );
+
+    scoped(&u, || fetch_with_retry(1));
+}
+
+#[test]
+fn records_outside_scope_are_discarded() {
+    let u = Unimock::new(LogMock::log.next_call(matching!(_)).returns(()));
+
+    scoped(&u, || log_0_4::error!("inside"));
+    log_0_4::error!("outside");
+}
+
+#[test]
+fn scope_is_reset_after_panic() {
+    let u = Unimock::new(LogMock::log.next_call(matching!(_)).returns(()));
+
+    let result = std::panic::catch_unwind(|| {
+        scoped(&u, || {
+            log_0_4::error!("inside");
+            panic!("oops");
+        })
+    });
+    assert!(result.is_err());
+
+    let u2 = Unimock::new(LogMock::log.next_call(matching!(_)).returns(()));
+    scoped(&u2, || log_0_4::error!("next scope"));
+}
+
+#[test]
+fn direct_use_as_log() {
+    let u = Unimock::new((
+        LogMock::enabled
+            .next_call(matching!((meta) if meta.target() == "app"))
+            .returns(true),
+        LogMock::flush.next_call(matching!()).returns(()),
+    ));
+
+    assert!(u.enabled(&log_0_4::Metadata::builder().target("app").build()));
+    u.flush();
+}
@@ -0,0 +1,101 @@
+use rand_0_8::{seq::SliceRandom, CryptoRng, Rng};
+use rand_core_0_6::RngCore;
+
+use unimock::{mock::rand_core_0_6::RngCoreMock, *};
+
+#[test]
+fn gen_range_uses_scripted_values() {
+    let mut u = Unimock::new(RngCoreMock::next_u32.returns_sequence([0, 1 << 31]));
+
+    assert_eq!(0, u.gen_range(0..10u32));
+    assert_eq!(5, u.gen_range(0..10u32));
+}
+
+#[test]
+fn shuffle_is_deterministic() {
+    let mut u = Unimock::new(RngCoreMock::next_u32.returns_sequence([0, 0]));
+
+    let mut items = [1, 2, 3];
+    items.shuffle(&mut u);
+
+    assert_eq!([2, 3, 1], items);
+}
+
+#[test]
+fn next_u64_sequence_then_other_value() {
+    let mut u = Unimock::new(
+        RngCoreMock::next_u64
+            .returns_sequence([1, 2])
+            .then()
+            .returns(42u64),
+    );
+
+    assert_eq!(1, u.next_u64());
+    assert_eq!(2, u.next_u64());
+    assert_eq!(42, u.next_u64());
+    assert_eq!(42, u.next_u64());
+}
+
+#[test]
+#[should_panic(expected = "to match exactly 3 calls, but it actually matched 2 calls.")]
+fn sequence_must_be_consumed() {
+    let mut u = Unimock::new(RngCoreMock::next_u32.returns_sequence([1, 2, 3]));
+
+    u.next_u32();
+    u.next_u32();
+}
+
+#[test]
+#[should_panic(expected = "RngCore::next_u64: all 1 scripted values have been returned")]
+fn sequence_exhausted() {
+    let mut u = Unimock::new(RngCoreMock::next_u64.returns_sequence([1]));
+
+    u.next_u64();
+    u.next_u64();
+}
+
+#[test]
+fn fill_bytes_from_script() {
+    let mut u = Unimock::new(RngCoreMock::fill_bytes.fills_from([1, 2, 3, 4, 5]));
+
+    let mut a = [0; 2];
+    let mut b = [0; 3];
+    u.fill_bytes(&mut a);
+    u.fill_bytes(&mut b);
+
+    assert_eq!([1, 2], a);
+    assert_eq!([3, 4, 5], b);
+}
+
+#[test]
+#[should_panic(
+    expected = "RngCore::fill_bytes: requested 3 bytes, but only 1 scripted bytes are left"
+)]
+fn fill_bytes_script_exhausted() {
+    let mut u = Unimock::new(RngCoreMock::fill_bytes.fills_from([1]));
+
+    u.fill_bytes(&mut [0; 3]);
+}
+
+#[test]
+fn try_fill_bytes_error() {
+    let mut u = Unimock::new(RngCoreMock::try_fill_bytes.next_call(matching!(_)).answers(
+        &|_, _| {
+            Err(rand_core_0_6::Error::from(
+                core::num::NonZeroU32::new(rand_core_0_6::Error::CUSTOM_START).unwrap(),
+            ))
+        },
+    ));
+
+    assert!(u.try_fill_bytes(&mut [0; 4]).is_err());
+}
+
+#[test]
+fn is_crypto_rng() {
+    fn takes_crypto_rng(rng: &mut (impl RngCore + CryptoRng)) -> u64 {
+        rng.next_u64()
+    }
+
+    let mut u = Unimock::new(RngCoreMock::next_u64.returns_sequence([7]));
+    assert_eq!(7, takes_crypto_rng(&mut u));
+}
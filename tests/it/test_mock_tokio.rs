@@ -36,3 +36,70 @@ fn test_tokio_read() {
     }
     .test()
 }
+
+#[test]
+fn test_tokio_scripted_handshake() {
+    use tokio_1::io::AsyncWriteExt;
+    use unimock::mock::tokio_1::io::{AsyncReadResponses, AsyncWriteMock, AsyncWriteResponses};
+
+    async {
+        let mut u = Unimock::new((
+            AsyncWriteMock::poll_write
+                .next_call(matching!(_, eq!(b"HELLO")))
+                .pending_once()
+                .then()
+                .write_accepts(2),
+            AsyncWriteMock::poll_write
+                .next_call(matching!(_, eq!(b"LLO")))
+                .write_accepts(usize::MAX),
+            AsyncWriteMock::poll_flush
+                .next_call(matching!(_))
+                .returns(Poll::Ready(Ok(()))),
+            AsyncReadMock::poll_read
+                .next_call(matching!())
+                .pending_once(),
+            AsyncReadMock::poll_read.next_call(matching!()).reads(b"OK"),
+        ));
+
+        u.write_all(b"HELLO").await.unwrap();
+        u.flush().await.unwrap();
+
+        let mut buf = [0; 8];
+        let n = u.read(&mut buf).await.unwrap();
+        assert_eq!(b"OK", &buf[..n]);
+    }
+    .test()
+}
+
+#[test]
+fn test_tokio_seek_poll_responses() {
+    use tokio_1::io::AsyncSeekExt;
+    use unimock::mock::tokio_1::io::{AsyncPollResponses, AsyncSeekMock};
+
+    async {
+        let mut u = Unimock::new((
+            AsyncSeekMock::start_seek
+                .each_call(matching!(_))
+                .answers(&|_, _| Ok(())),
+            AsyncSeekMock::poll_complete
+                .next_call(matching!())
+                .ready_ok(0),
+            AsyncSeekMock::poll_complete
+                .next_call(matching!())
+                .pending_once(),
+            AsyncSeekMock::poll_complete
+                .next_call(matching!())
+                .ready_ok(42),
+            AsyncSeekMock::poll_complete
+                .next_call(matching!())
+                .ready_err(std::io::ErrorKind::UnexpectedEof),
+        ));
+
+        assert_eq!(42, u.seek(std::io::SeekFrom::Start(42)).await.unwrap());
+        assert_eq!(
+            std::io::ErrorKind::UnexpectedEof,
+            u.seek(std::io::SeekFrom::End(1)).await.unwrap_err().kind()
+        );
+    }
+    .test()
+}
@@ -0,0 +1,84 @@
+use core::convert::Infallible;
+#[cfg(feature = "mock-tokio-1")]
+use core::time::Duration;
+
+#[cfg(feature = "mock-tokio-1")]
+use tower_0_5::timeout::{error::Elapsed, Timeout};
+use tower_0_5::Service;
+use unimock::mock::tower_service_0_3::{script, ServiceMock};
+use unimock::*;
+
+use crate::AsyncTest;
+
+#[test]
+fn scripted_responses() {
+    async {
+        let u = Unimock::new(
+            script::<u32, String, String>()
+                .ready()
+                .responds("one".to_string())
+                .fails("two".to_string()),
+        );
+        let mut service = u.as_service::<u32, String, String>();
+
+        assert!(core::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .is_ok());
+        assert_eq!(Ok("one".to_string()), service.call(1).await);
+        assert_eq!(Err("two".to_string()), service.call(2).await);
+    }
+    .test()
+}
+
+#[test]
+#[cfg(feature = "mock-tokio-1")]
+fn timeout_middleware_times_out_slow_inner_service() {
+    async {
+        let u = Unimock::new(
+            script::<&str, &str, Infallible>()
+                .ready()
+                .responds_after(Duration::from_secs(10), "slow")
+                .responds("fast"),
+        );
+        let mut timeout = Timeout::new(
+            u.as_service::<&str, &str, Infallible>(),
+            Duration::from_millis(10),
+        );
+
+        core::future::poll_fn(|cx| timeout.poll_ready(cx))
+            .await
+            .unwrap();
+        let error = timeout.call("first").await.unwrap_err();
+        assert!(error.is::<Elapsed>());
+
+        core::future::poll_fn(|cx| timeout.poll_ready(cx))
+            .await
+            .unwrap();
+        assert_eq!("fast", timeout.call("second").await.unwrap());
+    }
+    .test()
+}
+
+#[test]
+fn call_matching_on_request() {
+    async {
+        let u = Unimock::new(
+            ServiceMock::call
+                .with_types::<u32, u32, Infallible>()
+                .each_call(matching!(n))
+                .answers(&|_, n| Box::pin(async move { Ok(n * 2) })),
+        );
+        let mut service = u.as_service::<u32, u32, Infallible>();
+
+        assert_eq!(Ok(4), service.call(2).await);
+        assert_eq!(Ok(6), service.call(3).await);
+    }
+    .test()
+}
+
+#[test]
+#[should_panic(expected = "to match exactly 1 call, but it actually matched no calls.")]
+fn unused_response_fails_verification() {
+    let u = Unimock::new(script::<u32, u32, Infallible>().responds(1));
+    let _service = u.as_service::<u32, u32, Infallible>();
+}
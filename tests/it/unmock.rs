@@ -1,4 +1,4 @@
-use unimock::alloc::{format, String, ToString};
+use unimock::alloc::{format, vec, String, ToString};
 use unimock::*;
 
 use core::any::Any;
@@ -31,7 +31,7 @@ mod unmock_simple {
 
     #[test]
     #[should_panic(
-        expected = "Mock for Spyable::concat was never called. Dead mocks should be removed."
+        expected = "Mock for it::unmock::unmock_simple::Spyable::concat was never called. Dead mocks should be removed."
     )]
     fn works_with_a_partial_having_a_stub_with_non_matching_pattern() {
         assert_eq!(
@@ -68,7 +68,7 @@ mod unmock_simple {
 
     #[test]
     #[should_panic(
-        expected = "Spyable::concat: Expected Spyable::concat(\"\", \"\") at tests/it/unmock.rs:75 to match at least 1 call, but it actually matched no calls."
+        expected = "it::unmock::unmock_simple::Spyable::concat: Expected it::unmock::unmock_simple::Spyable::concat(\"\", \"\") at tests/it/unmock.rs:75 to match at least 1 call, but it actually matched no calls."
     )]
     fn unmatched_pattern_still_panics() {
         Unimock::new(SpyableMock::concat.stub(|each| {
@@ -81,6 +81,275 @@ mod unmock_simple {
     }
 }
 
+mod per_mock_fn_fallback {
+    use super::*;
+
+    #[unimock(api=SpyableMock, unmock_with=[repeat, concat])]
+    trait Spyable {
+        fn repeat(&self, arg: String) -> String;
+        fn concat(&self, a: String, b: String) -> String;
+    }
+
+    fn repeat(_: &impl Any, arg: String) -> String {
+        format!("{arg}{arg}")
+    }
+    fn concat(_: &impl Any, a: String, b: String) -> String {
+        format!("{a}{b}")
+    }
+
+    #[test]
+    fn spy_fallback_unmocks_one_mock_fn_in_strict_mock() {
+        let u = Unimock::new((
+            SpyableMock::repeat.spy_fallback(),
+            SpyableMock::concat
+                .each_call(matching!(_, _))
+                .returns("mocked"),
+        ));
+
+        assert_eq!("aa", u.repeat("a".to_string()));
+        assert_eq!("mocked", u.concat("a".to_string(), "b".to_string()));
+    }
+
+    #[test]
+    fn spy_fallback_applies_to_unmatched_calls() {
+        let u = Unimock::new((
+            SpyableMock::repeat.spy_fallback(),
+            SpyableMock::repeat
+                .some_call(matching!("a"))
+                .returns("mocked"),
+        ));
+
+        assert_eq!("mocked", u.repeat("a".to_string()));
+        assert_eq!("bb", u.repeat("b".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Spyable::concat(\"a\", \"b\"): No mock implementation found.")]
+    fn other_mock_fns_stay_strict_with_spy_fallback() {
+        Unimock::new(SpyableMock::repeat.spy_fallback()).concat("a".to_string(), "b".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Spyable::repeat(\"a\"): No mock implementation found.")]
+    fn strict_disables_unmock_in_partial_mock() {
+        let u = Unimock::new_partial(SpyableMock::repeat.strict());
+
+        assert_eq!("ab", u.concat("a".to_string(), "b".to_string()));
+        u.repeat("a".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Spyable::repeat(\"b\"): No matching call patterns.")]
+    fn strict_applies_to_unmatched_calls_in_partial_mock() {
+        let u = Unimock::new_partial((
+            SpyableMock::repeat.strict(),
+            SpyableMock::repeat
+                .each_call(matching!("a"))
+                .returns("mocked"),
+        ));
+
+        assert_eq!("mocked", u.repeat("a".to_string()));
+        u.repeat("b".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "A fallback mode for it::unmock::per_mock_fn_fallback::Spyable::repeat has already been registered as Unmock, but got re-registered as Error."
+    )]
+    fn conflicting_fallback_modes() {
+        Unimock::new((
+            SpyableMock::repeat.spy_fallback(),
+            SpyableMock::repeat.strict(),
+        ));
+    }
+}
+
+mod builder {
+    use super::*;
+
+    #[unimock(api=SpyableMock, unmock_with=[repeat, _])]
+    trait Spyable {
+        fn repeat(&self, arg: String) -> String;
+        fn other(&self) -> i32;
+    }
+
+    fn repeat(_: &impl Any, arg: String) -> String {
+        format!("{arg}{arg}")
+    }
+
+    #[test]
+    #[should_panic(expected = "Spyable::repeat(\"a\"): No mock implementation found.")]
+    fn defaults_to_error_fallback() {
+        Unimock::builder().build().repeat("a".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Spyable::repeat(\"a\"): No mock implementation found.")]
+    fn new_is_error_fallback() {
+        Unimock::new(()).repeat("a".to_string());
+    }
+
+    #[test]
+    fn unmock_fallback_behaves_like_new_partial() {
+        let clause = || SpyableMock::other.each_call(matching!()).returns(42);
+
+        for u in [
+            Unimock::builder()
+                .fallback(FallbackMode::Unmock)
+                .clause(clause())
+                .build(),
+            Unimock::new_partial(clause()),
+        ] {
+            assert_eq!("aa", u.repeat("a".to_string()));
+            assert_eq!(42, u.other());
+        }
+    }
+
+    #[test]
+    fn clauses_accumulate() {
+        let u = Unimock::builder()
+            .clause(
+                SpyableMock::repeat
+                    .each_call(matching!(_))
+                    .returns("mocked"),
+            )
+            .clause(SpyableMock::other.each_call(matching!()).returns(42))
+            .build();
+
+        assert_eq!("mocked", u.repeat("a".to_string()));
+        assert_eq!(42, u.other());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "A fallback mode for it::unmock::builder::Spyable::repeat has already been registered as Unmock, but got re-registered as Error."
+    )]
+    fn clause_errors_panic_in_build() {
+        let _ = Unimock::builder()
+            .clause(SpyableMock::repeat.spy_fallback())
+            .clause(SpyableMock::repeat.strict())
+            .build();
+    }
+}
+
+mod counting_only {
+    use super::*;
+    use core::any::TypeId;
+
+    #[unimock(api=SpyableMock, unmock_with=[repeat, concat])]
+    trait Spyable {
+        fn repeat(&self, arg: String) -> String;
+        fn concat(&self, a: String, b: String) -> String;
+    }
+
+    fn repeat(_: &impl Any, arg: String) -> String {
+        format!("{arg}{arg}")
+    }
+    fn concat(_: &impl Any, a: String, b: String) -> String {
+        format!("{a}{b}")
+    }
+
+    #[test]
+    fn counts_calls_and_forwards_to_unmocked() {
+        let u = Unimock::new_counting_only(&[TypeId::of::<SpyableMock::repeat>()]);
+
+        assert_eq!(0, u.call_count::<SpyableMock::repeat>());
+        assert_eq!("aa", u.repeat("a".to_string()));
+        assert_eq!("bb", u.clone().repeat("b".to_string()));
+        assert_eq!("ab", u.concat("a".to_string(), "b".to_string()));
+        assert_eq!(2, u.call_count::<SpyableMock::repeat>());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Calls to it::unmock::counting_only::Spyable::concat are not being counted."
+    )]
+    fn call_count_of_uncounted_fn_panics() {
+        let u = Unimock::new_counting_only(&[TypeId::of::<SpyableMock::repeat>()]);
+        u.call_count::<SpyableMock::concat>();
+    }
+
+    #[test]
+    fn counts_mocked_calls_when_enabled_on_builder() {
+        let u = Unimock::builder()
+            .clause(
+                SpyableMock::concat
+                    .each_call(matching!(_, _))
+                    .returns("mocked"),
+            )
+            .count_calls([TypeId::of::<SpyableMock::concat>()])
+            .build();
+
+        assert_eq!("mocked", u.concat("a".to_string(), "b".to_string()));
+        assert_eq!(1, u.call_count::<SpyableMock::concat>());
+    }
+}
+
+#[cfg(feature = "std")]
+mod value_store {
+    use super::*;
+    use std::sync::Mutex;
+    use unimock::alloc::{vec, Vec};
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Created(u32),
+        Deleted(u32),
+    }
+
+    #[unimock(api=RepoMock, unmock_with=[create, delete, _])]
+    trait Repo {
+        fn create(&self, id: u32);
+        fn delete(&self, id: u32);
+        fn count(&self) -> usize;
+    }
+
+    fn create(store: &impl AsValueStore, id: u32) {
+        events(store).lock().unwrap().push(Event::Created(id));
+    }
+
+    fn delete(store: &impl AsValueStore, id: u32) {
+        events(store).lock().unwrap().push(Event::Deleted(id));
+    }
+
+    fn events(store: &impl AsValueStore) -> &Mutex<Vec<Event>> {
+        store.get_value().expect("no event log")
+    }
+
+    #[test]
+    fn unmocked_fns_share_stored_value() {
+        let u = Unimock::new_partial(RepoMock::count.each_call(matching!()).returns(2_usize))
+            .with_value(Mutex::new(Vec::<Event>::new()));
+
+        u.create(1);
+        u.clone().delete(1);
+        assert_eq!(2, u.count());
+
+        assert_eq!(
+            vec![Event::Created(1), Event::Deleted(1)],
+            *u.get_value::<Mutex<Vec<Event>>>().unwrap().lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn missing_value_is_none() {
+        let u = Unimock::new(()).with_value(42_u32);
+
+        assert_eq!(Some(&42), u.get_value::<u32>());
+        assert_eq!(None, u.get_value::<u64>());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Called with_value() on a shared instance. Store values before cloning the instance."
+    )]
+    fn with_value_on_shared_instance_panics() {
+        let u = Unimock::new(());
+        let _clone = u.clone();
+        let _ = u.with_value(42_u32);
+    }
+}
+
 mod unmock_recursion {
     use super::*;
 
@@ -104,6 +373,101 @@ mod unmock_recursion {
             .factorial(5)
         );
     }
+
+    #[unimock(api=CountdownMock, unmock_with=[my_countdown])]
+    trait Countdown {
+        fn countdown(&self, input: u32) -> u32;
+    }
+
+    fn my_countdown(f: &impl Countdown, input: u32) -> u32 {
+        f.countdown(input.wrapping_sub(1))
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Countdown::countdown recursed into its unmocked implementation more than 256 times. Is a clause for the base case missing?"
+    )]
+    fn missing_base_case_hits_the_recursion_limit() {
+        Unimock::new(
+            CountdownMock::countdown
+                .each_call(matching!(_))
+                .applies_unmocked(),
+        )
+        .countdown(5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Countdown::countdown recursed into its unmocked implementation more than 3 times."
+    )]
+    fn recursion_limit_is_configurable() {
+        Unimock::builder()
+            .clause(
+                CountdownMock::countdown
+                    .each_call(matching!(_))
+                    .applies_unmocked(),
+            )
+            .unmock_recursion_limit(3)
+            .build()
+            .countdown(5);
+    }
+
+    #[test]
+    fn recursion_within_the_limit_is_fine() {
+        let u = Unimock::builder()
+            .clause(CountdownMock::countdown.stub(|each| {
+                each.call(matching!(0)).returns(0_u32);
+                each.call(matching!(_)).applies_unmocked();
+            }))
+            .unmock_recursion_limit(3)
+            .build();
+
+        assert_eq!(0, u.countdown(3));
+        // depth is restored after returning
+        assert_eq!(0, u.countdown(3));
+    }
+
+    #[unimock(api=ConsumeMock, unmock_with=[my_consume])]
+    trait Consume {
+        fn consume(self) -> u32;
+    }
+
+    fn my_consume(_: impl Consume) -> u32 {
+        42
+    }
+
+    #[test]
+    fn by_value_receiver_can_be_unmocked() {
+        assert_eq!(42, Unimock::new_partial(()).consume());
+    }
+
+    #[cfg(feature = "std")]
+    mod per_thread {
+        use super::*;
+
+        #[unimock(api=RelayMock, unmock_with=[my_relay])]
+        trait Relay {
+            fn relay(&self, hops: u32) -> u32;
+        }
+
+        // Every hop runs on a new thread, so none of them is nested on the same thread
+        fn my_relay(r: &(impl Relay + Sync), hops: u32) -> u32 {
+            std::thread::scope(|s| s.spawn(|| r.relay(hops - 1)).join().unwrap()) + 1
+        }
+
+        #[test]
+        fn depth_is_counted_per_thread() {
+            let u = Unimock::builder()
+                .clause(RelayMock::relay.stub(|each| {
+                    each.call(matching!(0)).returns(0_u32);
+                    each.call(matching!(_)).applies_unmocked();
+                }))
+                .unmock_recursion_limit(1)
+                .build();
+
+            assert_eq!(3, u.relay(3));
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -188,3 +552,252 @@ mod unmock_without_api_should_not_have_shadowing_problems_wrt_unmock_fns {
 
     fn foo(_: &impl core::any::Any) {}
 }
+
+mod spy_record {
+    use super::*;
+
+    #[unimock(api=SpyableMock, unmock_with=[repeat, concat])]
+    trait Spyable {
+        fn repeat(&self, arg: String) -> String;
+        fn concat(&self, a: String, b: String) -> String;
+    }
+
+    fn repeat(_: &impl Any, arg: String) -> String {
+        format!("{arg}{arg}")
+    }
+    fn concat(_: &impl Any, a: String, b: String) -> String {
+        format!("{a}{b}")
+    }
+
+    #[test]
+    fn records_mocked_and_unmocked_calls() {
+        let (u, recording) = Unimock::spy_record(
+            SpyableMock::repeat
+                .each_call(matching!("x"))
+                .returns("mocked"),
+        );
+
+        assert_eq!("mocked", u.repeat("x".to_string()));
+        assert_eq!("abab", u.clone().repeat("ab".to_string()));
+        assert_eq!("ab", u.concat("a".to_string(), "b".to_string()));
+        drop(u);
+
+        assert_eq!(
+            vec![
+                "it::unmock::spy_record::Spyable::repeat(\"x\")",
+                "it::unmock::spy_record::Spyable::repeat(\"ab\")",
+                "it::unmock::spy_record::Spyable::concat(\"a\", \"b\")",
+            ],
+            recording.calls()
+        );
+    }
+
+    #[test]
+    fn queries_calls_of_one_mock_fn_after_the_fact() {
+        let (u, recording) = Unimock::spy_record(());
+
+        u.repeat("x".to_string());
+        u.concat("a".to_string(), "b".to_string());
+        u.repeat("y".to_string());
+
+        let repeats = recording.calls_of::<SpyableMock::repeat>();
+        assert_eq!(2, repeats.len());
+        assert_eq!(&[Some("\"y\"".to_string())], repeats[1].inputs());
+        assert_eq!(
+            "it::unmock::spy_record::Spyable::repeat(\"x\")",
+            repeats[0].to_string()
+        );
+
+        let concats = recording.calls_of::<SpyableMock::concat>();
+        assert_eq!(
+            &[Some("\"a\"".to_string()), Some("\"b\"".to_string())],
+            concats[0].inputs()
+        );
+    }
+}
+
+mod unmock_with_associated_fns_and_closures {
+    use super::*;
+
+    #[derive(Default)]
+    struct RealGateway {
+        fee: u32,
+    }
+
+    impl RealGateway {
+        fn charge(_: &impl Any, amount: u32) -> u32 {
+            amount
+        }
+
+        fn charge_with(&self, _: &impl Any, amount: u32) -> u32 {
+            amount + self.fee
+        }
+    }
+
+    #[unimock(api=GatewayMock, unmock_with=[
+        RealGateway::charge,
+        |deps, amount| RealGateway { fee: 1 }.charge_with(deps, amount),
+        |_, name| name.len()
+    ])]
+    trait Gateway {
+        fn charge(&self, amount: u32) -> u32;
+        fn charge_with_fee(&self, amount: u32) -> u32;
+        fn name_len(&self, name: &str) -> usize;
+    }
+
+    #[test]
+    fn unmocks_into_associated_fn() {
+        let u = Unimock::new(
+            GatewayMock::charge
+                .next_call(matching!(5))
+                .applies_unmocked(),
+        );
+
+        assert_eq!(5, u.charge(5));
+    }
+
+    #[test]
+    fn unmocks_into_closure() {
+        let u = Unimock::new_partial(());
+
+        assert_eq!(11, u.charge_with_fee(10));
+        assert_eq!(3, u.name_len("abc"));
+    }
+}
+
+mod spy_except {
+    use super::*;
+
+    #[unimock(api=ServiceMock, unmock_with=[real_send, real_log])]
+    trait Service {
+        fn send(&self, url: String) -> u16;
+        fn log(&self, msg: String) -> usize;
+    }
+
+    fn real_send(_: &impl Any, url: String) -> u16 {
+        panic!("real network call to {url}")
+    }
+
+    fn real_log(_: &impl Any, msg: String) -> usize {
+        msg.len()
+    }
+
+    #[test]
+    fn excluded_fn_uses_its_clauses_and_others_are_real() {
+        let u = Unimock::spy_except(
+            ServiceMock::send.must_be_mocked(),
+            ServiceMock::send
+                .each_call(matching!("/health"))
+                .returns(200_u16),
+        );
+
+        assert_eq!(200, u.send("/health".to_string()));
+        assert_eq!(3, u.log("abc".to_string()));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "it::unmock::spy_except::Service::send(\"/other\"): No call pattern matched, and it::unmock::spy_except::Service::send must be mocked instead of falling back to its unmocked implementation."
+    )]
+    fn unmatched_call_to_excluded_fn_panics() {
+        let u = Unimock::spy_except(
+            ServiceMock::send.must_be_mocked(),
+            ServiceMock::send
+                .each_call(matching!("/health"))
+                .returns(200_u16),
+        );
+
+        u.send("/other".to_string());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "must be mocked instead of falling back to its unmocked implementation."
+    )]
+    fn excluded_fn_without_clauses_panics() {
+        let u = Unimock::spy_except(ServiceMock::send.must_be_mocked(), ());
+
+        u.send("/health".to_string());
+    }
+}
+
+mod map_unmocked_output {
+    use super::*;
+
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use unimock::alloc::Arc;
+
+    #[unimock(api=StoreMock, unmock_with=[real_fetch, real_name])]
+    trait Store {
+        fn fetch(&self, key: u32) -> Result<u32, String>;
+        fn name(&self) -> String;
+    }
+
+    fn real_fetch(_: &impl Any, key: u32) -> Result<u32, String> {
+        Ok(key * 10)
+    }
+
+    fn real_name(_: &impl Any) -> String {
+        "real".to_string()
+    }
+
+    #[test]
+    fn flips_ok_into_err_on_second_call() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let u = Unimock::new(
+            StoreMock::fetch
+                .each_call(matching!(_))
+                .applies_unmocked()
+                .map_output({
+                    let calls = calls.clone();
+                    move |real| match calls.fetch_add(1, Ordering::SeqCst) {
+                        1 => Err("injected".to_string()),
+                        _ => real,
+                    }
+                }),
+        );
+
+        assert_eq!(Ok(10), u.fetch(1));
+        assert_eq!(Err("injected".to_string()), u.fetch(2));
+        assert_eq!(Ok(30), u.fetch(3));
+    }
+
+    #[test]
+    fn composes_with_quantifiers() {
+        let u = Unimock::new(
+            StoreMock::fetch
+                .next_call(matching!(_))
+                .applies_unmocked()
+                .map_output(|real| real.map(|value| value + 1))
+                .once()
+                .then()
+                .applies_unmocked()
+                .once(),
+        );
+
+        assert_eq!(Ok(11), u.fetch(1));
+        assert_eq!(Ok(20), u.fetch(2));
+    }
+
+    #[test]
+    fn overrides_spy_fallback_per_pattern() {
+        let u = Unimock::new_partial(
+            StoreMock::fetch
+                .each_call(matching!(_))
+                .applies_unmocked()
+                .map_output(|_| Err("unavailable".to_string())),
+        );
+
+        assert_eq!(Err("unavailable".to_string()), u.fetch(1));
+        assert_eq!("real", u.name());
+    }
+
+    #[test]
+    #[should_panic(expected = "map_output must directly follow applies_unmocked")]
+    fn map_output_after_returns_panics() {
+        let _ = StoreMock::name
+            .each_call(matching!())
+            .returns("mocked".to_string())
+            .map_output(|name| name);
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use unimock::*;
+
+use crate::AsyncTest;
+
+#[unimock(api = TraitMock)]
+trait Trait {
+    fn foo(&self, arg: i32) -> i32;
+}
+
+#[test]
+fn async_join_waits_for_spawned_tasks() {
+    async {
+        let (u, handle) = Unimock::new_with_verify_handle((
+            TraitMock::foo.next_call(matching!(1)).returns(10),
+            TraitMock::foo.next_call(matching!(2)).returns(20),
+        ));
+
+        let clone = u.clone();
+        tokio_1::spawn(async move {
+            tokio_1::time::sleep(Duration::from_millis(5)).await;
+            assert_eq!(20, clone.foo(2));
+        });
+        tokio_1::spawn(async move {
+            assert_eq!(10, u.foo(1));
+        });
+
+        handle.join().await.unwrap();
+    }
+    .test()
+}
+
+#[test]
+fn async_join_reports_missing_calls() {
+    async {
+        let (u, handle) =
+            Unimock::new_with_verify_handle(TraitMock::foo.next_call(matching!(1)).returns(10));
+
+        tokio_1::spawn(async move {
+            drop(u);
+        });
+
+        let errors = handle.join().await.unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|error| error.kind() == MockErrorKind::FailedVerification));
+    }
+    .test()
+}
+
+#[test]
+fn blocking_join_waits_for_threads() {
+    let (u, handle) = Unimock::new_with_verify_handle(
+        TraitMock::foo
+            .each_call(matching!(_))
+            .answers(&|_, arg| arg),
+    );
+
+    let threads: Vec<_> = (0..4)
+        .map(|i| {
+            let clone = u.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(i * 2));
+                assert_eq!(i as i32, clone.foo(i as i32));
+            })
+        })
+        .collect();
+    drop(u);
+
+    handle.join_blocking().unwrap();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
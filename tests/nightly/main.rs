@@ -4,9 +4,16 @@
 #![feature(type_alias_impl_trait)]
 #![feature(closure_track_caller)]
 #![feature(impl_trait_in_assoc_type)]
+#![feature(negative_bounds)]
+#![feature(negative_impls)]
+#![cfg_attr(feature = "trait-alias", feature(trait_alias))]
+#![allow(internal_features)]
 #![allow(clippy::disallowed_names)]
 
 mod associated_future;
+mod negative_bounds;
+#[cfg(feature = "trait-alias")]
+mod trait_alias;
 
 fn main() {}
 
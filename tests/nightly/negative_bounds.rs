@@ -0,0 +1,30 @@
+use unimock::*;
+
+#[derive(Debug, PartialEq)]
+struct Local(i32);
+
+impl !Send for Local {}
+impl !Sync for Local {}
+
+#[unimock(api=NegMock)]
+trait Neg {
+    fn local<T: !Send + PartialEq<Local> + 'static>(&self, value: T) -> i32
+    where
+        T: !Sync;
+
+    fn plain(&self) -> i32;
+}
+
+#[test]
+fn should_mock_method_with_negative_bounds() {
+    let u = Unimock::new((
+        NegMock::local
+            .with_types::<Local>()
+            .next_call(matching!((value) if value == &Local(1)))
+            .returns(10),
+        NegMock::plain.next_call(matching!()).returns(20),
+    ));
+
+    assert_eq!(10, u.local(Local(1)));
+    assert_eq!(20, u.plain());
+}
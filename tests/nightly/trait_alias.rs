@@ -0,0 +1,28 @@
+use unimock::*;
+
+#[unimock(api=ReadMock)]
+trait Read {
+    fn read(&self) -> i32;
+}
+
+#[unimock(api=WriteMock)]
+trait Write {
+    fn write(&self, value: i32);
+}
+
+#[unimock]
+trait Service = Read + Write;
+
+fn copy(service: &impl Service) {
+    service.write(service.read());
+}
+
+#[test]
+fn should_mock_all_traits_of_an_alias() {
+    let u = Unimock::new((
+        ReadMock::read.next_call(matching!()).returns(42),
+        WriteMock::write.next_call(matching!(42)).returns(()),
+    ));
+
+    copy(&u);
+}
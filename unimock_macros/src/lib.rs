@@ -14,11 +14,27 @@ pub fn unimock(
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
     let attrs = syn::parse_macro_input!(attr as unimock::Attr);
-    let item_trait = syn::parse_macro_input!(input as syn::ItemTrait);
+    let (input, negative_bounds) = unimock::NegativeBounds::strip(input.into());
+    let item_trait = match syn::parse2::<syn::ItemTrait>(input.clone()) {
+        Ok(item_trait) => item_trait,
+        #[cfg(feature = "trait-alias")]
+        Err(err) => match syn::parse2::<syn::ItemTraitAlias>(input) {
+            Ok(item_trait_alias) => {
+                let output = match unimock::trait_alias::generate(attrs, item_trait_alias) {
+                    Ok(stream) => stream,
+                    Err(err) => err.to_compile_error(),
+                };
+                return proc_macro::TokenStream::from(output);
+            }
+            Err(_) => return err.to_compile_error().into(),
+        },
+        #[cfg(not(feature = "trait-alias"))]
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let debug = attrs.debug;
 
-    let output = match unimock::generate(attrs, item_trait) {
+    let output = match unimock::generate(attrs, item_trait, negative_bounds) {
         Ok(stream) => stream,
         Err(err) => err.to_compile_error(),
     };
@@ -30,6 +46,27 @@ pub fn unimock(
     proc_macro::TokenStream::from(output)
 }
 
+#[proc_macro]
+pub fn matching_debug_hint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as matching::DebugHintInput);
+
+    let output = matching::generate_debug_hint(input);
+
+    proc_macro::TokenStream::from(output)
+}
+
+#[proc_macro_derive(MockFn, attributes(mock_fn))]
+pub fn derive_mock_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    let output = match unimock::derive_mock_fn::generate(input) {
+        Ok(stream) => stream,
+        Err(err) => err.to_compile_error(),
+    };
+
+    proc_macro::TokenStream::from(output)
+}
+
 #[proc_macro]
 pub fn matching(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as matching::MatchingInput);
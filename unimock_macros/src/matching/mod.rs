@@ -11,12 +11,23 @@ mod parse;
 pub struct MatchingInput {
     arg_patterns: Vec<ArgPattern>,
     guard: Option<(syn::token::If, syn::Expr)>,
+    arg_compares: Vec<ArgCompare>,
+    /// `match_fn!(path)`, an external function matching the whole inputs tuple
+    match_fn: Option<syn::Path>,
 }
 
 struct ArgPattern {
     tuple: syn::PatTuple,
 }
 
+/// `arg[index] == expr` or `arg[index] != expr`, matching one argument of many while ignoring the others
+struct ArgCompare {
+    index: usize,
+    compare_macro: CompareMacro,
+    span: proc_macro2::Span,
+    expr: syn::Expr,
+}
+
 struct Arg {
     arg_ident: syn::Ident,
     kind: ArgKind,
@@ -40,7 +51,33 @@ enum ArgKind {
     Slice,
 }
 
+/// Input of `matching_debug_hint!`: a description followed by the input of `matching!`
+pub struct DebugHintInput {
+    debug_hint: syn::LitStr,
+    matching: MatchingInput,
+}
+
+pub fn generate_debug_hint(input: DebugHintInput) -> proc_macro2::TokenStream {
+    let debug_hint = input.debug_hint;
+    let matching = generate(input.matching);
+
+    quote! {
+        &|_m| {
+            ::unimock::private::apply_matching(_m, #matching);
+            _m.debug_hint(#debug_hint);
+        }
+    }
+}
+
 pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
+    if let Some(match_fn) = &input.match_fn {
+        return generate_match_fn(match_fn);
+    }
+
+    if !input.arg_compares.is_empty() {
+        return generate_arg_compares(&input.arg_compares);
+    }
+
     if input.arg_patterns.is_empty() {
         return quote! {
             &|_m| {
@@ -70,11 +107,31 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
         .flat_map(|arm| arm.render_local_defs())
         .collect::<Vec<_>>();
 
+    let captured_defs = arg_pattern_arms
+        .iter()
+        .flat_map(|arm| arm.render_captured_defs())
+        .collect::<Vec<_>>();
+
+    // Captured values are owned by the matching function
+    let capture = if captured_defs.is_empty() {
+        None
+    } else {
+        Some(quote! { move })
+    };
+
     let arg_pat = concat_args_parenthesized(&args, |arg| {
         let arg_ident = &arg.arg_ident;
         quote! { #arg_ident }
     });
     let arg_expr = concat_args_parenthesized(&args, |arg| arg.render_expr());
+    // A single pattern may match the whole inputs tuple, e.g. `matching!(_)`,
+    // so only patterns listing several arguments are checked against `MockFn::N_INPUTS`.
+    let arity_check = if args.len() > 1 {
+        let arity = args.len();
+        Some(quote! { _m.arity::<#arity>(); })
+    } else {
+        None
+    };
 
     let diagnostics_arm = if global_guards.is_empty() {
         Some(generate_diagnostics_arm(&arg_pattern_arms, &args))
@@ -90,8 +147,10 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
 
     quote! {
         &|_m| {
+            #arity_check
+            #(#captured_defs)*
             _m.func(
-                |#arg_pat, reporter| {
+                #capture |#arg_pat, reporter| {
                     #(#local_defs)*
                     match #arg_expr {
                         #(#success_arms)*
@@ -105,6 +164,121 @@ pub fn generate(input: MatchingInput) -> proc_macro2::TokenStream {
     }
 }
 
+fn generate_match_fn(match_fn: &syn::Path) -> proc_macro2::TokenStream {
+    let pattern_debug_lit_str = generate_match_fn_debug(match_fn);
+
+    quote_spanned! { match_fn.span()=>
+        &|_m| {
+            _m.func(|inputs, _| #match_fn(inputs));
+            _m.pat_debug(#pattern_debug_lit_str, file!(), line!());
+        }
+    }
+}
+
+fn generate_match_fn_debug(match_fn: &syn::Path) -> syn::LitStr {
+    let path = quote! { #match_fn }.to_string().replace(' ', "");
+
+    syn::LitStr::new(
+        &format!("(match_fn!({path}))"),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+fn generate_arg_compares(arg_compares: &[ArgCompare]) -> proc_macro2::TokenStream {
+    let local_defs = arg_compares
+        .iter()
+        .enumerate()
+        .map(|(local_index, arg_compare)| {
+            let local_ident = quote::format_ident!("l{}", local_index);
+            let expr = &arg_compare.expr;
+            quote! {
+                let #local_ident = #expr;
+            }
+        });
+
+    let render_arg = |arg_compare: &ArgCompare| {
+        let span = arg_compare.span;
+        let index = syn::Index::from(arg_compare.index);
+        quote_spanned! { span=>
+            ::unimock::private::TupleArg::<#index>::arg(inputs)
+        }
+    };
+
+    let guards = arg_compares
+        .iter()
+        .enumerate()
+        .map(|(local_index, arg_compare)| {
+            let local_ident = quote::format_ident!("l{}", local_index);
+            let arg = render_arg(arg_compare);
+            let operator = arg_compare.compare_macro.operator(arg_compare.span);
+            quote! {
+                (*#arg #operator #local_ident)
+            }
+        });
+
+    let diagnostics_stmts = arg_compares.iter().enumerate().map(|(local_index, arg_compare)| {
+        let local_ident = quote::format_ident!("l{}", local_index);
+        let arg = render_arg(arg_compare);
+        let operator = arg_compare.compare_macro.operator(arg_compare.span);
+        let input_index = arg_compare.index;
+        let reporter_method = match arg_compare.compare_macro {
+            CompareMacro::Ne => quote! { ne_fail },
+            _ => quote! { eq_fail },
+        };
+
+        quote! {
+            if !(*#arg #operator #local_ident) {
+                use ::unimock::private::{ProperDebug, NoDebug};
+                reporter.#reporter_method(#input_index, #arg.unimock_try_debug(), (&#local_ident).unimock_try_debug());
+            }
+        }
+    });
+
+    let pattern_debug_lit_str = generate_arg_compares_debug(arg_compares);
+
+    quote! {
+        &|_m| {
+            _m.func(
+                |inputs, reporter| {
+                    #(#local_defs)*
+                    if #(#guards)&&* {
+                        true
+                    } else {
+                        if reporter.enabled() {
+                            #(#diagnostics_stmts)*
+                        }
+                        false
+                    }
+                }
+            );
+            _m.pat_debug(#pattern_debug_lit_str, file!(), line!());
+        }
+    }
+}
+
+fn generate_arg_compares_debug(arg_compares: &[ArgCompare]) -> syn::LitStr {
+    let mut debug = String::from("(");
+
+    for (index, arg_compare) in arg_compares.iter().enumerate() {
+        if index > 0 {
+            debug.push_str(" && ");
+        }
+        debug.push_str(&format!(
+            "arg[{}] {} ",
+            arg_compare.index,
+            match arg_compare.compare_macro {
+                CompareMacro::Ne => "!=",
+                _ => "==",
+            }
+        ));
+        arg_compare.expr.doc(&mut debug);
+    }
+
+    debug.push(')');
+
+    syn::LitStr::new(debug.as_str(), proc_macro2::Span::call_site())
+}
+
 // An arm (or, _candidate_) for a complete arg match (all patterns)
 struct ArgPatternArm {
     arg_matchers: Vec<ArgMatcher>,
@@ -129,6 +303,12 @@ impl ArgPatternArm {
             .filter_map(|arg_matcher| arg_matcher.render_local_def())
     }
 
+    fn render_captured_defs(&self) -> impl Iterator<Item = proc_macro2::TokenStream> + '_ {
+        self.arg_matchers
+            .iter()
+            .filter_map(|arg_matcher| arg_matcher.render_captured_def())
+    }
+
     fn render_success_arm(&self, global_guards: &[TokenStream]) -> proc_macro2::TokenStream {
         let mut concatenated_guards = Vec::from_iter(global_guards);
 
@@ -167,11 +347,24 @@ impl ArgPatternArm {
 enum ArgMatcher {
     Pattern(syn::Pat),
     Compare(CompareMatcher),
+    Within(WithinMatcher),
 }
 
 impl ArgMatcher {
     fn new(pat: syn::Pat, index: usize, local_counter: &mut usize) -> Self {
         match pat {
+            syn::Pat::Macro(pat_macro) if pat_macro.mac.path.is_ident("within") => {
+                let span = pat_macro.mac.path.span();
+                let local_ident = syn::Ident::new(&format!("l{local_counter}"), span);
+                *local_counter += 1;
+
+                Self::Within(WithinMatcher {
+                    span,
+                    local_ident,
+                    pat_bind_ident: syn::Ident::new(&format!("m{index}"), span),
+                    tokens: pat_macro.mac.tokens,
+                })
+            }
             syn::Pat::Macro(pat_macro) => match CompareMacro::detect(&pat_macro.mac.path) {
                 Some(compare_macro) => {
                     let span = pat_macro.mac.path.span();
@@ -198,15 +391,33 @@ impl ArgMatcher {
 
     fn render_local_def(&self) -> Option<proc_macro2::TokenStream> {
         match self {
-            Self::Pattern(_) => None,
+            Self::Pattern(_) | Self::Within(_) => None,
             Self::Compare(compare_matcher) => {
                 let local_ident = &compare_matcher.local_ident;
-                let tokens = &compare_matcher.tokens;
+                let value = compare_matcher
+                    .compare_macro
+                    .render_local_value(compare_matcher.span, &compare_matcher.tokens);
 
                 Some(quote! {
-                    let #local_ident = #tokens;
+                    let #local_ident = #value;
+                })
+            }
+        }
+    }
+
+    /// The collection of a `within!` is cloned once, when the pattern is registered
+    fn render_captured_def(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::Within(within_matcher) => {
+                let span = within_matcher.span;
+                let local_ident = &within_matcher.local_ident;
+                let tokens = &within_matcher.tokens;
+
+                Some(quote_spanned! { span=>
+                    let #local_ident = ::core::clone::Clone::clone(&(#tokens));
                 })
             }
+            _ => None,
         }
     }
 
@@ -217,6 +428,10 @@ impl ArgMatcher {
                 let bind_ident = &compare_matcher.pat_bind_ident;
                 quote! { #bind_ident }
             }
+            Self::Within(within_matcher) => {
+                let bind_ident = &within_matcher.pat_bind_ident;
+                quote! { #bind_ident }
+            }
         }
     }
 
@@ -227,9 +442,20 @@ impl ArgMatcher {
                 let span = compare_matcher.span;
                 let pat_bind_ident = &compare_matcher.pat_bind_ident;
                 let local_ident = &compare_matcher.local_ident;
-                let operator = compare_matcher.compare_macro.operator(span);
+                let compare_macro = &compare_matcher.compare_macro;
+                let lhs = compare_macro.render_lhs(span, quote! { #pat_bind_ident });
+                let rhs = compare_macro.render_rhs(span, local_ident);
+                let operator = compare_macro.operator(span);
+                Some(quote_spanned! { span=>
+                    (#lhs #operator #rhs)
+                })
+            }
+            Self::Within(within_matcher) => {
+                let span = within_matcher.span;
+                let pat_bind_ident = &within_matcher.pat_bind_ident;
+                let local_ident = &within_matcher.local_ident;
                 Some(quote_spanned! { span=>
-                    (#pat_bind_ident #operator #local_ident)
+                    #local_ident.contains(#pat_bind_ident)
                 })
             }
         }
@@ -272,21 +498,36 @@ impl ArgMatcher {
             },
             ArgMatcher::Compare(compare_matcher) => {
                 let span = compare_matcher.span;
-                let operator = compare_matcher.compare_macro.operator(span);
-                let local_ident = &compare_matcher.local_ident;
+                let compare_macro = &compare_matcher.compare_macro;
+                let operator = compare_macro.operator(span);
+                let lhs = compare_macro.render_lhs(span, arg_expr);
+                let rhs = compare_macro.render_rhs(span, &compare_matcher.local_ident);
 
                 let reporter_method = syn::Ident::new(
-                    match &compare_matcher.compare_macro {
-                        CompareMacro::Eq => "eq_fail",
+                    match compare_macro {
+                        CompareMacro::Eq
+                        | CompareMacro::IoError
+                        | CompareMacro::Json
+                        | CompareMacro::MsgContains => "eq_fail",
                         CompareMacro::Ne => "ne_fail",
                     },
                     span,
                 );
 
                 Some(quote! {
-                    if !(#arg_expr #operator #local_ident) {
+                    if !(#lhs #operator #rhs) {
+                        use ::unimock::private::{ProperDebug, NoDebug};
+                        reporter.#reporter_method(#index, (#lhs).unimock_try_debug(), (#rhs).unimock_try_debug());
+                    }
+                })
+            }
+            ArgMatcher::Within(within_matcher) => {
+                let local_ident = &within_matcher.local_ident;
+
+                Some(quote! {
+                    if !#local_ident.contains(#arg_expr) {
                         use ::unimock::private::{ProperDebug, NoDebug};
-                        reporter.#reporter_method(#index, #arg_expr.unimock_try_debug(), #local_ident.unimock_try_debug());
+                        reporter.pat_fail(#index, (#arg_expr).unimock_try_debug(), Some("within!(..)"));
                     }
                 })
             }
@@ -302,6 +543,13 @@ struct CompareMatcher {
     tokens: proc_macro2::TokenStream,
 }
 
+struct WithinMatcher {
+    span: proc_macro2::Span,
+    local_ident: syn::Ident,
+    pat_bind_ident: syn::Ident,
+    tokens: proc_macro2::TokenStream,
+}
+
 fn generate_diagnostics_arm(arms: &[ArgPatternArm], args: &[Arg]) -> proc_macro2::TokenStream {
     let body = match arms.last() {
         None => quote! { false },
@@ -332,6 +580,12 @@ fn generate_diagnostics_arm(arms: &[ArgPatternArm], args: &[Arg]) -> proc_macro2
 enum CompareMacro {
     Eq,
     Ne,
+    /// Compare the `std::io::ErrorKind` of an `io::Result`
+    IoError,
+    /// Compare a JSON-encoded argument to a `serde_json::json!` literal
+    Json,
+    /// Check that the formatted message of a `log::Record` contains a substring
+    MsgContains,
 }
 
 impl CompareMacro {
@@ -340,6 +594,12 @@ impl CompareMacro {
             Some(Self::Eq)
         } else if path.is_ident("ne") {
             Some(Self::Ne)
+        } else if path.is_ident("io_error") {
+            Some(Self::IoError)
+        } else if path.is_ident("json") {
+            Some(Self::Json)
+        } else if path.is_ident("msg_contains") {
+            Some(Self::MsgContains)
         } else {
             None
         }
@@ -347,10 +607,66 @@ impl CompareMacro {
 
     fn operator(&self, span: proc_macro2::Span) -> proc_macro2::TokenStream {
         match self {
-            Self::Eq => quote_spanned! { span=> == },
+            Self::Eq | Self::IoError | Self::Json | Self::MsgContains => {
+                quote_spanned! { span=> == }
+            }
             Self::Ne => quote_spanned! { span=> != },
         }
     }
+
+    /// The value to compare against, derived from the macro argument
+    fn render_local_value(
+        &self,
+        span: proc_macro2::Span,
+        tokens: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Eq | Self::Ne | Self::IoError | Self::MsgContains => tokens.clone(),
+            Self::Json => quote_spanned! { span=>
+                ::unimock::private::serde_json::json!(#tokens)
+            },
+        }
+    }
+
+    /// The left hand side of the comparison, derived from the argument being matched
+    fn render_lhs(
+        &self,
+        span: proc_macro2::Span,
+        arg: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Eq | Self::Ne => arg,
+            Self::IoError => quote_spanned! { span=>
+                #arg.as_ref().err().map(|err| err.kind())
+            },
+            Self::Json => quote_spanned! { span=>
+                ::unimock::private::parse_json(#arg).as_ref()
+            },
+            Self::MsgContains => quote_spanned! { span=>
+                ::unimock::private::LogMessage::of(#arg)
+            },
+        }
+    }
+
+    /// The right hand side of the comparison, derived from the macro argument
+    fn render_rhs(
+        &self,
+        span: proc_macro2::Span,
+        local_ident: &syn::Ident,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Eq | Self::Ne => quote! { #local_ident },
+            Self::IoError => quote_spanned! { span=>
+                ::core::option::Option::Some(#local_ident)
+            },
+            Self::Json => quote_spanned! { span=>
+                ::core::option::Option::Some(&#local_ident)
+            },
+            Self::MsgContains => quote_spanned! { span=>
+                ::unimock::private::Contains(&#local_ident)
+            },
+        }
+    }
 }
 
 fn concat_args_parenthesized<F>(args: &[Arg], f: F) -> proc_macro2::TokenStream
@@ -508,6 +824,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_arg_compares_by_doc_output() {
+        fn test_doc(input: MatchingInput) -> String {
+            generate_arg_compares_debug(&input.arg_compares).value()
+        }
+
+        assert_eq!(
+            "(arg[1] == \"bar\")",
+            test_doc(parse_quote!(arg[1] == "bar"))
+        );
+        assert_eq!(
+            "(arg[0] != 1 && arg[3] == ?expr?)",
+            test_doc(parse_quote!(arg[0] != 1 && arg[3] == some_value()))
+        );
+    }
+
+    #[test]
+    fn test_match_fn_by_doc_output() {
+        fn test_doc(input: MatchingInput) -> String {
+            generate_match_fn_debug(&input.match_fn.unwrap()).value()
+        }
+
+        assert_eq!(
+            "(match_fn!(helpers::is_small))",
+            test_doc(parse_quote!(match_fn!(helpers::is_small)))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Excessive tokens")]
+    fn match_fn_must_be_alone() {
+        test_doc(parse_quote!(match_fn!(is_small), 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected `arg[n] == expr` or `arg[n] != expr`")]
+    fn arg_compare_requires_comparison() {
+        test_doc(parse_quote!(arg[1] > 2));
+    }
+
     #[test]
     #[should_panic(expected = "Expected tuple")]
     fn syntax_error1() {
@@ -519,4 +875,11 @@ mod tests {
     fn guard_should_require_tuple() {
         test_doc(parse_quote!(1 if expr()));
     }
+
+    #[test]
+    fn debug_hint_precedes_the_pattern() {
+        let input: DebugHintInput = parse_quote!("small numbers", 1 | 2, _);
+        assert_eq!("small numbers", input.debug_hint.value());
+        assert_eq!("(1 | 2, _)", test_doc(input.matching));
+    }
 }
@@ -1,9 +1,54 @@
-use super::{ArgPattern, MatchingInput};
+use super::{ArgCompare, ArgPattern, CompareMacro, DebugHintInput, MatchingInput};
 
 use syn::spanned::Spanned;
 
+impl syn::parse::Parse for DebugHintInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let debug_hint = input.parse()?;
+        let _: syn::token::Comma = input.parse()?;
+
+        Ok(Self {
+            debug_hint,
+            matching: input.parse()?,
+        })
+    }
+}
+
 impl syn::parse::Parse for MatchingInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if is_match_fn(input) {
+            let mac: syn::Macro = input.parse()?;
+            let match_fn: syn::Path = mac.parse_body()?;
+
+            if !input.is_empty() {
+                return Err(syn::Error::new(input.span(), "Excessive tokens"));
+            }
+
+            return Ok(MatchingInput {
+                arg_patterns: vec![],
+                guard: None,
+                arg_compares: vec![],
+                match_fn: Some(match_fn),
+            });
+        }
+
+        if is_arg_compare(input) {
+            let expr: syn::Expr = input.parse()?;
+            let mut arg_compares = Vec::new();
+            collect_arg_compares(expr, &mut arg_compares)?;
+
+            if !input.is_empty() {
+                return Err(syn::Error::new(input.span(), "Excessive tokens"));
+            }
+
+            return Ok(MatchingInput {
+                arg_patterns: vec![],
+                guard: None,
+                arg_compares,
+                match_fn: None,
+            });
+        }
+
         let mut arg_patterns = Vec::new();
         let mut guard = None;
 
@@ -52,10 +97,74 @@ impl syn::parse::Parse for MatchingInput {
         Ok(MatchingInput {
             arg_patterns,
             guard,
+            arg_compares: vec![],
+            match_fn: None,
         })
     }
 }
 
+/// `match_fn!(path)` replaces all other matching syntax.
+fn is_match_fn(input: syn::parse::ParseStream) -> bool {
+    let fork = input.fork();
+    matches!(fork.parse::<syn::Ident>(), Ok(ident) if ident == "match_fn")
+        && fork.peek(syn::token::Not)
+}
+
+/// `arg[..]` is not a valid pattern, so it unambiguously starts the positional comparison form.
+fn is_arg_compare(input: syn::parse::ParseStream) -> bool {
+    let fork = input.fork();
+    matches!(fork.parse::<syn::Ident>(), Ok(ident) if ident == "arg")
+        && fork.peek(syn::token::Bracket)
+}
+
+fn collect_arg_compares(expr: syn::Expr, out: &mut Vec<ArgCompare>) -> syn::Result<()> {
+    let span = expr.span();
+    let syn::Expr::Binary(binary) = expr else {
+        return Err(arg_compare_error(span));
+    };
+
+    let compare_macro = match binary.op {
+        syn::BinOp::And(_) => {
+            collect_arg_compares(*binary.left, out)?;
+            return collect_arg_compares(*binary.right, out);
+        }
+        syn::BinOp::Eq(_) => CompareMacro::Eq,
+        syn::BinOp::Ne(_) => CompareMacro::Ne,
+        _ => return Err(arg_compare_error(span)),
+    };
+
+    let index = match *binary.left {
+        syn::Expr::Index(syn::ExprIndex { expr, index, .. }) if matches!(&*expr, syn::Expr::Path(path) if path.path.is_ident("arg")) => {
+            match *index {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                }) => lit_int.base10_parse::<usize>()?,
+                other => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "The argument index must be an integer literal",
+                    ))
+                }
+            }
+        }
+        _ => return Err(arg_compare_error(span)),
+    };
+
+    out.push(ArgCompare {
+        index,
+        compare_macro,
+        span,
+        expr: *binary.right,
+    });
+
+    Ok(())
+}
+
+fn arg_compare_error(span: proc_macro2::Span) -> syn::Error {
+    syn::Error::new(span, "Expected `arg[n] == expr` or `arg[n] != expr`")
+}
+
 fn parse_pat_then_pipes_unless_parenthesized(
     input: syn::parse::ParseStream,
 ) -> syn::Result<syn::Pat> {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use super::trait_info::TraitInfo;
+use super::util;
 
 /// Parsed unimock attribute
 pub struct Attr {
@@ -14,6 +15,14 @@ pub struct Attr {
     pub mirror: Option<syn::Path>,
     pub input_lifetime: syn::Lifetime,
     pub debug: bool,
+    /// Whether to derive `Debug` for the MockFn structs
+    pub derive_debug: bool,
+    /// Set if a `default_stub()` function should be generated in the mock module
+    pub derive_default_stub: Option<proc_macro2::Span>,
+    /// Name of the `#[cfg(test)]` module of stub factories, if requested
+    pub test_helpers: Option<syn::Ident>,
+    /// Naming template for the MockFn structs, if requested
+    pub rename_fn: Option<RenameFn>,
 }
 
 pub struct WithSpan<T>(pub T, pub proc_macro2::Span);
@@ -29,6 +38,19 @@ impl Attr {
         })
     }
 
+    /// Whether any option is given that only applies to a trait with methods of its own.
+    #[cfg(feature = "trait-alias")]
+    pub fn has_trait_options(&self) -> bool {
+        !matches!(self.mock_api, MockApi::Hidden)
+            || !self.associated_types.is_empty()
+            || !self.associated_consts.is_empty()
+            || self.unmocks.is_some()
+            || self.mirror.is_some()
+            || self.derive_debug
+            || self.derive_default_stub.is_some()
+            || self.test_helpers.is_some()
+    }
+
     pub fn validate(&self, trait_info: &TraitInfo) -> syn::Result<()> {
         match &self.unmocks {
             Some(unmocked) if unmocked.0.len() != trait_info.methods.len() => {
@@ -40,6 +62,53 @@ impl Attr {
             _ => {}
         }
 
+        for (index, method) in trait_info.methods.iter().enumerate() {
+            if let (Some(method), Some(UnmockFn::Path { path, .. })) =
+                (method, self.get_unmock_fn(index))
+            {
+                if let Some(syn::PathArguments::AngleBracketed(generic_args)) =
+                    path.path.segments.last().map(|segment| &segment.arguments)
+                {
+                    let expected = method.unmock_generic_params().len();
+                    let actual = generic_args
+                        .args
+                        .iter()
+                        .filter(|arg| matches!(arg, syn::GenericArgument::Type(_)))
+                        .count();
+                    if actual != expected {
+                        return Err(syn::Error::new_spanned(
+                            generic_args,
+                            format!(
+                                "The unmock function of `{}` must take {expected} generic arguments: the type parameters of the method",
+                                method.method.sig.ident
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            if let (Some(method), Some(UnmockFn::Closure(closure))) =
+                (method, self.get_unmock_fn(index))
+            {
+                let expected = method.non_receiver_arg_count + 1;
+                if closure.inputs.len() != expected {
+                    let syn::ExprClosure {
+                        or1_token,
+                        inputs,
+                        or2_token,
+                        ..
+                    } = closure;
+                    return Err(syn::Error::new_spanned(
+                        quote::quote! { #or1_token #inputs #or2_token },
+                        format!(
+                            "The unmock closure of `{}` must take {expected} parameters: the `self`-replacement followed by the {} inputs of the method",
+                            method.method.sig.ident, method.non_receiver_arg_count
+                        ),
+                    ));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -52,7 +121,11 @@ impl syn::parse::Parse for Attr {
         let mut associated_consts = HashMap::default();
         let mut unmocks = None;
         let mut debug = false;
+        let mut derive_debug = false;
+        let mut derive_default_stub = None;
         let mut mirror = None;
+        let mut test_helpers = None;
+        let mut rename_fn: Option<RenameFn> = None;
 
         while !input.is_empty() {
             if input.peek(syn::token::Type) {
@@ -106,11 +179,29 @@ impl syn::parse::Parse for Attr {
                         let _: syn::token::Eq = input.parse()?;
                         debug = input.parse::<syn::LitBool>()?.value;
                     }
+                    "derive_debug" => {
+                        derive_debug = true;
+                    }
+                    "derive_default_stub" => {
+                        derive_default_stub = Some(keyword.span());
+                    }
                     "mirror" => {
                         let _: syn::token::Eq = input.parse()?;
                         let path: syn::Path = input.parse()?;
                         mirror = Some(path);
                     }
+                    "test_helpers" => {
+                        test_helpers = if input.peek(syn::token::Eq) {
+                            let _: syn::token::Eq = input.parse()?;
+                            Some(input.parse()?)
+                        } else {
+                            Some(keyword.clone())
+                        };
+                    }
+                    "rename_fn" => {
+                        let _: syn::token::Eq = input.parse()?;
+                        rename_fn = Some(input.parse()?);
+                    }
                     _ => return Err(syn::Error::new(keyword.span(), "Unrecognized keyword")),
                 }
 
@@ -122,6 +213,23 @@ impl syn::parse::Parse for Attr {
             }
         }
 
+        match (&mock_api, &rename_fn) {
+            (MockApi::Flattened(flat_mocks), Some(_)) => {
+                return Err(syn::Error::new(
+                    flat_mocks.span,
+                    "rename_fn cannot be combined with a list of MockFn names",
+                ));
+            }
+            // Without a module, the renamed MockFns are put at the top level
+            (MockApi::Hidden, Some(rename_fn)) => {
+                mock_api = MockApi::Flattened(FlattenedMethods {
+                    span: rename_fn.template.span(),
+                    idents: vec![],
+                });
+            }
+            _ => {}
+        }
+
         Ok(Self {
             prefix: prefix.unwrap_or_else(|| syn::parse_quote! { ::unimock }),
             mock_api,
@@ -131,6 +239,10 @@ impl syn::parse::Parse for Attr {
             mirror,
             input_lifetime: syn::Lifetime::new("'__i", proc_macro2::Span::call_site()),
             debug,
+            derive_debug,
+            derive_default_stub,
+            test_helpers,
+            rename_fn,
         })
     }
 }
@@ -161,11 +273,72 @@ impl FlattenedMethods {
     }
 }
 
+/// A template for MockFn struct names, e.g. `"{Trait}{Method}Mock"`.
+///
+/// Supported placeholders are `{Trait}` and `{method}` as written in the source,
+/// `{trait}` in snake_case and `{Method}` in PascalCase.
+pub struct RenameFn {
+    template: syn::LitStr,
+}
+
+impl RenameFn {
+    const PLACEHOLDERS: [&'static str; 4] = ["{Trait}", "{trait}", "{Method}", "{method}"];
+
+    pub fn mock_fn_ident(
+        &self,
+        trait_ident: &syn::Ident,
+        method_ident: &syn::Ident,
+    ) -> syn::Result<syn::Ident> {
+        let trait_name = trait_ident.to_string();
+        let method_name = method_ident.to_string();
+
+        let name = self
+            .template
+            .value()
+            .replace("{Trait}", &trait_name)
+            .replace("{trait}", &util::to_snake_case(&trait_name))
+            .replace("{Method}", &util::to_pascal_case(&method_name))
+            .replace("{method}", &method_name);
+
+        match syn::parse_str::<syn::Ident>(&name) {
+            Ok(_) => Ok(syn::Ident::new(&name, method_ident.span())),
+            Err(_) => Err(syn::Error::new(
+                self.template.span(),
+                format!("rename_fn produced `{name}` for `{method_name}`, which is not a valid identifier"),
+            )),
+        }
+    }
+}
+
+impl syn::parse::Parse for RenameFn {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let template: syn::LitStr = input.parse()?;
+
+        let mut rest = template.value();
+        for placeholder in Self::PLACEHOLDERS {
+            rest = rest.replace(placeholder, "");
+        }
+        if rest.contains(['{', '}']) {
+            return Err(syn::Error::new(
+                template.span(),
+                "Unknown placeholder. Supported placeholders are {Trait}, {trait}, {Method} and {method}",
+            ));
+        }
+
+        Ok(Self { template })
+    }
+}
+
 pub struct Unmock(Option<UnmockFn>);
 
-pub struct UnmockFn {
-    pub path: syn::Path,
-    pub params: Option<UnmockFnParams>,
+pub enum UnmockFn {
+    /// A function or associated function, called with the `self`-replacement and the inputs, or with the given params
+    Path {
+        path: syn::ExprPath,
+        params: Option<UnmockFnParams>,
+    },
+    /// A closure, called with the `self`-replacement and the inputs
+    Closure(syn::ExprClosure),
 }
 
 impl syn::parse::Parse for Unmock {
@@ -175,7 +348,15 @@ impl syn::parse::Parse for Unmock {
             return Ok(Self(None));
         }
 
-        let path: syn::Path = input.parse()?;
+        if input.peek(syn::token::Or)
+            || input.peek(syn::token::OrOr)
+            || input.peek(syn::token::Move)
+            || input.peek(syn::token::Async)
+        {
+            return Ok(Self(Some(UnmockFn::Closure(input.parse()?))));
+        }
+
+        let path: syn::ExprPath = input.parse()?;
         let mut opt_params = None;
 
         if input.peek(syn::token::Paren) {
@@ -198,7 +379,7 @@ impl syn::parse::Parse for Unmock {
             opt_params = Some(UnmockFnParams { params });
         }
 
-        Ok(Self(Some(UnmockFn {
+        Ok(Self(Some(UnmockFn::Path {
             path,
             params: opt_params,
         })))
@@ -0,0 +1,67 @@
+use quote::quote;
+
+use super::attr::{Attr, MockApi};
+use super::trait_info::TraitInfo;
+use super::util;
+
+/// Generate a `default_stub()` function for the mock module,
+/// stubbing every non-generic method whose output implements `Default`.
+pub fn def_default_stub_fn(
+    trait_info: &TraitInfo,
+    attr: &Attr,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let Some(span) = attr.derive_default_stub else {
+        return Ok(None);
+    };
+
+    if !matches!(attr.mock_api, MockApi::MockMod(_)) {
+        return Err(syn::Error::new(
+            span,
+            "derive_default_stub requires a mock module, e.g. `api=TraitMock`",
+        ));
+    }
+
+    if util::is_generic(&trait_info.input_trait.generics).0 {
+        return Err(syn::Error::new(
+            span,
+            "derive_default_stub is not supported for generic traits",
+        ));
+    }
+
+    let prefix = &attr.prefix;
+    let trait_ident = &trait_info.input_trait.ident;
+    let doc = syn::LitStr::new(
+        &format!(
+            "Stub every method of [{trait_ident}](super::{trait_ident}) whose output implements `Default` to return a default value for every call.\n\n\
+            The stub is tried after all other call patterns, so that other clauses can override it, and its methods may go uncalled."
+        ),
+        proc_macro2::Span::call_site(),
+    );
+
+    let pushes = trait_info
+        .methods
+        .iter()
+        .filter_map(Option::as_ref)
+        .filter(|method| !method.is_type_generic.0)
+        .map(|method| {
+            let mock_fn_ident = &method.mock_fn_ident;
+            let mirrored_attrs = method.mirrored_attrs();
+
+            quote! {
+                #(#mirrored_attrs)*
+                (&#prefix::private::DefaultStubProbe::new(#mock_fn_ident)).push_default_stub(&mut clauses);
+            }
+        });
+
+    Ok(Some(quote! {
+        #[doc = #doc]
+        pub fn default_stub() -> impl #prefix::Clause {
+            #[allow(unused_imports)]
+            use #prefix::private::{DefaultStubOf as _, NoDefaultStub as _};
+
+            let mut clauses = #prefix::build::Clauses::new();
+            #(#pushes)*
+            #prefix::private::default_stub(clauses)
+        }
+    }))
+}
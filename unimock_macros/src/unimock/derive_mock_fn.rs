@@ -0,0 +1,190 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+use super::answer_fn::make_answer_fn;
+use super::trait_info::TraitInfo;
+use super::{path_to_string, util, Attr, InputTypesTuple, NegativeBounds};
+
+/// Options of `#[mock_fn(..)]`
+struct MockFnAttr {
+    /// The mocked method, e.g. `MyTrait::method`, optionally preceded by the module of the trait
+    name: syn::Path,
+    inputs: Vec<syn::Type>,
+    output: syn::ReturnType,
+}
+
+impl MockFnAttr {
+    fn parse(input: &syn::DeriveInput) -> syn::Result<Self> {
+        let mut name: Option<syn::Path> = None;
+        let mut inputs = vec![];
+        let mut output = syn::ReturnType::Default;
+
+        for attribute in input
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("mock_fn"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("name") {
+                    name = Some(value.parse()?);
+                } else if meta.path.is_ident("inputs") {
+                    inputs = match value.parse::<syn::Type>()? {
+                        syn::Type::Tuple(tuple) => tuple.elems.into_iter().collect(),
+                        ty => vec![ty],
+                    };
+                } else if meta.path.is_ident("output") {
+                    let ty: syn::Type = value.parse()?;
+                    output = syn::parse_quote! { -> #ty };
+                } else {
+                    return Err(meta.error("Expected one of `name`, `inputs` or `output`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let name = match name {
+            Some(name) if name.segments.len() >= 2 => name,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "Expected `#[mock_fn(name = \"Trait::method\")]`",
+                ))
+            }
+        };
+
+        Ok(Self {
+            name,
+            inputs,
+            output,
+        })
+    }
+}
+
+/// Generate the output for `#[derive(MockFn)]`.
+///
+/// The `MockFn` implementation is derived from a trait with the single method described by `#[mock_fn(..)]`,
+/// analyzed the same way as a trait annotated with `#[unimock]`.
+pub fn generate(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Unit) => {}
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "MockFn can only be derived for a unit struct",
+            ))
+        }
+    }
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "MockFn can not be derived for a generic struct",
+        ));
+    }
+
+    let mock_fn_attr = MockFnAttr::parse(&input)?;
+    let attr: Attr = syn::parse2(TokenStream::new())?;
+    let prefix = &attr.prefix;
+
+    let mut segments = mock_fn_attr.name.segments.iter().rev();
+    let method_ident = &segments.next().unwrap().ident;
+    let trait_ident = &segments.next().unwrap().ident;
+    let module_path: Vec<String> = segments
+        .rev()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+
+    let arg_idents: Vec<syn::Ident> = (0..mock_fn_attr.inputs.len())
+        .map(|index| format_ident!("arg{index}"))
+        .collect();
+    let input_types = &mock_fn_attr.inputs;
+    let output = &mock_fn_attr.output;
+    let item_trait: syn::ItemTrait = syn::parse_quote! {
+        trait #trait_ident {
+            fn #method_ident(&self, #(#arg_idents: #input_types),*) #output;
+        }
+    };
+
+    let negative_bounds = NegativeBounds::default();
+    let trait_info = TraitInfo::analyze(&item_trait, &attr, &negative_bounds)?;
+    let Some(method) = trait_info.methods.first().and_then(Option::as_ref) else {
+        return Err(syn::Error::new(
+            mock_fn_attr.name.span(),
+            "The described method can not be mocked",
+        ));
+    };
+
+    let ident = &input.ident;
+    let input_lifetime = &attr.input_lifetime;
+    let input_types_tuple = InputTypesTuple::new(method, &trait_info, &attr);
+    let n_inputs = input_types_tuple.0.len();
+    let output_kind_assoc_type =
+        method
+            .output_structure
+            .output_kind_assoc_type(prefix, &trait_info, &attr);
+    let answer_fn_assoc_type = make_answer_fn(method, &trait_info, &attr);
+    let debug_inputs_fn = method.generate_debug_inputs_fn(&attr);
+
+    let trait_ident_lit = syn::LitStr::new(&trait_ident.to_string(), trait_ident.span());
+    let method_ident_lit = syn::LitStr::new(&method_ident.to_string(), method_ident.span());
+    let module_path = if module_path.is_empty() {
+        quote! { ::core::module_path!() }
+    } else {
+        let module_path = module_path.join("::");
+        quote! { #module_path }
+    };
+
+    // Elided lifetimes of the output borrow from the Unimock instance
+    let call_lifetime = syn::Lifetime::new("'__u", proc_macro2::Span::call_site());
+    let call_output = match output {
+        syn::ReturnType::Default => None,
+        syn::ReturnType::Type(_, ty) => {
+            let ty = util::substitute_lifetimes(ty.as_ref().clone(), Some(&call_lifetime));
+            Some(quote! { -> #ty })
+        }
+    };
+    let call_doc = format!(
+        "Evaluate a call to `{}` against the clauses of `unimock`.",
+        path_to_string(&mock_fn_attr.name)
+    );
+
+    Ok(quote! {
+        impl #prefix::MockFn for #ident {
+            type Inputs<#input_lifetime> = #input_types_tuple;
+            type OutputKind = #output_kind_assoc_type;
+            type AnswerFn = #answer_fn_assoc_type;
+
+            const N_INPUTS: ::core::option::Option<usize> = ::core::option::Option::Some(#n_inputs);
+
+            fn info() -> #prefix::MockFnInfo {
+                #prefix::MockFnInfo::new::<Self>()
+                    .path(&[#trait_ident_lit, #method_ident_lit])
+                    .module_path(#module_path)
+            }
+
+            #debug_inputs_fn
+        }
+
+        impl #ident {
+            #[doc = #call_doc]
+            ///
+            /// Call this from an implementation of the mocked trait.
+            #[track_caller]
+            #[allow(unused)]
+            pub fn call<#call_lifetime>(
+                unimock: &#call_lifetime #prefix::Unimock,
+                #(#arg_idents: #input_types),*
+            ) #call_output {
+                match #prefix::private::eval::<Self>(unimock, (#(#arg_idents),*)) {
+                    #prefix::private::Eval::Return(output) => output,
+                    #prefix::private::Eval::Continue(
+                        #prefix::private::Continuation::Answer(__answer_fn),
+                        (#(#arg_idents),*),
+                    ) => __answer_fn(unimock, #(#arg_idents),*),
+                    #prefix::private::Eval::Continue(cont, _) => cont.report(unimock),
+                }
+            }
+        }
+    })
+}
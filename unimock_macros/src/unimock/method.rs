@@ -8,7 +8,8 @@ use syn::visit_mut::VisitMut;
 use super::attr::MockApi;
 use super::output;
 use super::util::{
-    contains_lifetime, guess_is_pin, DotAwait, IsGeneric, IsTypeGeneric, RpitFuture,
+    contains_borrow, contains_lifetime, guess_is_pin, DotAwait, IsGeneric, IsTypeGeneric,
+    RpitFuture,
 };
 use super::Attr;
 
@@ -45,7 +46,7 @@ pub enum InputsSyntax {
 
 pub enum ArgClass<'t> {
     Receiver,
-    MutImpossible(&'t syn::PatIdent, &'t syn::Type),
+    MutImpossible(&'t syn::PatIdent, #[allow(dead_code)] &'t syn::Type),
     Other(&'t syn::PatIdent, &'t syn::Type),
     Unprocessable(&'t syn::PatType),
 }
@@ -66,6 +67,11 @@ impl<'t> MockMethod<'t> {
         }
     }
 
+    /// Whether the method returns the never type `!`
+    pub fn is_diverging(&self) -> bool {
+        matches!(&self.method.sig.output, syn::ReturnType::Type(_, ty) if matches!(ty.as_ref(), syn::Type::Never(_)))
+    }
+
     pub fn opt_dot_await(&self) -> Option<DotAwait> {
         if self.method.sig.asyncness.is_some()
             || matches!(
@@ -90,7 +96,7 @@ impl<'t> MockMethod<'t> {
         syntax: InputsSyntax,
         tupled: Tupled,
         attr: &'t Attr,
-    ) -> InputsDestructuring {
+    ) -> InputsDestructuring<'_> {
         InputsDestructuring {
             method: self,
             syntax,
@@ -127,6 +133,102 @@ impl<'t> MockMethod<'t> {
         }
     }
 
+    /// Whether calls can be forwarded to another implementation of the trait through an answer function.
+    ///
+    /// This requires a `&self` receiver, no generics, and a non-future owned output that borrows nothing.
+    /// Since the answer function replaces `Self` with `Unimock`, the signature may not mention `Self` at all.
+    pub fn can_delegate(&self) -> bool {
+        let sig = &self.method.sig;
+        let inputs = sig.inputs.iter().skip(1);
+        let output = &sig.output;
+        let mentions_self = quote! { #(#inputs)* #output }
+            .to_string()
+            .split_whitespace()
+            .any(|token| token == "Self");
+
+        matches!(self.receiver(), Receiver::Ref)
+            && !self.is_generic.0
+            && sig.asyncness.is_none()
+            && matches!(self.output_structure.wrapping, output::OutputWrapping::None)
+            && matches!(
+                self.output_structure.output_kind,
+                output::OutputKind::Owning
+            )
+            && !self
+                .output_structure
+                .output_type_stripped()
+                .is_some_and(contains_borrow)
+            && !mentions_self
+    }
+
+    /// The type parameters declared by the method itself, which an unmock function has to be generic over.
+    pub fn unmock_generic_params(&self) -> Vec<&syn::Ident> {
+        self.method
+            .sig
+            .generics
+            .params
+            .iter()
+            .filter_map(|generic_param| match generic_param {
+                syn::GenericParam::Type(type_param) => Some(&type_param.ident),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Turbofish forwarding the method's generic parameters to an unmock function at `path`.
+    ///
+    /// Nothing is forwarded when the path specifies its own generic arguments.
+    pub fn unmock_turbofish(&self, path: &syn::ExprPath) -> Option<TokenStream> {
+        let params = self.unmock_generic_params();
+        let has_generic_args = path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| !segment.arguments.is_empty());
+
+        if params.is_empty() || has_generic_args {
+            None
+        } else {
+            Some(quote! { ::<#(#params),*> })
+        }
+    }
+
+    /// Call an unmock closure with the `self`-replacement and the inputs of the method.
+    ///
+    /// The closure is passed through a generic function instead of being called directly,
+    /// so that its parameter types are inferred from the arguments.
+    /// This way the parameters need no type annotations, and an `async` block returned
+    /// from the closure may borrow from them.
+    pub fn unmock_closure_call(
+        &self,
+        closure: &syn::ExprClosure,
+        fn_params: TokenStream,
+    ) -> TokenStream {
+        let arg_idents: Vec<_> = (0..self.non_receiver_arg_count)
+            .map(|index| quote::format_ident!("__a{index}"))
+            .collect();
+        let arg_types: Vec<_> = (0..self.non_receiver_arg_count)
+            .map(|index| quote::format_ident!("__A{index}"))
+            .collect();
+
+        quote! {
+            {
+                fn __unmock_closure<__F, __S, #(#arg_types,)* __R>(
+                    f: __F,
+                    s: __S,
+                    #(#arg_idents: #arg_types,)*
+                ) -> __R
+                where
+                    __F: FnOnce(__S, #(#arg_types),*) -> __R,
+                {
+                    f(s, #(#arg_idents),*)
+                }
+
+                __unmock_closure(#closure, self, #fn_params)
+            }
+        }
+    }
+
     pub fn generate_debug_inputs_fn(&self, attr: &Attr) -> Option<proc_macro2::TokenStream> {
         let prefix = &attr.prefix;
         let first_param = self
@@ -137,7 +239,7 @@ impl<'t> MockMethod<'t> {
             .find(|fn_arg| matches!(fn_arg, syn::FnArg::Typed(_)));
 
         let body = if first_param.is_some() {
-            let inputs_try_debug_exprs = self.inputs_try_debug_exprs();
+            let inputs_try_debug_exprs = self.inputs_try_debug_exprs(prefix);
             quote! {
                 use #prefix::private::{ProperDebug, NoDebug};
                 #prefix::alloc::Box::new([#(#inputs_try_debug_exprs),*])
@@ -155,7 +257,10 @@ impl<'t> MockMethod<'t> {
         })
     }
 
-    pub fn inputs_try_debug_exprs(&self) -> impl Iterator<Item = proc_macro2::TokenStream> + 't {
+    pub fn inputs_try_debug_exprs(
+        &self,
+        prefix: &'t syn::Path,
+    ) -> impl Iterator<Item = proc_macro2::TokenStream> + 't {
         self.method
             .sig
             .inputs
@@ -165,7 +270,7 @@ impl<'t> MockMethod<'t> {
                 syn::FnArg::Receiver(_) => None,
                 syn::FnArg::Typed(pat_type) => match (index, pat_type.pat.as_ref()) {
                     (_, syn::Pat::Ident(pat_ident)) => {
-                        Some(try_debug_expr(pat_ident, &pat_type.ty))
+                        Some(try_debug_expr(pat_ident, &pat_type.ty, prefix))
                     }
                     _ => Some(
                         syn::Error::new(pat_type.span(), "Unprocessable argument")
@@ -290,6 +395,7 @@ pub fn extract_methods<'s>(
                 impl_trait_idents: adapt_sig_result.impl_trait_idents,
                 non_generic_mock_entry_ident: if is_type_generic.0 {
                     Some(generate_mock_fn_ident(
+                        &item_trait.ident,
                         method,
                         index,
                         IsTypeGeneric(false),
@@ -298,7 +404,13 @@ pub fn extract_methods<'s>(
                 } else {
                     None
                 },
-                mock_fn_ident: generate_mock_fn_ident(method, index, is_type_generic, attr)?,
+                mock_fn_ident: generate_mock_fn_ident(
+                    &item_trait.ident,
+                    method,
+                    index,
+                    is_type_generic,
+                    attr,
+                )?,
                 ident_lit: syn::LitStr::new(
                     &format!("{}", &method.sig.ident),
                     method.sig.ident.span(),
@@ -346,31 +458,33 @@ fn determine_mockable(method: &syn::TraitItemFn) -> Mockable {
 }
 
 fn generate_mock_fn_ident(
+    trait_ident: &syn::Ident,
     method: &syn::TraitItemFn,
     method_index: usize,
     generic: IsTypeGeneric,
     attr: &Attr,
 ) -> syn::Result<syn::Ident> {
+    let ident = match (&attr.mock_api, &attr.rename_fn) {
+        (MockApi::Hidden, _) => method.sig.ident.clone(),
+        (_, Some(rename_fn)) => rename_fn.mock_fn_ident(trait_ident, &method.sig.ident)?,
+        (MockApi::Flattened(flat_mocks), None) => flat_mocks.get_mock_ident(method_index)?.clone(),
+        (MockApi::MockMod(_), None) => method.sig.ident.clone(),
+    };
+
     if generic.0 {
-        match &attr.mock_api {
-            MockApi::Flattened(flat_mocks) => Ok(quote::format_ident!(
-                "__Generic{}",
-                flat_mocks.get_mock_ident(method_index)?
-            )),
-            MockApi::MockMod(_) | MockApi::Hidden => {
-                Ok(quote::format_ident!("__Generic{}", method.sig.ident))
-            }
-        }
+        Ok(quote::format_ident!("__Generic{}", ident))
+    } else if matches!(attr.mock_api, MockApi::Hidden) {
+        Ok(quote::format_ident!("UnimockHidden__{}", ident))
     } else {
-        match &attr.mock_api {
-            MockApi::Flattened(flat_mocks) => Ok(flat_mocks.get_mock_ident(method_index)?.clone()),
-            MockApi::MockMod(_) => Ok(method.sig.ident.clone()),
-            MockApi::Hidden => Ok(quote::format_ident!("UnimockHidden__{}", method.sig.ident)),
-        }
+        Ok(ident)
     }
 }
 
-fn try_debug_expr(pat_ident: &syn::PatIdent, ty: &syn::Type) -> proc_macro2::TokenStream {
+fn try_debug_expr(
+    pat_ident: &syn::PatIdent,
+    ty: &syn::Type,
+    prefix: &syn::Path,
+) -> proc_macro2::TokenStream {
     #[derive(Clone, Copy)]
     enum InnerKind {
         Slice,
@@ -400,6 +514,14 @@ fn try_debug_expr(pat_ident: &syn::PatIdent, ty: &syn::Type) -> proc_macro2::Tok
     }
 
     let ident = &pat_ident.ident;
+
+    // Byte buffers can be huge, so they are summarized instead of listing every byte
+    if is_byte_buffer(ty) {
+        return quote! {
+            ::core::option::Option::Some(#prefix::private::debug_bytes(#ident))
+        };
+    }
+
     let mut derefs: Vec<TokenStream> = vec![];
     collect_derefs(ty, &mut derefs);
 
@@ -414,6 +536,36 @@ fn try_debug_expr(pat_ident: &syn::PatIdent, ty: &syn::Type) -> proc_macro2::Tok
     }
 }
 
+/// Whether the type is `[u8]` or `Vec<u8>`, behind any number of references.
+fn is_byte_buffer(ty: &syn::Type) -> bool {
+    fn is_u8(ty: &syn::Type) -> bool {
+        matches!(ty, syn::Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("u8"))
+    }
+
+    match ty {
+        syn::Type::Reference(type_reference) => is_byte_buffer(&type_reference.elem),
+        syn::Type::Slice(type_slice) => is_u8(&type_slice.elem),
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(last_segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            if last_segment.ident != "Vec" {
+                return false;
+            }
+            match &last_segment.arguments {
+                syn::PathArguments::AngleBracketed(generic_args) => {
+                    matches!(
+                        generic_args.args.first(),
+                        Some(syn::GenericArgument::Type(ty)) if generic_args.args.len() == 1 && is_u8(ty)
+                    )
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
 pub struct InputsDestructuring<'t> {
     method: &'t MockMethod<'t>,
     syntax: InputsSyntax,
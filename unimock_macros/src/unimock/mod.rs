@@ -4,15 +4,22 @@ use syn::parse_quote;
 mod answer_fn;
 mod associated_future;
 mod attr;
+mod default_stub;
+pub mod derive_mock_fn;
 mod method;
+mod negative_bounds;
 mod output;
+mod test_helpers;
+#[cfg(feature = "trait-alias")]
+pub mod trait_alias;
 mod trait_info;
 mod util;
 
-use crate::doc::SynDoc;
+use crate::doc::{self, SynDoc};
 use crate::unimock::method::{InputsSyntax, Receiver, SelfReference, SelfToDelegator, Tupled};
 use crate::unimock::util::replace_self_ty_with_path;
 pub use attr::{Attr, MockApi};
+pub use negative_bounds::NegativeBounds;
 use trait_info::TraitInfo;
 
 use attr::{UnmockFn, UnmockFnParams};
@@ -21,8 +28,12 @@ use self::answer_fn::make_answer_fn;
 use self::method::{ArgClass, MockMethod};
 use self::util::{iter_generic_type_params, InferImplTrait};
 
-pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macro2::TokenStream> {
-    let trait_info = trait_info::TraitInfo::analyze(&item_trait, &attr)?;
+pub fn generate(
+    attr: Attr,
+    item_trait: syn::ItemTrait,
+    negative_bounds: NegativeBounds,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let trait_info = trait_info::TraitInfo::analyze(&item_trait, &attr, &negative_bounds)?;
     attr.validate(&trait_info)?;
 
     let prefix = &attr.prefix;
@@ -91,17 +102,21 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
             syn::TraitItem::Type(trait_item_type) => {
                 let ident = &trait_item_type.ident;
                 let ident_string = ident.to_string();
-                attr.associated_types
-                    .get(&ident_string)
-                    .map(|trait_item_type| {
-                        quote! {
-                            #trait_item_type
-                        }
-                    })
+                match attr.associated_types.get(&ident_string) {
+                    Some(trait_item_type) => Some(Ok(quote! {
+                        #trait_item_type
+                    })),
+                    None if trait_item_type.default.is_some()
+                        || is_associated_future(&trait_info, ident) =>
+                    {
+                        None
+                    }
+                    None => Some(Err(missing_associated_type_error(trait_item_type))),
+                }
             }
             _ => None,
         })
-        .collect::<Vec<_>>();
+        .collect::<syn::Result<Vec<_>>>()?;
 
     let attr_associated_consts = trait_info
         .input_trait
@@ -121,6 +136,8 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
         })
         .collect::<Vec<_>>();
 
+    let default_stub_fn = default_stub::def_default_stub_fn(&trait_info, &attr)?;
+
     let (opt_mock_interface_public, opt_mock_interface_private, impl_doc) = match &attr.mock_api {
         MockApi::Hidden => (
             None,
@@ -146,6 +163,7 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
                     #[allow(non_snake_case)]
                     #vis mod #module_ident {
                         #(#mock_fn_struct_items)*
+                        #default_stub_fn
                     }
                 }),
                 None,
@@ -193,11 +211,20 @@ pub fn generate(attr: Attr, item_trait: syn::ItemTrait) -> syn::Result<proc_macr
         None
     };
 
-    let output_trait = trait_info.output_trait;
+    let test_helpers_mod = test_helpers::def_test_helpers(&trait_info, &attr)?;
+    // The output trait keeps its negative bounds, which syn can't represent
+    let output_trait =
+        trait_info
+            .output_trait
+            .map(|output_trait| match &trait_info.negative_bounds.original {
+                Some(original) => original.clone(),
+                None => output_trait.to_token_stream(),
+            });
 
     Ok(quote! {
         #output_trait
         #opt_mock_interface_public
+        #test_helpers_mod
 
         // private part:
         const _: () = {
@@ -232,7 +259,7 @@ fn def_mock_fn(
     let method = method?;
     let prefix = &attr.prefix;
     let span = method.span();
-    let mirrored_attrs = method.mirrored_attrs();
+    let mirrored_attrs: Vec<_> = method.mirrored_attrs().collect();
     let impl_allow_lints = impl_allow_lints();
     let mock_fn_ident = &method.mock_fn_ident;
     let mock_fn_path = method.mock_fn_path(attr);
@@ -248,6 +275,7 @@ fn def_mock_fn(
 
     let input_lifetime = &attr.input_lifetime;
     let input_types_tuple = InputTypesTuple::new(method, trait_info, attr);
+    let n_inputs = input_types_tuple.0.len();
 
     let generic_params = util::Generics::fn_params(trait_info, Some(method));
     let generic_args = util::Generics::fn_args(
@@ -260,7 +288,17 @@ fn def_mock_fn(
     let doc_attrs = if matches!(attr.mock_api, attr::MockApi::Hidden) {
         vec![]
     } else {
-        method.mockfn_doc_attrs(&trait_info.trait_path)
+        let mut doc_attrs = method.mockfn_doc_attrs(&trait_info.trait_path);
+        if let Some(note) = trait_info
+            .negative_bounds
+            .doc_note(&method.method.sig.ident)
+        {
+            doc_attrs.push(quote! {
+                #[doc = ""]
+                #[doc = #note]
+            });
+        }
+        doc_attrs
     };
 
     let output_kind_assoc_type = method
@@ -271,10 +309,33 @@ fn def_mock_fn(
 
     let debug_inputs_fn = method.generate_debug_inputs_fn(attr);
 
+    // A mirrored trait is named by the path it was mirrored from, e.g. `core::fmt::Display`
+    let (trait_path_lit, module_path) = match &attr.mirror {
+        Some(mirror) => (syn::LitStr::new(&path_to_string(mirror), span), None),
+        None => (
+            trait_ident_lit.clone(),
+            Some(quote! { .module_path(::core::module_path!()) }),
+        ),
+    };
+    let signature_lit = syn::LitStr::new(
+        &format!(
+            "fn {}",
+            doc::signature_documentation(&method.method.sig, doc::SkipReceiver(false))
+        ),
+        span,
+    );
+
+    let derive_debug = if attr.derive_debug {
+        Some(quote! { #[derive(Debug)] })
+    } else {
+        None
+    };
+
     let gen_mock_fn_struct_item = |non_generic_ident: &syn::Ident| {
         quote! {
             #[allow(non_camel_case_types)]
             #(#doc_attrs)*
+            #derive_debug
             #mock_visibility struct #non_generic_ident;
         }
     };
@@ -293,9 +354,13 @@ fn def_mock_fn(
             type OutputKind = #output_kind_assoc_type;
             type AnswerFn = #answer_fn_assoc_type;
 
+            const N_INPUTS: ::core::option::Option<usize> = ::core::option::Option::Some(#n_inputs);
+
             fn info() -> #prefix::MockFnInfo {
                 #prefix::MockFnInfo::new::<Self>()
-                    .path(&[#trait_ident_lit, #method_ident_lit])
+                    .path(&[#trait_path_lit, #method_ident_lit])
+                    #module_path
+                    .signature(#signature_lit)
                     #info_set_default_impl
             }
 
@@ -312,6 +377,8 @@ fn def_mock_fn(
             MockApi::MockMod(ident) => Some(quote_spanned! { span=> #ident:: }),
             _ => None,
         };
+        // Must be as visible as the trait, since `with_types` exposes its `MockFn::AnswerFn`
+        let trait_vis = &trait_info.input_trait.vis;
 
         MockFnDef {
             mock_fn_struct_item: gen_mock_fn_struct_item(non_generic_ident),
@@ -333,15 +400,43 @@ fn def_mock_fn(
                 }
 
                 #[allow(non_camel_case_types)]
-                struct #mock_fn_ident #generic_args #phantoms_tuple;
+                #trait_vis struct #mock_fn_ident #generic_args #phantoms_tuple;
 
                 #impl_block
             },
         }
     } else {
+        // Lets `delegates_to` forward calls to another implementation of the trait
+        let delegate_impl = if method.can_delegate() {
+            let trait_path = &trait_info.trait_path;
+            let method_ident = &method.method.sig.ident;
+            let fn_params =
+                method.inputs_destructuring(InputsSyntax::FnParams, Tupled(false), attr);
+
+            Some(quote_spanned! { span=>
+                #(#mirrored_attrs)*
+                #impl_allow_lints
+                impl<__T: #trait_path + Send + Sync + 'static> #prefix::private::DelegateTo<__T> for #mock_fn_path {
+                    fn delegate_answer(
+                        instance: #prefix::alloc::Arc<__T>,
+                    ) -> #prefix::alloc::Arc<<Self as #prefix::MockFn>::AnswerFn> {
+                        #prefix::alloc::Arc::new(move |_, #fn_params| {
+                            let _guard = #prefix::private::DelegateGuard::enter::<Self, __T>(&*instance);
+                            <__T as #trait_path>::#method_ident(&instance, #fn_params)
+                        })
+                    }
+                }
+            })
+        } else {
+            None
+        };
+
         MockFnDef {
             mock_fn_struct_item: gen_mock_fn_struct_item(mock_fn_ident),
-            impl_details: impl_block,
+            impl_details: quote! {
+                #impl_block
+                #delegate_impl
+            },
         }
     };
 
@@ -412,20 +507,51 @@ fn def_method_impl(
     let body = match kind {
         MethodImplKind::Mock => {
             let unmock_arm = attr.get_unmock_fn(index).map(
-                |UnmockFn {
-                     path: unmock_path,
-                     params: unmock_params,
-                 }| {
+                |unmock_fn| {
                     let fn_params =
                         method.inputs_destructuring(InputsSyntax::FnParams, Tupled(false), attr);
 
-                    let unmock_expr = match unmock_params {
-                        None => quote! {
-                            #unmock_path(self, #fn_params) #opt_dot_await
-                        },
-                        Some(UnmockFnParams { params }) => quote! {
-                            #unmock_path(#params) #opt_dot_await
-                        },
+                    let unmock_call = match unmock_fn {
+                        // Spanned by the path, so that a signature mismatch is reported at the attribute
+                        UnmockFn::Path { path, params: None } => {
+                            let turbofish = method.unmock_turbofish(path);
+                            quote_spanned! { syn::spanned::Spanned::span(path)=>
+                                #path #turbofish(self, #fn_params)
+                            }
+                        }
+                        UnmockFn::Path { path, params: Some(UnmockFnParams { params }) } => {
+                            let turbofish = method.unmock_turbofish(path);
+                            quote! {
+                                #path #turbofish(#params)
+                            }
+                        }
+                        UnmockFn::Closure(closure) => {
+                            method.unmock_closure_call(closure, quote! { #fn_params })
+                        }
+                    };
+
+                    let unmock_guard = quote! {
+                        let __unmock_guard = #prefix::private::UnmockGuard::enter::<#mock_fn_path #eval_generic_args>(#self_ref);
+                    };
+
+                    // The recursion depth is counted per thread, so it must not be held across an await
+                    let unmock_expr = if opt_dot_await.is_some() {
+                        quote! {
+                            {
+                                let __unmock_future = {
+                                    #unmock_guard
+                                    #unmock_call
+                                };
+                                __unmock_future #opt_dot_await
+                            }
+                        }
+                    } else {
+                        quote! {
+                            {
+                                #unmock_guard
+                                #unmock_call
+                            }
+                        }
                     };
 
                     let eval_pattern = method.inputs_destructuring(
@@ -434,8 +560,21 @@ fn def_method_impl(
                         attr,
                     );
 
+                    // Only owned outputs can be passed through an output mapper
+                    let unmock_map_arm = match method.output_structure.output_kind {
+                        output::OutputKind::Owning => Some(quote! {
+                            #prefix::private::Eval::Continue(#prefix::private::Continuation::UnmockMap(__output_mapper), #eval_pattern) => {
+                                __output_mapper.apply(#unmock_expr)
+                            }
+                        }),
+                        _ => None,
+                    };
+
                     quote! {
-                        #prefix::private::Eval::Continue(#prefix::private::Continuation::Unmock, #eval_pattern) => #unmock_expr,
+                        #prefix::private::Eval::Continue(#prefix::private::Continuation::Unmock, #eval_pattern) => {
+                            #unmock_expr
+                        }
+                        #unmock_map_arm
                     }
                 },
             );
@@ -493,7 +632,70 @@ fn def_method_impl(
                 None
             };
 
+            // A diverging method's output is `Infallible`, which has to be matched into `!`
+            let is_diverging = method.is_diverging();
+            let return_output = if is_diverging {
+                quote! { match output {} }
+            } else {
+                quote! { output }
+            };
+            let answer_call = |answer_self: proc_macro2::TokenStream| {
+                if is_diverging {
+                    quote! { match __answer_fn(#answer_self, #fn_params) {} }
+                } else {
+                    quote! { __answer_fn(#answer_self, #fn_params) }
+                }
+            };
+
+            // Only owned outputs that borrow nothing can be deserialized from a replayed call
+            let is_replayable = matches!(
+                method.output_structure.output_kind,
+                output::OutputKind::Owning
+            ) && !is_diverging
+                && !method
+                    .output_structure
+                    .output_type_stripped()
+                    .is_some_and(util::contains_borrow);
+            let replay_expr = |replay_self: proc_macro2::TokenStream| {
+                is_replayable.then(|| {
+                    quote! {
+                        {
+                            use #prefix::private::{NoReplayOutput as _, ReplayOutputOf as _};
+                            (&#prefix::private::ReplayProbe::new(__cont)).replay(#replay_self)
+                        }
+                    }
+                })
+            };
+
             match &receiver {
+                // Nothing is ever returned, so no borrow of self needs to escape the evaluation
+                Receiver::MutRef { .. } | Receiver::Pin { .. } if is_diverging => {
+                    let answer_call = answer_call(quote! { #self_ref });
+                    let eval_pattern_no_mut = method.inputs_destructuring(
+                        InputsSyntax::EvalPatternMutAsWildcard,
+                        Tupled(true),
+                        attr,
+                    );
+
+                    let default_impl_delegate_arm = default_delegator_call.as_ref().map(|default_delegator_call| {
+                        quote! {
+                            #prefix::private::Eval::Continue(#prefix::private::Continuation::CallDefaultImpl, #eval_pattern_no_mut) => {
+                                #default_delegator_call
+                            },
+                        }
+                    });
+
+                    quote_spanned! { span=>
+                        match #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params) {
+                            #prefix::private::Eval::Return(output) => #return_output,
+                            #prefix::private::Eval::Continue(#prefix::private::Continuation::Answer(__answer_fn), #eval_pattern_no_mut) => {
+                                #answer_call
+                            }
+                            #default_impl_delegate_arm
+                            #prefix::private::Eval::Continue(cont, _) => cont.report(#self_ref),
+                        }
+                    }
+                }
                 Receiver::MutRef { .. } | Receiver::Pin { .. } => {
                     let eval_pattern_no_mut = method.inputs_destructuring(
                         InputsSyntax::EvalPatternMutAsWildcard,
@@ -524,6 +726,11 @@ fn def_method_impl(
                     } else {
                         quote!()
                     };
+                    let replay_arm = replay_expr(quote! { __self }).map(|replay_expr| {
+                        quote! {
+                            __cont @ #prefix::private::Continuation::Replay(_) => #replay_expr,
+                        }
+                    });
 
                     quote! {
                         let (__cont, #eval_pattern_all) = #prefix::polonius::_polonius!(|#self_ref| -> #polonius_return_type {
@@ -537,11 +744,13 @@ fn def_method_impl(
                                 __answer_fn(__self, #fn_params)
                             }
                             #default_impl_input_eval_arm
+                            #replay_arm
                             cont => cont.report(__self)
                         }
                     }
                 }
                 _ => {
+                    let answer_call = answer_call(quote! { self });
                     let eval_pattern_no_mut = method.inputs_destructuring(
                         InputsSyntax::EvalPatternMutAsWildcard,
                         Tupled(true),
@@ -558,14 +767,32 @@ fn def_method_impl(
                         None
                     };
 
+                    let replay_arm = replay_expr(quote! { #self_ref }).map(|replay_expr| {
+                        quote! {
+                            #prefix::private::Eval::Continue(__cont @ #prefix::private::Continuation::Replay(_), _) => #replay_expr,
+                        }
+                    });
+
+                    // In async context, a delayed response is awaited
+                    let eval_expr = if opt_dot_await.is_some() {
+                        quote_spanned! { span=>
+                            #prefix::private::eval_async::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params).await
+                        }
+                    } else {
+                        quote_spanned! { span=>
+                            #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params)
+                        }
+                    };
+
                     quote_spanned! { span=>
-                        match #prefix::private::eval::<#mock_fn_path #eval_generic_args>(#self_ref, #inputs_eval_params) {
-                            #prefix::private::Eval::Return(output) => output,
+                        match #eval_expr {
+                            #prefix::private::Eval::Return(output) => #return_output,
                             #prefix::private::Eval::Continue(#prefix::private::Continuation::Answer(__answer_fn), #eval_pattern_no_mut) => {
-                                __answer_fn(self, #fn_params)
+                                #answer_call
                             }
                             #unmock_arm
                             #default_impl_delegate_arm
+                            #replay_arm
                             #prefix::private::Eval::Continue(cont, _) => cont.report(#self_ref),
                         }
                     }
@@ -668,6 +895,46 @@ fn prefix_with_span(prefix: &syn::Path, span: proc_macro2::Span) -> syn::Path {
     prefix
 }
 
+/// Whether the associated type is the future of a method, which is implemented by the macro.
+fn is_associated_future(trait_info: &TraitInfo, ident: &syn::Ident) -> bool {
+    trait_info
+        .methods
+        .iter()
+        .filter_map(Option::as_ref)
+        .any(|method| match &method.output_structure.wrapping {
+            output::OutputWrapping::AssociatedFuture(trait_item_type) => {
+                &trait_item_type.ident == ident
+            }
+            _ => false,
+        })
+}
+
+fn missing_associated_type_error(trait_item_type: &syn::TraitItemType) -> syn::Error {
+    let ident = &trait_item_type.ident;
+    let params = trait_item_type
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Lifetime(param) => param.lifetime.to_string(),
+            syn::GenericParam::Type(param) => param.ident.to_string(),
+            syn::GenericParam::Const(param) => param.ident.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let params = if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    };
+
+    syn::Error::new(
+        ident.span(),
+        format!(
+            "The associated type `{ident}` must be given a concrete type for the mock, e.g. `#[unimock(type {ident}{params} = ...;)]`"
+        ),
+    )
+}
+
 struct InputTypesTuple(Vec<syn::Type>);
 
 impl InputTypesTuple {
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Delimiter, Group, Spacing, TokenStream, TokenTree};
+
+/// Negative trait bounds (`T: !Send`) found in the input trait.
+///
+/// These are a nightly-only feature that `syn` is unable to parse.
+/// Unimock has to be `Send + Sync`, so it can't honor them anyway.
+/// They are removed from the tokens before parsing, and are then only present in the re-emitted trait definition.
+#[derive(Default)]
+pub struct NegativeBounds {
+    /// The unmodified trait tokens, if any negative bounds were removed
+    pub original: Option<TokenStream>,
+    /// Negative bounds on the trait itself, its generics or associated items
+    trait_level: Vec<String>,
+    /// Negative bounds on the generics of a method, keyed by method name
+    methods: BTreeMap<String, Vec<String>>,
+}
+
+impl NegativeBounds {
+    /// Remove all negative bounds from the tokens of a trait item
+    pub fn strip(input: TokenStream) -> (TokenStream, Self) {
+        let mut bounds = Self::default();
+        let mut state = StripState {
+            bounds: &mut bounds,
+            current_method: None,
+            bounded_ident: None,
+        };
+        let output = state.strip_level(input.clone(), 0);
+
+        if !bounds.trait_level.is_empty() || !bounds.methods.is_empty() {
+            bounds.original = Some(input);
+        }
+
+        (output, bounds)
+    }
+
+    /// A note for the documentation of a MockFn, if negative bounds were omitted from its implementation
+    pub fn doc_note(&self, method_ident: &syn::Ident) -> Option<String> {
+        let omitted = self
+            .trait_level
+            .iter()
+            .chain(
+                self.methods
+                    .get(&method_ident.to_string())
+                    .into_iter()
+                    .flatten(),
+            )
+            .map(|bound| format!("`{bound}`"))
+            .collect::<Vec<_>>();
+
+        if omitted.is_empty() {
+            None
+        } else {
+            Some(format!(
+                "Note: The negative bounds {} were omitted from the mock implementation, because `Unimock` must be `Send + Sync`.",
+                omitted.join(", ")
+            ))
+        }
+    }
+}
+
+struct StripState<'b> {
+    bounds: &'b mut NegativeBounds,
+    current_method: Option<String>,
+    /// The identifier in front of the last seen `:`
+    bounded_ident: Option<String>,
+}
+
+impl<'b> StripState<'b> {
+    /// Strip one level of tokens.
+    ///
+    /// Brace groups other than the trait body are left alone, because they contain method bodies and other expressions.
+    fn strip_level(&mut self, input: TokenStream, brace_depth: usize) -> TokenStream {
+        let trees: Vec<TokenTree> = input.into_iter().collect();
+        let mut output: Vec<TokenTree> = Vec::with_capacity(trees.len());
+        let mut index = 0;
+
+        while index < trees.len() {
+            match &trees[index] {
+                TokenTree::Punct(punct) if punct.as_char() == '!' && is_bound_position(&output) => {
+                    let end = bound_path_end(&trees, index + 1);
+                    if end == index + 1 {
+                        // Not followed by a path, e.g. the never type
+                        output.push(trees[index].clone());
+                        index += 1;
+                        continue;
+                    }
+
+                    let path = trees[index + 1..end]
+                        .iter()
+                        .cloned()
+                        .collect::<TokenStream>()
+                        .to_string();
+                    self.record(match &self.bounded_ident {
+                        Some(ident) => format!("{ident}: !{path}"),
+                        None => format!("!{path}"),
+                    });
+
+                    index = end;
+                    if is_punct(output.last(), '+') {
+                        output.pop();
+                    } else if is_punct(trees.get(index), '+') {
+                        index += 1;
+                    }
+                }
+                TokenTree::Punct(punct)
+                    if punct.as_char() == ':' && punct.spacing() == Spacing::Alone =>
+                {
+                    if !is_joint_colon(output.last()) {
+                        self.bounded_ident = match output.last() {
+                            Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+                            _ => None,
+                        };
+                    }
+                    output.push(trees[index].clone());
+                    index += 1;
+                }
+                TokenTree::Ident(ident) if brace_depth == 1 => {
+                    if ident == "fn" {
+                        self.current_method = match trees.get(index + 1) {
+                            Some(TokenTree::Ident(method_ident)) => Some(method_ident.to_string()),
+                            _ => None,
+                        };
+                    } else if ident == "type" || ident == "const" {
+                        self.current_method = None;
+                    }
+                    output.push(trees[index].clone());
+                    index += 1;
+                }
+                TokenTree::Group(group)
+                    if group.delimiter() != Delimiter::Brace || brace_depth == 0 =>
+                {
+                    let inner_depth = if group.delimiter() == Delimiter::Brace {
+                        brace_depth + 1
+                    } else {
+                        brace_depth
+                    };
+                    let mut stripped = Group::new(
+                        group.delimiter(),
+                        self.strip_level(group.stream(), inner_depth),
+                    );
+                    stripped.set_span(group.span());
+                    output.push(TokenTree::Group(stripped));
+                    index += 1;
+                }
+                other => {
+                    output.push(other.clone());
+                    index += 1;
+                }
+            }
+        }
+
+        output.into_iter().collect()
+    }
+
+    fn record(&mut self, bound: String) {
+        match &self.current_method {
+            Some(method) => self
+                .bounds
+                .methods
+                .entry(method.clone())
+                .or_default()
+                .push(bound),
+            None => self.bounds.trait_level.push(bound),
+        }
+    }
+}
+
+/// A `!` is a negative bound when it follows the `:` of a bound list (but not a `::`), or a `+`.
+fn is_bound_position(output: &[TokenTree]) -> bool {
+    match output {
+        [.., before, TokenTree::Punct(colon)]
+            if colon.as_char() == ':' && colon.spacing() == Spacing::Alone =>
+        {
+            !is_joint_colon(Some(before))
+        }
+        [.., TokenTree::Punct(plus)] => plus.as_char() == '+',
+        _ => false,
+    }
+}
+
+/// Find the end of the trait path following a `!`, including any generic arguments.
+fn bound_path_end(trees: &[TokenTree], start: usize) -> usize {
+    let mut angle_depth = 0;
+    let mut index = start;
+
+    while let Some(tree) = trees.get(index) {
+        match tree {
+            // Two consecutive identifiers, e.g. `!Send where`, end the path
+            TokenTree::Ident(_)
+                if angle_depth == 0
+                    && index > start
+                    && matches!(trees[index - 1], TokenTree::Ident(_)) =>
+            {
+                break
+            }
+            TokenTree::Ident(_) => {}
+            TokenTree::Punct(punct) if punct.as_char() == ':' => {}
+            TokenTree::Punct(punct) if punct.as_char() == '<' => angle_depth += 1,
+            TokenTree::Punct(punct) if punct.as_char() == '>' && angle_depth > 0 => {
+                angle_depth -= 1
+            }
+            _ if angle_depth > 0 => {}
+            _ => break,
+        }
+        index += 1;
+    }
+
+    index
+}
+
+fn is_punct(tree: Option<&TokenTree>, ch: char) -> bool {
+    matches!(tree, Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+}
+
+fn is_joint_colon(tree: Option<&TokenTree>) -> bool {
+    matches!(tree, Some(TokenTree::Punct(punct)) if punct.as_char() == ':' && punct.spacing() == Spacing::Joint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NegativeBounds;
+    use quote::quote;
+
+    fn strip(input: proc_macro2::TokenStream) -> (String, NegativeBounds) {
+        let (output, bounds) = NegativeBounds::strip(input);
+        (output.to_string(), bounds)
+    }
+
+    #[test]
+    fn should_leave_traits_without_negative_bounds_alone() {
+        let input = quote! {
+            trait Trait {
+                fn foo<T: Clone>(&self, t: T) -> ! where T: ::core::fmt::Debug;
+                fn bar(&self) -> bool { let x: bool = true; !x }
+            }
+        };
+        let (output, bounds) = strip(input.clone());
+
+        assert_eq!(input.to_string(), output);
+        assert!(bounds.original.is_none());
+    }
+
+    #[test]
+    fn should_strip_method_bounds() {
+        let (output, bounds) = strip(quote! {
+            trait Trait {
+                fn foo<T: !Send + Clone, U: Clone + !Sync>(&self, t: T, u: U) where T: !core::marker::Sync;
+                fn bar(&self);
+            }
+        });
+
+        assert_eq!(
+            quote! {
+                trait Trait {
+                    fn foo<T: Clone, U: Clone>(&self, t: T, u: U) where T:;
+                    fn bar(&self);
+                }
+            }
+            .to_string(),
+            output
+        );
+        assert!(bounds.original.is_some());
+        assert_eq!(
+            Some("Note: The negative bounds `T: !Send`, `U: !Sync`, `T: !core :: marker :: Sync` were omitted from the mock implementation, because `Unimock` must be `Send + Sync`.".to_string()),
+            bounds.doc_note(&syn::parse_quote!(foo))
+        );
+        assert_eq!(None, bounds.doc_note(&syn::parse_quote!(bar)));
+    }
+
+    #[test]
+    fn should_end_bound_before_where_clause() {
+        let (output, _) = strip(quote! {
+            trait Trait {
+                type Assoc: !Send where Self: Sized;
+            }
+        });
+
+        assert_eq!(
+            quote! {
+                trait Trait {
+                    type Assoc: where Self: Sized;
+                }
+            }
+            .to_string(),
+            output
+        );
+    }
+
+    #[test]
+    fn should_strip_trait_level_bounds() {
+        let (output, bounds) = strip(quote! {
+            trait Trait<T: !Send> {
+                fn foo(&self, t: T);
+            }
+        });
+
+        assert_eq!(
+            quote! {
+                trait Trait<T:> {
+                    fn foo(&self, t: T);
+                }
+            }
+            .to_string(),
+            output
+        );
+        assert!(bounds.doc_note(&syn::parse_quote!(foo)).is_some());
+    }
+
+    #[test]
+    fn should_not_touch_method_bodies() {
+        let input = quote! {
+            trait Trait {
+                fn foo(&self) -> Foo { Foo { flag: !FLAG } }
+            }
+        };
+        let (output, bounds) = strip(input.clone());
+
+        assert_eq!(input.to_string(), output);
+        assert!(bounds.original.is_none());
+    }
+}
@@ -59,7 +59,7 @@ impl OutputStructure {
 
 pub enum OutputWrapping {
     None,
-    RpitFuture(syn::Type),
+    RpitFuture(#[allow(dead_code)] syn::Type),
     AssociatedFuture(syn::TraitItemType),
 }
 
@@ -71,6 +71,7 @@ pub enum OutputKind {
     StaticReference,
     Shallow,
     Deep,
+    CowLending,
 }
 
 impl OutputKind {
@@ -87,6 +88,7 @@ impl OutputKind {
             Self::StaticReference => "StaticRef",
             Self::Shallow => "Shallow",
             Self::Deep => "Deep",
+            Self::CowLending => "CowLending",
         }
     }
 }
@@ -105,6 +107,18 @@ pub fn determine_output_structure(
             output_ty_with_kind: AssociatedInnerType::Unit,
         },
         syn::ReturnType::Type(_, output_ty) => match output_ty.as_ref() {
+            // `!` can't be used as an associated type, `Infallible` is the stable equivalent
+            syn::Type::Never(never) => {
+                let infallible: syn::Type = parse_quote_spanned! {never.span()=>
+                    ::core::convert::Infallible
+                };
+                OutputStructure {
+                    wrapping: OutputWrapping::None,
+                    output_kind: OutputKind::Owning,
+                    output_ty_stripped: AssociatedInnerType::Typed(infallible.clone()),
+                    output_ty_with_kind: AssociatedInnerType::Typed(infallible),
+                }
+            }
             syn::Type::Reference(type_reference) => {
                 let mut inner_ty = *type_reference.elem.clone();
                 let borrow_info = ReturnTypeAnalyzer::analyze_borrows(sig, &mut inner_ty);
@@ -150,6 +164,17 @@ pub fn determine_owned_or_deep_output_structure(
     output_ty: &syn::Type,
     attr: &Attr,
 ) -> OutputStructure {
+    if let Some(mut borrowed_ty) = find_cow_borrowing_from_self(sig, output_ty) {
+        let borrow_info = ReturnTypeAnalyzer::analyze_borrows(sig, &mut borrowed_ty);
+
+        return OutputStructure {
+            wrapping: OutputWrapping::None,
+            output_kind: OutputKind::CowLending,
+            output_ty_stripped: AssociatedInnerType::Typed(output_ty.clone()),
+            output_ty_with_kind: AssociatedInnerType::new_static(borrowed_ty, &borrow_info),
+        };
+    }
+
     let output_ty = output_ty.clone();
     let mut inner_ty = output_ty.clone();
 
@@ -208,6 +233,42 @@ pub fn determine_owned_or_deep_output_structure(
     }
 }
 
+/// The borrowed type `T` of a `Cow<'_, T>` output whose lifetime is the lifetime of `self`.
+fn find_cow_borrowing_from_self(sig: &syn::Signature, output_ty: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(path) = output_ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if path.qself.is_some() || segment.ident != "Cow" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(angle) = &segment.arguments else {
+        return None;
+    };
+
+    let mut lifetime = None;
+    let mut borrowed_ty = None;
+    for generic_arg in &angle.args {
+        match generic_arg {
+            syn::GenericArgument::Lifetime(arg) => lifetime = Some(arg),
+            syn::GenericArgument::Type(arg) => borrowed_ty = Some(arg),
+            _ => return None,
+        }
+    }
+
+    let borrows_from_self = match lifetime {
+        None => true,
+        Some(lifetime) if lifetime.ident == "_" => true,
+        Some(lifetime) => find_param_lifetime(sig, &lifetime.ident) == Some(0),
+    };
+
+    if borrows_from_self {
+        borrowed_ty.cloned()
+    } else {
+        None
+    }
+}
+
 fn make_generic_kind(ty: syn::Type, attr: &Attr) -> (OutputKind, syn::Type) {
     match ty {
         syn::Type::Reference(reference) => {
@@ -348,6 +409,7 @@ fn find_param_lifetime(sig: &syn::Signature, lifetime_ident: &syn::Ident) -> Opt
 }
 
 #[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
 enum AssociatedInnerType {
     Unit,
     Typed(syn::Type),
@@ -487,10 +549,10 @@ fn add_dyn_static_bound(ty: &mut syn::Type) {
                         let mut tmp = Box::new(syn::Type::Verbatim(TokenStream::new()));
                         std::mem::swap(&mut tmp, &mut ty.elem);
 
-                        ty.elem = Box::new(syn::Type::Paren(syn::TypeParen {
+                        *ty.elem = syn::Type::Paren(syn::TypeParen {
                             paren_token: syn::token::Paren::default(),
                             elem: tmp,
-                        }));
+                        });
                     }
                 }
             }
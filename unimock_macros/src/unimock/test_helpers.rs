@@ -0,0 +1,123 @@
+use quote::{format_ident, quote};
+
+use super::attr::{Attr, MockApi};
+use super::method::MockMethod;
+use super::output::OutputKind;
+use super::trait_info::TraitInfo;
+use super::util;
+
+/// Generate a module with one-line stub factories for each non-generic method.
+///
+/// The module is not gated on `cfg(test)`, it exists wherever the mock API exists.
+pub fn def_test_helpers(
+    trait_info: &TraitInfo,
+    attr: &Attr,
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let Some(module_ident) = &attr.test_helpers else {
+        return Ok(None);
+    };
+
+    if matches!(attr.mock_api, MockApi::Hidden) {
+        return Err(syn::Error::new(
+            module_ident.span(),
+            "test_helpers requires a mock API, e.g. `api=TraitMock`",
+        ));
+    }
+
+    let prefix = &attr.prefix;
+    let trait_snake = util::to_snake_case(&trait_info.input_trait.ident.to_string());
+    let trait_is_generic = util::is_generic(&trait_info.input_trait.generics).0;
+
+    // Items are visible to the module containing the trait, which is where the mock API lives.
+    let fn_vis = match &trait_info.input_trait.vis {
+        syn::Visibility::Inherited => quote! { pub(super) },
+        _ => quote! { pub },
+    };
+
+    let helper_fns = trait_info
+        .methods
+        .iter()
+        .filter_map(Option::as_ref)
+        .filter(|method| !trait_is_generic && !method.is_type_generic.0)
+        .map(|method| {
+            let mock_fn_path = method.mock_fn_path(attr);
+            let method_ident = &method.method.sig.ident;
+            let mirrored_attrs = method.mirrored_attrs().collect::<Vec<_>>();
+
+            let default_fn = supports_returns_default(method).then(|| {
+                let default_ident = format_ident!("stub_{}_{}_default", trait_snake, method_ident);
+                let default_doc = syn::LitStr::new(
+                    &format!("Stub `{method_ident}` to return a default value for every call.\n\nOnly usable if the output implements `Default`."),
+                    proc_macro2::Span::call_site(),
+                );
+
+                // Whether the output implements `Default` can't be seen from the signature.
+                // The `for<'h>` bound is only checked when the function is used.
+                quote! {
+                    #(#mirrored_attrs)*
+                    #[doc = #default_doc]
+                    #fn_vis fn #default_ident() -> impl #prefix::Clause
+                    where
+                        for<'h> super::#mock_fn_path: #prefix::private::EachCallReturnsDefault,
+                    {
+                        #prefix::private::EachCallReturnsDefault::each_call_returns_default(super::#mock_fn_path)
+                    }
+                }
+            });
+
+            let returns_fn = supports_returns(method).then(|| {
+                let returns_ident = format_ident!("stub_{}_{}_returns", trait_snake, method_ident);
+                let returns_doc = syn::LitStr::new(
+                    &format!("Stub `{method_ident}` to return `value` for every call."),
+                    proc_macro2::Span::call_site(),
+                );
+
+                quote! {
+                    #(#mirrored_attrs)*
+                    #[doc = #returns_doc]
+                    #fn_vis fn #returns_ident<T>(value: T) -> impl #prefix::Clause
+                    where
+                        super::#mock_fn_path: #prefix::private::EachCallReturns<T>,
+                    {
+                        #prefix::private::EachCallReturns::each_call_returns(super::#mock_fn_path, value)
+                    }
+                }
+            });
+
+            quote! {
+                #default_fn
+                #returns_fn
+            }
+        });
+
+    let mod_doc = syn::LitStr::new(
+        &format!(
+            "Stub factories for [{}](super::{}).",
+            trait_info.input_trait.ident, trait_info.input_trait.ident
+        ),
+        proc_macro2::Span::call_site(),
+    );
+    let mod_vis = &trait_info.input_trait.vis;
+
+    Ok(Some(quote! {
+        #[doc = #mod_doc]
+        #[allow(dead_code)]
+        #mod_vis mod #module_ident {
+            #(#helper_fns)*
+        }
+    }))
+}
+
+/// `returns_default` is only available for owned outputs.
+fn supports_returns_default(method: &MockMethod) -> bool {
+    !method.is_diverging() && matches!(method.output_structure.output_kind, OutputKind::Owning)
+}
+
+/// `returns` is available for all outputs except mutable references and `!`.
+fn supports_returns(method: &MockMethod) -> bool {
+    !method.is_diverging()
+        && !matches!(
+            method.output_structure.output_kind,
+            OutputKind::MutSelfReference
+        )
+}
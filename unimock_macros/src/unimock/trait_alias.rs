@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use super::Attr;
+
+/// Generate the output for `#[unimock]` on a trait alias.
+///
+/// A trait alias is implemented for every type implementing all of its constituent traits,
+/// so there is nothing to implement for `Unimock`. The constituent traits need their own `#[unimock]`.
+/// Instead, the alias is checked to actually be implemented by `Unimock`,
+/// reporting a missing mock at the alias instead of at the place it is used.
+pub fn generate(attr: Attr, item_trait_alias: syn::ItemTraitAlias) -> syn::Result<TokenStream> {
+    if attr.has_trait_options() {
+        return Err(syn::Error::new(
+            item_trait_alias.ident.span(),
+            "A trait alias only accepts the `prefix` option. Put the other options on the `#[unimock]` of each constituent trait",
+        ));
+    }
+
+    let prefix = &attr.prefix;
+    let ident = &item_trait_alias.ident;
+
+    // A generic alias can't be checked without knowing its parameters
+    let assertion = if item_trait_alias.generics.params.is_empty() {
+        Some(quote_spanned! { item_trait_alias.bounds.span()=>
+            const _: () = {
+                fn assert_implemented<T: #ident>() {}
+
+                fn assert_unimock() {
+                    assert_implemented::<#prefix::Unimock>();
+                }
+            };
+        })
+    } else {
+        None
+    };
+
+    Ok(quote! {
+        #item_trait_alias
+        #assertion
+    })
+}
@@ -1,5 +1,6 @@
 use super::attr::Attr;
 use super::method;
+use super::negative_bounds::NegativeBounds;
 use super::output::OutputWrapping;
 use super::util::{is_type_generic, ContainsAsync};
 
@@ -11,10 +12,15 @@ pub struct TraitInfo<'t> {
     pub methods: Vec<Option<method::MockMethod<'t>>>,
     pub has_default_impls: bool,
     pub contains_async: ContainsAsync,
+    pub negative_bounds: &'t NegativeBounds,
 }
 
 impl<'t> TraitInfo<'t> {
-    pub fn analyze(input_trait: &'t syn::ItemTrait, attr: &Attr) -> syn::Result<Self> {
+    pub fn analyze(
+        input_trait: &'t syn::ItemTrait,
+        attr: &Attr,
+        negative_bounds: &'t NegativeBounds,
+    ) -> syn::Result<Self> {
         let methods =
             method::extract_methods(input_trait, is_type_generic(&input_trait.generics), attr)?;
 
@@ -51,6 +57,7 @@ impl<'t> TraitInfo<'t> {
             methods,
             has_default_impls,
             contains_async: ContainsAsync(contains_async),
+            negative_bounds,
         })
     }
 }
@@ -510,6 +510,28 @@ pub fn contains_lifetime(mut ty: syn::Type) -> bool {
     visitor.contains
 }
 
+/// Whether the type contains a reference or any lifetime, i.e. whether it may borrow data.
+pub fn contains_borrow(mut ty: syn::Type) -> bool {
+    struct Visitor {
+        contains: bool,
+    }
+    impl syn::visit_mut::VisitMut for Visitor {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+            self.contains = true;
+            syn::visit_mut::visit_lifetime_mut(self, lifetime);
+        }
+
+        fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+            self.contains = true;
+            syn::visit_mut::visit_type_reference_mut(self, reference);
+        }
+    }
+
+    let mut visitor = Visitor { contains: false };
+    visitor.visit_type_mut(&mut ty);
+    visitor.contains
+}
+
 pub struct DotAwait;
 
 impl quote::ToTokens for DotAwait {
@@ -610,6 +632,7 @@ pub fn replace_self_ty_with_path(mut ty: syn::Type, replacement_path: &syn::Path
 }
 
 pub struct FutureBound<'s> {
+    #[allow(dead_code)]
     pub future_ident: &'s syn::Ident,
     pub output: &'s syn::AssocType,
 }
@@ -667,3 +690,34 @@ pub fn guess_is_pin(ty: &syn::Type) -> bool {
 
     false
 }
+
+pub fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::with_capacity(ident.len() + 4);
+    for (index, ch) in ident.chars().enumerate() {
+        if ch.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
+}
+
+pub fn to_pascal_case(ident: &str) -> String {
+    let mut pascal = String::with_capacity(ident.len());
+    let mut uppercase_next = true;
+    for ch in ident.chars() {
+        if ch == '_' {
+            uppercase_next = true;
+        } else if uppercase_next {
+            pascal.extend(ch.to_uppercase());
+            uppercase_next = false;
+        } else {
+            pascal.push(ch);
+        }
+    }
+    pascal
+}